@@ -0,0 +1,36 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use salph::{Alphabet, SpellingAlphabet};
+
+// Spell a string, giving the code words separated by spaces
+fn spelled(alphabet: &SpellingAlphabet, s: &str) -> String {
+    alphabet
+        .str_to_spellings(s)
+        .iter()
+        .map(|spelling| spelling.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decoder(c: &mut Criterion) {
+    let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    let cz = SpellingAlphabet::load(Alphabet::cz).unwrap();
+    let text = "The quick brown fox jumps over the lazy dog 0123456789".repeat(50);
+
+    let short = spelled(&nato, "salph");
+    c.bench_function("decode nato short", |b| {
+        b.iter(|| nato.spellings_to_str(black_box(&short)))
+    });
+    let long = spelled(&nato, &text);
+    c.bench_function("decode nato long", |b| {
+        b.iter(|| nato.spellings_to_str(black_box(&long)))
+    });
+    // Czech has code words of multiple words ("dvojité V"), so longer sequences of
+    // words are tried first
+    let long = spelled(&cz, &text);
+    c.bench_function("decode cz long", |b| {
+        b.iter(|| cz.spellings_to_str(black_box(&long)))
+    });
+}
+
+criterion_group!(benches, decoder);
+criterion_main!(benches);
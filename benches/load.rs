@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use salph::{Alphabet, SpellingAlphabet};
+
+fn load(c: &mut Criterion) {
+    // Embedded alphabets are parsed at compile time, so loading only builds the
+    // lookup structures
+    c.bench_function("load nato", |b| {
+        b.iter(|| SpellingAlphabet::load(black_box(Alphabet::nato)))
+    });
+    c.bench_function("load de", |b| {
+        b.iter(|| SpellingAlphabet::load(black_box(Alphabet::de)))
+    });
+    c.bench_function("load cached nato", |b| {
+        b.iter(|| SpellingAlphabet::cached(black_box(Alphabet::nato)))
+    });
+
+    // Alphabet files that are read at runtime are parsed first
+    let nato = include_str!("../alphabets/nato");
+    c.bench_function("parse nato", |b| {
+        b.iter(|| SpellingAlphabet::parse(black_box(nato)))
+    });
+    let v1 = SpellingAlphabet::load(Alphabet::nato)
+        .unwrap()
+        .entries()
+        .map(|(key, word)| format!("{} {}", key, word))
+        .collect::<Vec<_>>()
+        .join("\n");
+    c.bench_function("parse nato v1", |b| {
+        b.iter(|| SpellingAlphabet::parse(black_box(&v1)))
+    });
+}
+
+criterion_group!(benches, load);
+criterion_main!(benches);
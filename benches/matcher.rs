@@ -0,0 +1,72 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use salph::{Alphabet, SpellingAlphabet, SpellingOptions};
+
+fn matcher(c: &mut Criterion) {
+    let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    let de = SpellingAlphabet::load(Alphabet::de).unwrap();
+    let long = "The quick brown fox jumps over the lazy dog 0123456789. ".repeat(200);
+
+    c.bench_function("nato short", |b| {
+        b.iter(|| nato.str_to_spellings(black_box("salph")))
+    });
+    c.bench_function("nato long", |b| {
+        b.iter(|| nato.str_to_spellings(black_box(&long)))
+    });
+    // German has keys of up to three characters ("Sch")
+    c.bench_function("de long", |b| {
+        b.iter(|| de.str_to_spellings(black_box(&long)))
+    });
+    c.bench_function("nato long borrowed", |b| {
+        b.iter(|| nato.spellings_ref(black_box(&long)).count())
+    });
+}
+
+// Input outside of ASCII, which goes through normalization and the fallbacks for
+// kana, Hangul and diacritics
+fn unicode(c: &mut Criterion) {
+    let ru = SpellingAlphabet::load(Alphabet::ru).unwrap();
+    let ja = SpellingAlphabet::load(Alphabet::ja).unwrap();
+    let ko = SpellingAlphabet::load(Alphabet::ko).unwrap();
+    let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+
+    let cyrillic = "Съешь же ещё этих мягких французских булок, да выпей чаю. ".repeat(100);
+    c.bench_function("ru long", |b| {
+        b.iter(|| ru.str_to_spellings(black_box(&cyrillic)))
+    });
+    // Hiragana with voicing marks are split into the plain katakana and the mark
+    let kana = "がぎぐげござじずぜぞ ぱぴぷぺぽ きゃきゅきょ ".repeat(100);
+    c.bench_function("ja kana long", |b| {
+        b.iter(|| ja.str_to_spellings(black_box(&kana)))
+    });
+    // Every syllable is decomposed into its letters
+    let hangul = "다람쥐 헌 쳇바퀴에 타고파 ".repeat(100);
+    c.bench_function("ko hangul long", |b| {
+        b.iter(|| ko.str_to_spellings(black_box(&hangul)))
+    });
+    // Decomposed accents are composed before matching and folded if there's no match
+    let accents = "Cre\u{301}me bru\u{302}le\u{301}e, naïve façade, Ærøskøbing. ".repeat(100);
+    let options = SpellingOptions::new().fold_diacritics(true);
+    c.bench_function("nato folded accents long", |b| {
+        b.iter(|| nato.str_to_spellings_with(black_box(&accents), &options))
+    });
+}
+
+// Input that keeps almost matching keys of multiple characters, so the matcher has
+// to walk the trie as deep as it goes before falling back to a single character
+fn digraphs(c: &mut Criterion) {
+    let de = SpellingAlphabet::load(Alphabet::de).unwrap();
+    let cz = SpellingAlphabet::load(Alphabet::cz).unwrap();
+
+    // "sc" without the "h" of "sch", and "sch" itself
+    let near_misses = "scscscscscscscschschschsc".repeat(400);
+    c.bench_function("de digraph near misses", |b| {
+        b.iter(|| de.str_to_spellings(black_box(&near_misses)))
+    });
+    let digraphs = "chchchchchcchhc".repeat(400);
+    c.bench_function("cz digraphs", |b| {
+        b.iter(|| cz.str_to_spellings(black_box(&digraphs)))
+    });
+}
+
+criterion_group!(benches, matcher, unicode, digraphs);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+//! Node.js bindings for salph, exposing `spell()`, `decode()` and
+//! `listAlphabets()`. TypeScript definitions are generated by the napi CLI.
+use napi::{Error, Result};
+use napi_derive::napi;
+use std::str::FromStr;
+
+/// A single code word together with the part of the input it spells
+#[napi(object)]
+pub struct Spelling {
+    /// The code word, including the announced prefix (e.g. "capital Alpha")
+    pub spelling: String,
+    /// What kind of character the code word spells ("letter", "digit", ...)
+    pub kind: String,
+    /// The part of the input that was matched (e.g. "a" or "ch")
+    pub source: String,
+    /// Position of the match in the input, counted in grapheme clusters
+    pub position: u32,
+    /// Whether the source had to be transliterated to find a code word
+    pub folded: bool,
+    /// Other code words the alphabet allows for the source
+    pub alternates: Vec<String>,
+    /// How the code word is pronounced (e.g. "AL-fah")
+    pub pronunciation: Option<String>,
+    /// IPA transcription of the code word
+    pub ipa: Option<String>,
+}
+
+impl From<salph::Spelling> for Spelling {
+    fn from(spelling: salph::Spelling) -> Spelling {
+        Spelling {
+            spelling: spelling.to_string(),
+            kind: spelling.kind.to_string(),
+            source: spelling.source,
+            position: spelling.position as u32,
+            folded: spelling.folded,
+            alternates: spelling.alternates,
+            pronunciation: spelling.pronunciation,
+            ipa: spelling.ipa,
+        }
+    }
+}
+
+/// An available alphabet and its metadata
+#[napi(object)]
+pub struct AlphabetInfo {
+    /// Name to load the alphabet with (e.g. "nato")
+    pub alphabet: String,
+    /// Human readable name (e.g. "Dutch (Belgium)")
+    pub name: Option<String>,
+    /// ISO 639 language code (e.g. "nl")
+    pub language: Option<String>,
+    /// ISO 3166 region code (e.g. "BE")
+    pub region: Option<String>,
+}
+
+/// Spell a string using the alphabet with the given name, "nato" by default.
+/// Characters without a mapping are skipped.
+#[napi]
+pub fn spell(text: String, alphabet: Option<String>) -> Result<Vec<Spelling>> {
+    let alphabet = load(alphabet)?;
+    Ok(alphabet.spellings(&text).map(Spelling::from).collect())
+}
+
+/// Decode code words back to the text they spell, using the alphabet with the
+/// given name, "nato" by default
+#[napi]
+pub fn decode(text: String, alphabet: Option<String>) -> Result<String> {
+    Ok(load(alphabet)?.spellings_to_str(&text))
+}
+
+/// The available alphabets
+#[napi]
+pub fn list_alphabets() -> Vec<AlphabetInfo> {
+    salph::SpellingAlphabet::list()
+        .into_iter()
+        .map(|(alphabet, metadata)| AlphabetInfo {
+            alphabet,
+            name: metadata.name,
+            language: metadata.language,
+            region: metadata.region,
+        })
+        .collect()
+}
+
+// Load an alphabet by name, throwing an error if it doesn't exist
+fn load(alphabet: Option<String>) -> Result<salph::SpellingAlphabet> {
+    let name = alphabet.as_deref().unwrap_or("nato");
+    salph::SpellingAlphabet::from_str(name).map_err(|e| Error::from_reason(e.to_string()))
+}
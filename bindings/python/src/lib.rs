@@ -0,0 +1,144 @@
+//! Python bindings for salph. The module is called `salph` and exposes
+//! `SpellingAlphabet`, `Spelling`, `spell()`, `decode()` and `list_alphabets()`.
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::str::FromStr;
+
+/// A spelling alphabet
+#[pyclass(name = "SpellingAlphabet", frozen)]
+struct PySpellingAlphabet(salph::SpellingAlphabet);
+
+#[pymethods]
+impl PySpellingAlphabet {
+    /// Load an embedded alphabet by name (e.g. "nato")
+    #[new]
+    #[pyo3(signature = (name = "nato"))]
+    fn new(name: &str) -> PyResult<Self> {
+        load(name).map(PySpellingAlphabet)
+    }
+
+    /// Load an alphabet from a file
+    #[staticmethod]
+    fn from_file(path: &str) -> PyResult<Self> {
+        salph::SpellingAlphabet::from_file(path)
+            .map(PySpellingAlphabet)
+            .map_err(to_py_err)
+    }
+
+    /// Spell a string. Characters without a mapping are skipped.
+    fn spell(&self, text: &str) -> Vec<PySpelling> {
+        self.0.spellings(text).map(PySpelling).collect()
+    }
+
+    /// Decode code words back to the text they spell
+    fn decode(&self, text: &str) -> String {
+        self.0.spellings_to_str(text)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// A single code word together with the part of the input it spells
+#[pyclass(name = "Spelling", frozen)]
+struct PySpelling(salph::Spelling);
+
+#[pymethods]
+impl PySpelling {
+    #[getter]
+    fn spelling(&self) -> &str {
+        &self.0.spelling
+    }
+
+    #[getter]
+    fn kind(&self) -> String {
+        self.0.kind.to_string()
+    }
+
+    #[getter]
+    fn source(&self) -> &str {
+        &self.0.source
+    }
+
+    #[getter]
+    fn position(&self) -> usize {
+        self.0.position
+    }
+
+    #[getter]
+    fn prefix(&self) -> Option<&str> {
+        self.0.prefix.as_deref()
+    }
+
+    #[getter]
+    fn folded(&self) -> bool {
+        self.0.folded
+    }
+
+    #[getter]
+    fn alternates(&self) -> Vec<String> {
+        self.0.alternates.clone()
+    }
+
+    #[getter]
+    fn pronunciation(&self) -> Option<&str> {
+        self.0.pronunciation.as_deref()
+    }
+
+    #[getter]
+    fn ipa(&self) -> Option<&str> {
+        self.0.ipa.as_deref()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Spelling({:?})", self.0.to_string())
+    }
+}
+
+/// Spell a string using the alphabet with the given name
+#[pyfunction]
+#[pyo3(signature = (text, alphabet = "nato"))]
+fn spell(text: &str, alphabet: &str) -> PyResult<Vec<PySpelling>> {
+    Ok(load(alphabet)?.spellings(text).map(PySpelling).collect())
+}
+
+/// Decode code words back to the text they spell, using the alphabet with the
+/// given name
+#[pyfunction]
+#[pyo3(signature = (text, alphabet = "nato"))]
+fn decode(text: &str, alphabet: &str) -> PyResult<String> {
+    Ok(load(alphabet)?.spellings_to_str(text))
+}
+
+/// The names of the available alphabets
+#[pyfunction]
+fn list_alphabets() -> Vec<String> {
+    salph::SpellingAlphabet::list()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+// Load an alphabet by name, raising a ValueError if it doesn't exist
+fn load(name: &str) -> PyResult<salph::SpellingAlphabet> {
+    salph::SpellingAlphabet::from_str(name).map_err(to_py_err)
+}
+
+fn to_py_err(e: salph::SalphError) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+#[pymodule]
+#[pyo3(name = "salph")]
+fn salph_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySpellingAlphabet>()?;
+    m.add_class::<PySpelling>()?;
+    m.add_function(wrap_pyfunction!(spell, m)?)?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_function(wrap_pyfunction!(list_alphabets, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! WebAssembly bindings for salph, exposing `spell()` and `listAlphabets()`.
+//!
+//! The package exports the following functions:
+//! ```text
+//! import init, { spell, listAlphabets } from "salph-wasm";
+//!
+//! await init();
+//! spell("ab", "nato");  // [{ spelling: "Alpha", kind: "letter", source: "a", ... }, ...]
+//! listAlphabets();      // [{ alphabet: "cz", name: "Czech", language: "cs", ... }, ...]
+//! ```
+use salph::{AlphabetMetadata, SpellingAlphabet};
+use serde::Serialize;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+// An alphabet as returned by `listAlphabets`
+#[derive(Serialize)]
+struct AlphabetInfo {
+    alphabet: String,
+    #[serde(flatten)]
+    metadata: AlphabetMetadata,
+}
+
+/// Spell a string using the alphabet with the given name, returning an array of
+/// spellings. Characters without a mapping are skipped.
+#[wasm_bindgen]
+pub fn spell(text: &str, alphabet: &str) -> Result<JsValue, JsError> {
+    let alphabet = SpellingAlphabet::from_str(alphabet)?;
+    Ok(serde_wasm_bindgen::to_value(
+        &alphabet.str_to_spellings(text),
+    )?)
+}
+
+/// List the available alphabets with their metadata
+#[wasm_bindgen(js_name = listAlphabets)]
+pub fn list_alphabets() -> Result<JsValue, JsError> {
+    let alphabets = SpellingAlphabet::list()
+        .into_iter()
+        .map(|(alphabet, metadata)| AlphabetInfo { alphabet, metadata })
+        .collect::<Vec<_>>();
+    Ok(serde_wasm_bindgen::to_value(&alphabets)?)
+}
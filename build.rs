@@ -1,44 +1,221 @@
-use rust_embed::RustEmbed;
 use std::env;
 use std::fs;
 use std::path::Path;
 
-#[derive(RustEmbed)]
-#[folder = "alphabets"]
-struct Asset;
+// The alphabet file parser is shared with the library
+#[allow(dead_code)]
+#[path = "src/format.rs"]
+mod format;
+
 fn main() {
-    let files: Vec<String> = Asset::iter().map(|a| a.to_string()).collect();
-    let mut alphabets: Vec<(String, String)> = files
-        .iter()
-        .map(|file_path| {
-            let file = Asset::get(file_path).unwrap();
-            let header = &String::from_utf8_lossy(&file.data)[2..]; // Strip "# " from the header
-            (
-                file_path.to_string(),
-                header.split('\n').next().unwrap().to_string(),
-            )
+    // Parse all embedded alphabets, so no parsing needs to happen at runtime
+    let mut alphabets: Vec<(String, format::ParsedAlphabet)> = fs::read_dir("alphabets")
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            let contents = fs::read_to_string(&path).unwrap();
+            let parsed = format::parse(&contents).unwrap_or_else(|(line, reason)| {
+                panic!(
+                    "Alphabet {} has an error on line {}: {}",
+                    name, line, reason
+                )
+            });
+            (name, parsed)
         })
         .collect();
     alphabets.sort_by(|(a, _), (b, _)| a.cmp(b));
+    resolve_extends(&mut alphabets);
+    for (name, parsed) in &alphabets {
+        if parsed.entries.is_empty() {
+            panic!("Alphabet {} contains no entries", name);
+        }
+        for alias in aliases(parsed) {
+            if alphabets.iter().any(|(other, parsed)| {
+                other.as_str() == alias || (other != name && aliases(parsed).contains(&alias))
+            }) {
+                panic!("Alias {} of alphabet {} is already taken", alias, name);
+            }
+        }
+    }
+
+    // Only embed the alphabets whose feature is enabled. Alphabets are resolved
+    // first, so an alphabet can extend one that isn't embedded.
+    let all = env::var_os("CARGO_FEATURE_ALL_ALPHABETS").is_some();
+    alphabets.retain(|(name, _)| {
+        let enabled = name == "nato" || env::var_os(feature_var(name)).is_some();
+        if all && !enabled {
+            panic!(
+                "Alphabet {} has no alphabet-{} feature in Cargo.toml",
+                name, name
+            );
+        }
+        enabled
+    });
 
     let mut contents = String::new();
     contents.push_str(
         "use strum_macros::{Display, EnumString};\n\
-        #[derive(Debug, Display, EnumString, Clone)]\n\
+        #[derive(Debug, Display, EnumString, Clone, PartialEq, Eq, Hash)]\n\
+        #[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]\n\
         #[allow(non_camel_case_types)]\n\
         pub enum Alphabet {\n",
     );
-
-    for (language, header) in alphabets {
-        contents.push_str(&format!("    /// {}\n", header));
+    for (language, parsed) in &alphabets {
+        contents.push_str(&format!("    /// {}\n", display_name(parsed)));
+        // Aliases are parsed as well, while the name stays the one that's displayed
+        let aliases = aliases(parsed);
+        if !aliases.is_empty() {
+            let serialize = aliases
+                .iter()
+                .map(|alias| format!(", serialize = {:?}", alias))
+                .collect::<String>();
+            contents.push_str(&format!(
+                "    #[strum(to_string = {:?}{})]\n",
+                language, serialize
+            ));
+        }
         contents.push_str(&format!("    {},\n", language));
     }
-    contents.push_str("}\n");
+    contents.push_str("}\n\n");
+
+    // Static data for every alphabet
+    for (language, parsed) in &alphabets {
+        contents.push_str(&format!(
+            "static {}: AlphabetData = AlphabetData {{\n    \
+                name: {:?},\n    \
+                directives: &{:?},\n    \
+                entries: &{:?},\n\
+            }};\n",
+            static_name(language),
+            language,
+            parsed.directives,
+            parsed.entries
+        ));
+    }
+
+    contents.push_str(
+        "\nimpl Alphabet {\n    \
+            // Static data of the alphabet\n    \
+            fn data(&self) -> &'static AlphabetData {\n        \
+                match self {\n",
+    );
+    for (language, _) in &alphabets {
+        contents.push_str(&format!(
+            "            Alphabet::{} => &{},\n",
+            language,
+            static_name(language)
+        ));
+    }
+    contents.push_str("        }\n    }\n}\n\n");
+
+    contents.push_str("// Static data of all alphabets, sorted by name\nstatic ALPHABETS: &[&AlphabetData] = &[\n");
+    for (language, _) in &alphabets {
+        contents.push_str(&format!("    &{},\n", static_name(language)));
+    }
+    contents.push_str("];\n");
 
     let out_dir = env::var_os("OUT_DIR").unwrap();
-    dbg!(&out_dir);
     let dest_path = Path::new(&out_dir).join("alphabet_kinds.rs");
     fs::write(dest_path, contents).unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/format.rs");
+    println!("cargo:rerun-if-changed=alphabets");
+}
+
+// Resolve `# extends:` directives by applying alphabets on top of the alphabet they
+// extend. Alphabets are resolved once their base is, so chains resolve in order.
+fn resolve_extends(alphabets: &mut [(String, format::ParsedAlphabet)]) {
+    loop {
+        let pending = alphabets
+            .iter()
+            .filter(|(_, parsed)| extends(parsed).is_some())
+            .count();
+        if pending == 0 {
+            return;
+        }
+        for i in 0..alphabets.len() {
+            let Some(base) = extends(&alphabets[i].1) else {
+                continue;
+            };
+            let Some(base) = alphabets.iter().find(|(name, _)| name == base) else {
+                panic!(
+                    "Alphabet {} extends unknown alphabet {}",
+                    alphabets[i].0, base
+                );
+            };
+            if extends(&base.1).is_some() {
+                continue;
+            }
+            let mut entries = base.1.entries.clone();
+            let mut directives = base.1.directives.clone();
+            // Aliases name the base, so they aren't inherited
+            directives.retain(|(name, _)| name != "aliases");
+            let parsed = &mut alphabets[i].1;
+            parsed.directives.retain(|(name, _)| name != "extends");
+            merge(&mut entries, &parsed.entries);
+            merge(&mut directives, &parsed.directives);
+            parsed.entries = entries;
+            parsed.directives = directives;
+        }
+        let remaining = alphabets
+            .iter()
+            .filter(|(_, parsed)| extends(parsed).is_some())
+            .count();
+        if remaining == pending {
+            panic!("Alphabets extend each other in a cycle");
+        }
+    }
+}
+
+// The alphabet extended by a parsed alphabet, if any
+fn extends(parsed: &format::ParsedAlphabet) -> Option<&str> {
+    parsed
+        .directives
+        .iter()
+        .find(|(name, _)| name == "extends")
+        .map(|(_, value)| value.as_str())
+}
+
+// The aliases of a parsed alphabet, from its comma separated `aliases` directive
+fn aliases(parsed: &format::ParsedAlphabet) -> Vec<&str> {
+    parsed
+        .directives
+        .iter()
+        .filter(|(name, _)| name == "aliases")
+        .flat_map(|(_, value)| value.split(','))
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .collect()
+}
+
+// Apply `(key, value)` pairs on top of `base`, replacing existing keys in place
+fn merge(base: &mut Vec<(String, String)>, pairs: &[(String, String)]) {
+    for (key, value) in pairs {
+        match base.iter_mut().find(|(k, _)| k == key) {
+            Some(existing) => existing.1 = value.clone(),
+            None => base.push((key.clone(), value.clone())),
+        }
+    }
+}
+
+// The display name of an alphabet, or an empty string if it has none
+fn display_name(parsed: &format::ParsedAlphabet) -> &str {
+    parsed
+        .directives
+        .iter()
+        .find(|(name, _)| name == "name")
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_default()
+}
+
+// Environment variable cargo sets when the feature of an alphabet is enabled
+fn feature_var(name: &str) -> String {
+    format!("CARGO_FEATURE_ALPHABET_{}", name.to_uppercase())
+}
+
+// Name of the static holding the data of an alphabet
+fn static_name(language: &str) -> String {
+    format!("DATA_{}", language.to_uppercase())
 }
@@ -0,0 +1,88 @@
+#ifndef SALPH_H
+#define SALPH_H
+
+/* Generated by cbindgen from src/ffi.rs. Don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * What kind of character a code word spells, see [`SpellingKind`]
+ */
+typedef enum SalphKind {
+  SALPH_KIND_LETTER,
+  SALPH_KIND_DIGIT,
+  SALPH_KIND_PUNCTUATION,
+  SALPH_KIND_WHITESPACE,
+  SALPH_KIND_UNKNOWN,
+  SALPH_KIND_SYMBOL,
+} SalphKind;
+
+/**
+ * A single code word, see [`Spelling`](crate::Spelling). `spelling` includes the
+ * announced prefix (e.g. "capital Alpha").
+ */
+typedef struct SalphSpelling {
+  char *spelling;
+  char *source;
+  uintptr_t position;
+  enum SalphKind kind;
+} SalphSpelling;
+
+/**
+ * The spellings of a string, returned by [`salph_spell`]
+ */
+typedef struct SalphSpellings {
+  struct SalphSpelling *spellings;
+  uintptr_t len;
+} SalphSpellings;
+
+/**
+ * The names of the available alphabets, returned by [`salph_list_alphabets`]
+ */
+typedef struct SalphAlphabets {
+  char **names;
+  uintptr_t len;
+} SalphAlphabets;
+
+/**
+ * Spell `text` using the alphabet named `alphabet`. Characters without a mapping
+ * are skipped. Returns NULL if either argument is NULL or not valid UTF-8, or if
+ * the alphabet doesn't exist. The result needs to be freed with [`salph_free`].
+ *
+ * # Safety
+ *
+ * `text` and `alphabet` need to be NULL or point to NUL-terminated strings.
+ */
+struct SalphSpellings *salph_spell(const char *text, const char *alphabet);
+
+/**
+ * Free the result of [`salph_spell`]. Does nothing if `spellings` is NULL.
+ *
+ * # Safety
+ *
+ * `spellings` needs to be NULL or returned by [`salph_spell`], and can't be used
+ * afterwards.
+ */
+void salph_free(struct SalphSpellings *spellings);
+
+/**
+ * List the names of the available alphabets. The result needs to be freed with
+ * [`salph_free_alphabets`].
+ */
+struct SalphAlphabets *salph_list_alphabets(void);
+
+/**
+ * Free the result of [`salph_list_alphabets`]. Does nothing if `alphabets` is
+ * NULL.
+ *
+ * # Safety
+ *
+ * `alphabets` needs to be NULL or returned by [`salph_list_alphabets`], and
+ * can't be used afterwards.
+ */
+void salph_free_alphabets(struct SalphAlphabets *alphabets);
+
+#endif /* SALPH_H */
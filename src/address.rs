@@ -0,0 +1,82 @@
+// Recognition of email addresses and URLs, whose separators are spelled as words
+// when `SpellingOptions::spell_addresses` is enabled
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// English words for the separators of email addresses and URLs
+const ADDRESS_WORDS: &[(&str, &str)] = &[
+    ("@", "at"),
+    (".", "dot"),
+    ("/", "slash"),
+    (":", "colon"),
+    ("-", "dash"),
+    ("_", "underscore"),
+    ("?", "question mark"),
+    ("=", "equals"),
+    ("&", "ampersand"),
+    ("#", "hash"),
+    ("%", "percent"),
+    ("+", "plus"),
+    ("~", "tilde"),
+];
+
+// Punctuation around an address that belongs to the sentence rather than the
+// address (e.g. in "(mail john@example.com.)")
+const LEADING: &[char] = &['(', '<', '"', '\''];
+const TRAILING: &[char] = &['.', ',', ';', ':', '!', '?', ')', '>', '"', '\''];
+
+// The English word for a separator of an address
+pub(crate) fn address_word(grapheme: &str) -> Option<&'static str> {
+    ADDRESS_WORDS
+        .iter()
+        .find(|(separator, _)| *separator == grapheme)
+        .map(|(_, word)| *word)
+}
+
+// The ranges of the email addresses and URLs in a string split into graphemes
+pub(crate) fn address_ranges(graphemes: &[&str]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for end in 0..=graphemes.len() {
+        let boundary = graphemes
+            .get(end)
+            .is_none_or(|g| g.chars().all(char::is_whitespace));
+        if !boundary {
+            continue;
+        }
+        let token = graphemes[start..end].concat();
+        let trimmed = token.trim_start_matches(LEADING);
+        let offset = start + token[..token.len() - trimmed.len()].graphemes(true).count();
+        let address = trimmed.trim_end_matches(TRAILING);
+        if is_email(address) || is_url(address) {
+            ranges.push(offset..offset + address.graphemes(true).count());
+        }
+        start = end + 1;
+    }
+    ranges
+}
+
+// Whether a token looks like an email address (e.g. "john.doe@example.com")
+fn is_email(token: &str) -> bool {
+    match token.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
+// Whether a token looks like a URL (e.g. "https://example.com" or "www.example.com")
+fn is_url(token: &str) -> bool {
+    let scheme = token
+        .split_once("://")
+        .is_some_and(|(scheme, rest)| !scheme.is_empty() && !rest.is_empty());
+    scheme
+        || token
+            .strip_prefix("www.")
+            .is_some_and(|rest| rest.contains('.'))
+}
@@ -0,0 +1,146 @@
+use crate::{SpellingAlphabet, SpellingOptions, Unmatched};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Characters that look alike, so whoever reads out a serial number or license
+// plate may pick the wrong one. The code words don't help with these, announcing
+// the class of the character does.
+const LOOKALIKES: &[&[&str]] = &[
+    &["0", "O", "o", "Q"],
+    &["1", "I", "l", "i"],
+    &["2", "Z", "z"],
+    &["5", "S", "s"],
+    &["6", "G"],
+    &["8", "B"],
+];
+
+// Letters whose names sound alike over a bad connection, which is exactly what
+// the code words resolve
+const SOUNDALIKES: &[&[&str]] = &[
+    &["B", "C", "D", "E", "G", "P", "T", "V", "Z"],
+    &["A", "J", "K"],
+    &["F", "S", "X"],
+    &["I", "Y"],
+    &["M", "N"],
+    &["Q", "U"],
+];
+
+/// The characters of an input that are commonly confused, as returned by
+/// [`SpellingAlphabet::ambiguity_report`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct AmbiguityReport {
+    /// Every confusable character, in order of appearance
+    pub ambiguities: Vec<Ambiguity>,
+}
+
+/// A character that is commonly confused with others
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Ambiguity {
+    /// Position of the character in the input, counted in grapheme clusters
+    pub position: usize,
+    /// The character itself
+    pub character: String,
+    /// The characters it's confused with
+    pub confusable: Vec<String>,
+    /// Words to read it out with when the code word alone doesn't tell it apart
+    /// (e.g. "digit zero" or "lowercase Lima"), or `None` if the code word is
+    /// enough
+    pub clarifier: Option<String>,
+}
+
+impl AmbiguityReport {
+    /// Whether there are no confusable characters
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert!(spelling_alphabet.ambiguity_report("HW 437").is_empty());
+    /// assert!(!spelling_alphabet.ambiguity_report("B0").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.ambiguities.is_empty()
+    }
+
+    /// Whether any character needs a clarifier on top of its code word
+    pub fn needs_clarifiers(&self) -> bool {
+        self.ambiguities.iter().any(|a| a.clarifier.is_some())
+    }
+}
+
+impl SpellingAlphabet {
+    /// Find the characters of a string that are commonly confused when read out,
+    /// like serial numbers and license plates. Characters that look alike (0/O,
+    /// 1/I/l) get a clarifier announcing their class, since the reader might
+    /// pick the wrong code word. Letters that only sound alike (B/D, M/N) are
+    /// told apart by their code words.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let report = spelling_alphabet.ambiguity_report("B0l");
+    /// let b = &report.ambiguities[0];
+    /// assert_eq!(b.character, "B");
+    /// assert!(b.confusable.contains(&"8".to_string()));
+    /// assert!(b.confusable.contains(&"D".to_string()));
+    /// assert_eq!(b.clarifier.as_deref(), Some("capital Bravo"));
+    ///
+    /// let zero = &report.ambiguities[1];
+    /// assert_eq!(zero.position, 1);
+    /// assert_eq!(zero.confusable, ["O", "o", "Q"]);
+    /// assert_eq!(zero.clarifier.as_deref(), Some("digit zero"));
+    /// assert_eq!(report.ambiguities[2].clarifier.as_deref(), Some("lowercase Lima"));
+    ///
+    /// let report = spelling_alphabet.ambiguity_report("M");
+    /// assert_eq!(report.ambiguities[0].confusable, ["N"]);
+    /// assert_eq!(report.ambiguities[0].clarifier, None);
+    /// ```
+    pub fn ambiguity_report(&self, s: &str) -> AmbiguityReport {
+        let options = SpellingOptions::new()
+            .announce_class(true)
+            .unmatched(Unmatched::Keep);
+        let mut report = AmbiguityReport::default();
+        for (position, grapheme) in s.graphemes(true).enumerate() {
+            let mut confusable = Vec::new();
+            let lookalike = add_confusable(&mut confusable, LOOKALIKES, grapheme);
+            // Letters sound the same regardless of their case
+            add_confusable(&mut confusable, SOUNDALIKES, &grapheme.to_uppercase());
+            if confusable.is_empty() {
+                continue;
+            }
+
+            let clarifier = lookalike.then(|| {
+                self.str_to_spellings_with(grapheme, &options)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            report.ambiguities.push(Ambiguity {
+                position,
+                character: grapheme.to_string(),
+                confusable,
+                clarifier,
+            });
+        }
+        report
+    }
+}
+
+// Add the other characters of the sets a grapheme is in, returning whether it's
+// in any of them
+fn add_confusable(confusable: &mut Vec<String>, sets: &[&[&str]], grapheme: &str) -> bool {
+    let mut found = false;
+    for set in sets.iter().filter(|set| set.contains(&grapheme)) {
+        found = true;
+        for c in set.iter().filter(|c| **c != grapheme) {
+            if !confusable.iter().any(|known| known == c) {
+                confusable.push(c.to_string());
+            }
+        }
+    }
+    found
+}
@@ -0,0 +1,205 @@
+//! WAV files assembled from recordings of code words, enabled through the `audio`
+//! feature. The CLI writes them with `--audio-out`.
+//!
+//! Recordings are WAV files in a directory, named after the lowercase word they
+//! contain (e.g. `alpha.wav`). Code words consisting of multiple words, like
+//! "capital Alpha", are assembled from a recording per word. All recordings need
+//! to have the same sample rate, number of channels and sample format.
+use crate::Spelling;
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use std::{
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Error returned by [`Recordings::write`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AudioError {
+    /// There are no recordings for these words
+    Missing(Vec<String>),
+    /// The recording of the word can't be read or has a different format than
+    /// the others
+    InvalidRecording { word: String, reason: String },
+    /// The WAV file couldn't be written
+    Io(io::Error),
+}
+
+impl fmt::Display for AudioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AudioError::Missing(words) => write!(f, "No recordings of {}", words.join(", ")),
+            AudioError::InvalidRecording { word, reason } => {
+                write!(f, "Invalid recording of {}: {}", word, reason)
+            }
+            AudioError::Io(e) => write!(f, "Unable to write audio: {}", e),
+        }
+    }
+}
+
+impl Error for AudioError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AudioError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<hound::Error> for AudioError {
+    fn from(e: hound::Error) -> AudioError {
+        match e {
+            hound::Error::IoError(e) => AudioError::Io(e),
+            e => AudioError::Io(io::Error::other(e)),
+        }
+    }
+}
+
+/// A directory of recordings of code words
+/// ```
+/// use salph::{audio::Recordings, SpellingAlphabet, Alphabet};
+/// use std::time::Duration;
+///
+/// // Recordings of a second of silence, sampled at 8 kHz
+/// let dir = std::env::temp_dir().join("salph-recordings");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let spec = hound::WavSpec {
+///     channels: 1,
+///     sample_rate: 8000,
+///     bits_per_sample: 16,
+///     sample_format: hound::SampleFormat::Int,
+/// };
+/// for word in ["alpha", "bravo"] {
+///     let mut writer = hound::WavWriter::create(dir.join(format!("{}.wav", word)), spec).unwrap();
+///     (0..8000).for_each(|_| writer.write_sample(0i16).unwrap());
+///     writer.finalize().unwrap();
+/// }
+///
+/// let spellings = SpellingAlphabet::load(Alphabet::nato).unwrap().str_to_spellings("ab");
+/// let out = dir.join("ab.wav");
+/// let recordings = Recordings::new(&dir).gap(Duration::from_millis(500));
+/// recordings.write(&spellings, &out).unwrap();
+/// assert_eq!(hound::WavReader::open(&out).unwrap().duration(), 8000 + 4000 + 8000);
+///
+/// let spellings = SpellingAlphabet::load(Alphabet::nato).unwrap().str_to_spellings("c");
+/// assert!(recordings.write(&spellings, &out).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recordings {
+    dir: PathBuf,
+    gap: Duration,
+}
+
+impl Recordings {
+    /// Use the recordings in `dir`. Code words are separated by 300ms of silence.
+    pub fn new(dir: impl Into<PathBuf>) -> Recordings {
+        Recordings {
+            dir: dir.into(),
+            gap: Duration::from_millis(300),
+        }
+    }
+
+    /// Set the silence between code words
+    pub fn gap(mut self, gap: Duration) -> Recordings {
+        self.gap = gap;
+        self
+    }
+
+    /// Write the code words of `spellings` to a WAV file at `path`, using the
+    /// format of the recordings. Fails without writing anything if a recording
+    /// is missing.
+    pub fn write(&self, spellings: &[Spelling], path: &Path) -> Result<(), AudioError> {
+        let words = spellings
+            .iter()
+            .map(|spelling| {
+                spelling
+                    .to_string()
+                    .split_whitespace()
+                    .map(str::to_lowercase)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut missing = Vec::new();
+        for word in words.iter().flatten() {
+            if !self.recording(word).is_file() && !missing.contains(word) {
+                missing.push(word.clone());
+            }
+        }
+        if !missing.is_empty() {
+            return Err(AudioError::Missing(missing));
+        }
+
+        let mut writer: Option<WavWriter<_>> = None;
+        for (i, spelling) in words.iter().enumerate() {
+            for (j, word) in spelling.iter().enumerate() {
+                let invalid = |reason: String| AudioError::InvalidRecording {
+                    word: word.clone(),
+                    reason,
+                };
+                let mut reader =
+                    WavReader::open(self.recording(word)).map_err(|e| invalid(e.to_string()))?;
+                let spec = reader.spec();
+                // The format of the first recording is used for the whole file
+                let writer = match &mut writer {
+                    Some(writer) => writer,
+                    None => writer.insert(WavWriter::create(path, spec)?),
+                };
+                if writer.spec() != spec {
+                    return Err(invalid("format differs from the other recordings".into()));
+                }
+                if i > 0 && j == 0 {
+                    write_silence(writer, self.gap)?;
+                }
+                match spec.sample_format {
+                    SampleFormat::Int => {
+                        for sample in reader.samples::<i32>() {
+                            writer.write_sample(sample.map_err(|e| invalid(e.to_string()))?)?;
+                        }
+                    }
+                    SampleFormat::Float => {
+                        for sample in reader.samples::<f32>() {
+                            writer.write_sample(sample.map_err(|e| invalid(e.to_string()))?)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        match writer {
+            Some(writer) => writer.finalize()?,
+            // Without code words there is no format to use, so the file is empty
+            None => WavWriter::create(path, DEFAULT_SPEC)?.finalize()?,
+        }
+        Ok(())
+    }
+
+    // Path of the recording of a word
+    fn recording(&self, word: &str) -> PathBuf {
+        self.dir.join(format!("{}.wav", word))
+    }
+}
+
+const DEFAULT_SPEC: WavSpec = WavSpec {
+    channels: 1,
+    sample_rate: 44100,
+    bits_per_sample: 16,
+    sample_format: SampleFormat::Int,
+};
+
+fn write_silence<W: io::Write + io::Seek>(
+    writer: &mut WavWriter<W>,
+    duration: Duration,
+) -> Result<(), hound::Error> {
+    let spec = writer.spec();
+    let frames = (duration.as_secs_f64() * f64::from(spec.sample_rate)).round() as usize;
+    for _ in 0..frames * usize::from(spec.channels) {
+        match spec.sample_format {
+            SampleFormat::Int => writer.write_sample(0i32)?,
+            SampleFormat::Float => writer.write_sample(0f32)?,
+        }
+    }
+    Ok(())
+}
@@ -1,97 +1,2145 @@
-use clap::Parser;
+use clap::{builder::PossibleValuesParser, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 use colored::*;
-use std::io::stdin;
+use salph::Speller;
+use serde_json::json;
+use std::io::{stdin, BufRead, IsTerminal};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use tabular::{Row, Table};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Parser, Debug)]
-#[clap(author, version, about, long_about = None)]
-struct Args {
-    /// Alphabet to use
-    #[clap(short, long, env="SALPH", default_value_t = String::from("nato"), value_parser = salph::SpellingAlphabet::validate)]
-    alphabet: String,
-    sentence: Vec<String>,
+#[clap(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
 
-    /// List available alphabets
-    #[clap(short, long)]
-    list_alphabets: bool,
+    /// When to use colors (words = green, numbers = yellow). "auto" (the default)
+    /// uses colors if stdout is a terminal and NO_COLOR isn't set
+    #[clap(long, value_enum, global = true)]
+    color: Option<Color>,
+
+    // Without a command, the arguments are those of `salph spell`
+    #[clap(flatten)]
+    spell: SpellArgs,
+
+    #[clap(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Spell a sentence. This is the default when no command is given
+    Spell {
+        #[clap(flatten)]
+        spell: SpellArgs,
 
+        #[clap(flatten)]
+        input: InputArgs,
+    },
+    /// Spell sentences entered at an interactive prompt
+    #[clap(alias = "interactive")]
+    Repl(SpellArgs),
+    /// Decode code words back to the text they spell
+    Decode {
+        #[clap(flatten)]
+        alphabet: AlphabetArgs,
+        sentence: Vec<String>,
+    },
+    /// Practice an alphabet by naming the code words of random letters
+    Quiz {
+        #[clap(flatten)]
+        alphabet: AlphabetArgs,
+
+        /// Show code words and ask for their letters instead
+        #[clap(long)]
+        reverse: bool,
+
+        /// Number of questions
+        #[clap(long, default_value_t = 10)]
+        rounds: usize,
+    },
+    /// List available alphabets
+    List {
+        /// Output format. The other formats include the number of entries and the
+        /// characters of every alphabet
+        #[clap(short, long, value_enum, default_value_t = Output::Table)]
+        output: Output,
+        /// Also list historical alphabets that are no longer in use
+        #[clap(long)]
+        include_historical: bool,
+    },
+    /// Search alphabets by their abbreviation, name or language (e.g. "french" or
+    /// "nl-BE")
+    Search { query: String },
     /// Show the contents of an alphabet
-    #[clap(short, long, value_parser = salph::SpellingAlphabet::validate)]
-    show_alphabet: Option<String>,
+    Show {
+        #[clap(value_parser = salph::SpellingAlphabet::validate)]
+        alphabet: String,
+
+        /// Print the alphabet in a structured format
+        #[clap(long, value_enum)]
+        export: Option<Export>,
+    },
+    /// Export an alphabet as flashcards that can be imported into Anki
+    ExportAnki {
+        /// Alphabet to export, instead of the configured one
+        #[clap(value_parser = validate_alphabet)]
+        alphabet: Option<String>,
+
+        /// Add the pronunciation to the code words
+        #[clap(long)]
+        pronunciation: bool,
+    },
+    /// Check an alphabet file for likely mistakes
+    Lint { file: PathBuf },
+    /// Compare the code words of two alphabets
+    Diff {
+        #[clap(value_parser = salph::SpellingAlphabet::validate)]
+        from: String,
+        #[clap(value_parser = salph::SpellingAlphabet::validate)]
+        to: String,
+    },
+    /// Print a shell completion script
+    Completions { shell: Shell },
+    /// Write man pages for salph and its commands
+    Manpage {
+        /// Directory to write a page per command to, instead of printing the page
+        /// of salph to stdout
+        dir: Option<PathBuf>,
+    },
+    /// Serve a REST API for spelling and decoding
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on
+        #[clap(long, default_value = "127.0.0.1:8080")]
+        address: String,
+    },
+}
+
+/// Selection of the alphabet to spell or decode with
+#[derive(clap::Args, Debug, Default)]
+struct AlphabetArgs {
+    /// Alphabet to use. "auto" (the default) picks the alphabet matching the system
+    /// locale
+    #[clap(short, long, env = "SALPH", value_parser = validate_alphabet)]
+    alphabet: Option<String>,
+
+    /// Load the alphabet from a file instead. Files ending in .json, .toml or .csv
+    /// are read as structured alphabets
+    #[clap(long)]
+    alphabet_file: Option<PathBuf>,
+
+    /// Apply the entries of an alphabet file on top of the selected alphabet
+    #[clap(long)]
+    override_file: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct SpellArgs {
+    #[clap(flatten)]
+    alphabet: AlphabetArgs,
+
+    /// Alphabet to spell the characters with that the alphabet has no code word
+    /// for. Can be given multiple times, the alphabets are tried in order
+    #[clap(long, value_name = "ALPHABET", value_parser = validate_alphabet,
+        conflicts_with_all = ["as_in", "group"])]
+    fallback: Vec<String>,
+
+    /// Spell the sentence in multiple alphabets, with a column per alphabet (e.g.
+    /// "nato,de,fr_FR")
+    #[clap(long, value_name = "ALPHABETS", value_delimiter = ',',
+        value_parser = salph::SpellingAlphabet::validate,
+        conflicts_with_all = ["fallback", "signals", "as_in", "format", "prowords", "group",
+        "phone", "layout", "phonetic", "ipa", "morse", "braille", "tap", "telegraph"])]
+    compare: Vec<String>,
+
+    /// Colors to print code words and input with
+    #[clap(long, value_enum)]
+    theme: Option<ThemeName>,
+
+    /// Separator to use when printing. Defaults to a space
+    #[clap(short = 'S', long)]
+    separator: Option<String>,
+
+    /// Spell spaces between words instead of skipping them
+    #[clap(long)]
+    spell_spaces: bool,
+
+    /// Announce uppercase letters (e.g. "capital Alpha")
+    #[clap(long)]
+    announce_case: bool,
+
+    /// Spell accented letters without a code word by their base letter (e.g. "é"
+    /// as "Echo")
+    #[clap(long)]
+    fold_diacritics: bool,
+
+    /// Spell emoji and other symbols without a code word by their Unicode name (e.g.
+    /// "😀" as "grinning face")
+    #[cfg(feature = "unicode-names")]
+    #[clap(long)]
+    name_symbols: bool,
+
+    /// Transliterate romaji to katakana before spelling, for the Japanese alphabet
+    /// (e.g. "sakura" as "サクラ")
+    #[clap(long)]
+    romaji: bool,
+
+    /// Recognize email addresses and URLs and spell their separators as words (e.g.
+    /// "at", "dot" and "slash") if the alphabet has no code words for them
+    #[clap(long)]
+    smart: bool,
+
+    /// Read out a password: announce the class of every character (e.g. "capital
+    /// Sierra", "lowercase Echo", "digit three" or "symbol dash"), keep symbols
+    /// without a code word and don't keep a history
+    #[clap(long, conflicts_with_all = ["announce_case", "radio_digits", "numbers"])]
+    password: bool,
+
+    /// Spell digits the way pilots and ATC do (e.g. "tree", "fife", "niner")
+    #[clap(long)]
+    radio_digits: bool,
+
+    /// Spell consecutive digits as a number (e.g. "twenty-three")
+    #[clap(long, conflicts_with = "radio_digits")]
+    numbers: bool,
+
+    /// Which variant to use for letters with alternate code words, starting at 0
+    #[clap(long, default_value_t = 0)]
+    variant: usize,
+
+    /// Add a column with the pronunciation of the code words (e.g. "AL-fah")
+    #[clap(long)]
+    phonetic: bool,
+
+    /// Add a column with the IPA transcription of the code words
+    #[clap(long)]
+    ipa: bool,
+
+    /// Add a column with the input in braille
+    #[clap(long)]
+    braille: bool,
+
+    /// Add a column with the input in Morse code
+    #[clap(long)]
+    morse: bool,
+
+    /// Print the maritime signal flag and semaphore positions of every letter
+    #[clap(long)]
+    signals: bool,
+
+    /// Add a column with the input in tap code
+    #[clap(long)]
+    tap: bool,
+
+    /// Add a column with the input in Chinese telegraph code
+    #[clap(long)]
+    telegraph: bool,
+
+    /// Warn about characters that are commonly confused when read out (e.g. 0 and
+    /// O, or B and D) and suggest how to clarify them
+    #[clap(long)]
+    warn_ambiguous: bool,
+
+    /// Fail with a non-zero exit code when a character can't be spelled
+    #[clap(long)]
+    strict: bool,
+
+    /// Output format
+    #[clap(short, long, value_enum, default_value_t = Output::Table)]
+    output: Output,
+
+    /// How the table is laid out
+    #[clap(long, value_enum, default_value_t = Layout::Horizontal)]
+    layout: Layout,
+
+    /// How right-to-left text, like Hebrew and Arabic, is ordered in the table
+    #[clap(long, value_enum, default_value_t = BidiOrder::Logical)]
+    bidi: BidiOrder,
+
+    /// Print every code word on its own line using a template instead of a table
+    /// (e.g. "{char} as in {word}"). Placeholders are {char}, {word}, {kind},
+    /// {index} (counting from 1) and {pronunciation}. Use {{ and }} for braces
+    #[clap(long, value_parser = Template::from_str, conflicts_with_all = ["output", "signals"])]
+    format: Option<Template>,
 
-    /// Disable colored output (word = green , number = yellow)
-    #[clap(short, long)]
-    disable_color: bool,
+    /// Print the sentence as a phrase like "A as in Alpha, B as in Bravo" instead of
+    /// a table
+    #[clap(long, conflicts_with_all = ["output", "signals", "format"])]
+    as_in: bool,
 
-    /// Separator to use when printing
-    #[clap(short = 'S', long, default_value = " ")]
-    separator: String,
+    /// Wrap the spelling in radio procedure words ("I spell", "break" and "over"),
+    /// in the language of the alphabet
+    #[clap(long, conflicts_with_all = ["output", "signals"])]
+    prowords: bool,
+
+    /// Spell the sentence in groups of this many characters, ignoring spaces, as
+    /// used when transmitting coded messages
+    #[clap(long, value_name = "SIZE")]
+    group: Option<NonZeroUsize>,
+
+    /// Word or pause marker printed between groups (see --group and --phone) or
+    /// words (e.g. "break" or "/")
+    #[clap(long, value_name = "WORD")]
+    group_separator: Option<String>,
+
+    /// Read out a phone number: group its digits the way they're written in the
+    /// region of the alphabet and spell them with radio digits
+    #[clap(long, conflicts_with_all = ["signals", "as_in", "format", "group", "phonetic", "ipa",
+        "morse", "braille", "tap", "telegraph", "password"])]
+    phone: bool,
+
+    /// Sizes of the groups of a phone number, instead of the convention of the
+    /// region (e.g. "3-3-4")
+    #[clap(long, value_name = "PATTERN", value_parser = PhonePattern::from_str, requires = "phone")]
+    phone_pattern: Option<PhonePattern>,
+
+    /// Read the code words aloud using the text-to-speech engine of the system
+    #[cfg(feature = "tts")]
+    #[clap(long)]
+    speak: bool,
+
+    /// Speech rate from -100 (slowest) to 100 (fastest). 0 is the normal rate
+    #[cfg(feature = "tts")]
+    #[clap(long, default_value_t = 0, requires = "speak", allow_negative_numbers = true,
+        value_parser = clap::value_parser!(i8).range(-100..=100))]
+    rate: i8,
+
+    /// Pause between spoken code words, in milliseconds
+    #[cfg(feature = "tts")]
+    #[clap(long, default_value_t = 300, requires = "speak")]
+    pause: u64,
+
+    /// Write the code words to a WAV file, assembled from recordings of the words
+    #[cfg(feature = "audio")]
+    #[clap(long)]
+    audio_out: Option<PathBuf>,
+
+    /// Directory with a recording per word (e.g. "alpha.wav"). Defaults to
+    /// ~/.config/salph/recordings
+    #[cfg(feature = "audio")]
+    #[clap(long, requires = "audio_out")]
+    recordings: Option<PathBuf>,
+}
+
+/// What to spell: the sentence or files to read sentences from
+#[derive(clap::Args, Debug)]
+struct InputArgs {
+    sentence: Vec<String>,
+
+    /// Spell every line of a file. Can be given multiple times, "-" reads stdin
+    #[clap(long, conflicts_with = "sentence")]
+    file: Vec<PathBuf>,
+}
+
+/// Spells sentences and prints them according to the arguments
+struct Printer {
+    args: SpellArgs,
+    alphabet: salph::SpellingAlphabet,
+    // Spells with the fallback alphabets, if there are any
+    fallback: Option<salph::FallbackSpeller>,
+    options: salph::SpellingOptions,
+    theme: Theme,
+    strict: bool,
+    // Number of code words printed with a template so far
+    index: usize,
+    // The alphabets of `--compare` with their names
+    comparison: Vec<(String, salph::SpellingAlphabet)>,
+    // Whether a comparison was printed, so terminal tables don't repeat its header
+    compared: bool,
+    #[cfg(feature = "tts")]
+    speaker: Option<Speaker>,
+    // Code words to write to the audio file at the end
+    #[cfg(feature = "audio")]
+    recorded: Vec<salph::Spelling>,
+}
+
+/// Reads code words aloud, see `--speak`
+#[cfg(feature = "tts")]
+struct Speaker {
+    tts: tts::Tts,
+    pause: std::time::Duration,
+}
+
+/// A template for printing a code word, see `--format`
+#[derive(Clone, Debug)]
+struct Template(Vec<Segment>);
+
+/// Sizes of the groups of a phone number, see `--phone-pattern`
+#[derive(Clone, Debug)]
+struct PhonePattern(Vec<usize>);
+
+#[derive(Clone, Debug)]
+enum Segment {
+    Text(String),
+    Char,
+    Word,
+    Kind,
+    Index,
+    Pronunciation,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ThemeName {
+    /// Green letters and yellow digits
+    Default,
+    /// Bright, bold colors for dark terminals
+    HighContrast,
+    /// No colors, with digits and input in bold
+    MonochromeBold,
+}
+
+/// The styles of the different parts of the output
+#[derive(Clone, Debug)]
+struct Theme {
+    letters: Style,
+    digits: Style,
+    /// Style of all code words that aren't letters or digits
+    punctuation: Style,
+    input: Style,
+}
+
+/// A color and text attributes, written like "bright cyan bold"
+#[derive(Clone, Copy, Debug, Default)]
+struct Style {
+    color: Option<colored::Color>,
+    bold: bool,
+    underline: bool,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+enum Export {
+    Json,
+    Csv,
+    Toml,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    /// A row per word, with the code words next to each other
+    Horizontal,
+    /// A row per character, with the code words below each other
+    Vertical,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum BidiOrder {
+    /// Keep the text in reading order, for terminals that support bidirectional text
+    Logical,
+    /// Reverse the text, for terminals that print everything from left to right
+    Visual,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Output {
+    /// Aligned table with one row per word
+    Table,
+    /// JSON array with one object per word
+    Json,
+    /// Markdown table with a header
+    Markdown,
+    /// HTML table with a header
+    Html,
+    /// CSV with a header row
+    Csv,
+}
+
+/// Defaults read from the config file, which apply to settings that aren't given
+/// on the command line or through environment variables
+#[derive(Debug, Default)]
+struct Config {
+    alphabet: Option<String>,
+    separator: Option<String>,
+    color: Option<Color>,
+    theme: Option<Theme>,
+    unmatched: Option<salph::Unmatched>,
+    digits: Option<salph::DigitStyle>,
 }
 
 fn main() {
-    let cli = Args::parse();
+    // User alphabets need to be known before the arguments are validated
+    register_user_alphabets();
+    let cli = Cli::parse();
+    let config = Config::load();
+    colored::control::set_override(use_color(cli.color.or(config.color)));
 
-    // List available alphabets
-    if cli.list_alphabets {
-        list_alphabets();
-        return;
+    match cli.command {
+        Some(Command::Spell { spell, input }) => spell_sentences(spell, input, &config),
+        None => spell_sentences(cli.spell, cli.input, &config),
+        Some(Command::Repl(args)) => repl(args, &config),
+        Some(Command::Decode { alphabet, sentence }) => {
+            let alphabet = alphabet.load(&config);
+            for sentence in sentences(sentence, &[]) {
+                match sentence {
+                    Ok(sentence) => println!("{}", alphabet.spellings_to_str(&sentence.join(" "))),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        Some(Command::Quiz {
+            alphabet,
+            reverse,
+            rounds,
+        }) => quiz(&alphabet.load(&config), reverse, rounds),
+        Some(Command::List {
+            output,
+            include_historical,
+        }) => list_alphabets(output, include_historical),
+        Some(Command::Search { query }) => search_alphabets(&query),
+        Some(Command::Show { alphabet, export }) => {
+            let alphabet = salph::SpellingAlphabet::from_str(&alphabet).unwrap();
+            match export {
+                Some(Export::Json) => println!("{}", alphabet.to_json()),
+                Some(Export::Csv) => print!("{}", alphabet.to_csv()),
+                Some(Export::Toml) => print!("{}", alphabet.to_toml()),
+                None => print_entries(&alphabet),
+            }
+        }
+        Some(Command::ExportAnki {
+            alphabet,
+            pronunciation,
+        }) => {
+            let alphabet = AlphabetArgs {
+                alphabet,
+                ..Default::default()
+            };
+            print!("{}", alphabet.load(&config).to_anki(pronunciation));
+        }
+        Some(Command::Lint { file }) => lint(&file),
+        Some(Command::Diff { from, to }) => diff(&from, &to),
+        Some(Command::Completions { shell }) => completions(shell),
+        Some(Command::Manpage { dir }) => manpage(dir.as_deref()),
+        #[cfg(feature = "server")]
+        Some(Command::Serve { address }) => {
+            eprintln!("Listening on http://{}", address);
+            if let Err(e) = salph::server::serve(&address) {
+                eprintln!("Unable to serve on {}: {}", address, e);
+                std::process::exit(1);
+            }
+        }
     }
+}
 
-    // Show the contents of an alphabet
-    if let Some(alphabet) = cli.show_alphabet {
-        println!("{}", salph::SpellingAlphabet::from_str(&alphabet).unwrap());
-        return;
+impl Config {
+    /// Read the config file, if there is one. Exits with an error if the file can't
+    /// be read or contains invalid settings.
+    fn load() -> Config {
+        let Some(path) = config_path() else {
+            return Config::default();
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Config::default(),
+            Err(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        };
+        Config::parse(&text).unwrap_or_else(|e| {
+            eprintln!("{}: {}", path.display(), e);
+            std::process::exit(1);
+        })
     }
 
-    // Select current alphabet
-    let alphabet = salph::SpellingAlphabet::from_str(&cli.alphabet).unwrap();
+    /// Parse the settings of a config file
+    fn parse(s: &str) -> Result<Config, String> {
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let string = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::String(value)) => Ok(Some(value.clone())),
+            Some(_) => Err(format!("'{}' must be a string", key)),
+        };
+        let mut config = Config {
+            alphabet: string("alphabet")?
+                .map(|a| validate_alphabet(&a))
+                .transpose()?,
+            separator: string("separator")?,
+            ..Config::default()
+        };
+        config.color = string("color")?
+            .map(|color| Color::from_str(&color, true))
+            .transpose()
+            .map_err(|_| "'color' must be auto, always or never".to_string())?;
+        config.theme = match table.get("theme") {
+            None => None,
+            Some(toml::Value::String(name)) => Some(
+                ThemeName::from_str(name, true)
+                    .map(Theme::new)
+                    .map_err(|_| format!("unknown theme '{}'", name))?,
+            ),
+            Some(toml::Value::Table(styles)) => Some(Theme::parse(styles)?),
+            Some(_) => return Err("'theme' must be a name or a table of styles".to_string()),
+        };
+        let placeholder = string("placeholder")?;
+        config.unmatched = match string("unmatched")?.as_deref() {
+            None => None,
+            Some("skip") => Some(salph::Unmatched::Skip),
+            Some("keep") => Some(salph::Unmatched::Keep),
+            Some("replace") => Some(salph::Unmatched::Replace(
+                placeholder.unwrap_or_else(|| "?".to_string()),
+            )),
+            Some("error") => Some(salph::Unmatched::Error),
+            Some(unmatched) => return Err(format!("unknown unmatched policy '{}'", unmatched)),
+        };
+        config.digits = match string("digits")?.as_deref() {
+            None => None,
+            Some("alphabet") => Some(salph::DigitStyle::Alphabet),
+            Some("radio") => Some(salph::DigitStyle::Icao),
+            Some("numbers") => Some(salph::DigitStyle::Number),
+            Some(digits) => return Err(format!("unknown digit style '{}'", digits)),
+        };
+        if let Some(key) = table
+            .keys()
+            .find(|key| !CONFIG_KEYS.contains(&key.as_str()))
+        {
+            return Err(format!("unknown setting '{}'", key));
+        }
+        Ok(config)
+    }
+}
 
-    // Read the sentence from either stdin or arguments
-    let sentence: Vec<String> = if cli.sentence.is_empty() {
-        read_from_stdin()
-    } else {
-        cli.sentence.into_iter().collect()
-    };
+/// Settings that can be used in the config file
+const CONFIG_KEYS: &[&str] = &[
+    "alphabet",
+    "separator",
+    "color",
+    "theme",
+    "unmatched",
+    "placeholder",
+    "digits",
+];
 
-    // Create a table with every letter mapped to a word from the alphabet
-    let mut table = Table::new("{:<}  {:<}");
-    for word in sentence {
-        let spellings = alphabet
-            .str_to_spellings(&word)
+impl Theme {
+    fn new(name: ThemeName) -> Theme {
+        let style = |s: &str| Style::from_str(s).expect("built-in styles are valid");
+        match name {
+            ThemeName::Default => Theme {
+                letters: style("green"),
+                digits: style("yellow"),
+                punctuation: style("green"),
+                input: style("bright cyan bold"),
+            },
+            ThemeName::HighContrast => Theme {
+                letters: style("bright white bold"),
+                digits: style("bright yellow bold"),
+                punctuation: style("bright magenta bold"),
+                input: style("bright cyan bold underline"),
+            },
+            ThemeName::MonochromeBold => Theme {
+                letters: style(""),
+                digits: style("bold"),
+                punctuation: style(""),
+                input: style("bold underline"),
+            },
+        }
+    }
+
+    /// Parse a theme from the config file. Parts that aren't set use the style of
+    /// the default theme.
+    fn parse(styles: &toml::Table) -> Result<Theme, String> {
+        let mut theme = Theme::new(ThemeName::Default);
+        for (part, style) in styles {
+            let toml::Value::String(style) = style else {
+                return Err(format!("the style of '{}' must be a string", part));
+            };
+            let style = Style::from_str(style)?;
+            match part.as_str() {
+                "letters" => theme.letters = style,
+                "digits" => theme.digits = style,
+                "punctuation" => theme.punctuation = style,
+                "input" => theme.input = style,
+                _ => return Err(format!("unknown theme part '{}'", part)),
+            }
+        }
+        Ok(theme)
+    }
+
+    /// Style a code word according to what it spells
+    fn paint(&self, spelling: &salph::Spelling) -> String {
+        let style = match spelling.kind {
+            salph::SpellingKind::Letter => self.letters,
+            salph::SpellingKind::Digit => self.digits,
+            _ => self.punctuation,
+        };
+        style.paint(&spelling.to_string())
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Style, String> {
+        let mut style = Style::default();
+        let mut words = s.split_whitespace();
+        while let Some(word) = words.next() {
+            match word {
+                "bold" => style.bold = true,
+                "underline" => style.underline = true,
+                _ => {
+                    // Bright colors are written as two words
+                    let color = match word {
+                        "bright" => format!("bright {}", words.next().unwrap_or_default()),
+                        _ => word.to_string(),
+                    };
+                    let color = colored::Color::from_str(&color)
+                        .map_err(|_| format!("unknown color '{}'", color))?;
+                    style.color = Some(color);
+                }
+            }
+        }
+        Ok(style)
+    }
+}
+
+impl Style {
+    fn paint(&self, s: &str) -> String {
+        let mut painted = s.normal();
+        if let Some(color) = self.color {
+            painted = painted.color(color);
+        }
+        if self.bold {
+            painted = painted.bold();
+        }
+        if self.underline {
+            painted = painted.underline();
+        }
+        painted.to_string()
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Template, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let (name, rest) = chars
+                        .as_str()
+                        .split_once('}')
+                        .ok_or("unclosed placeholder")?;
+                    let segment = match name {
+                        "char" => Segment::Char,
+                        "word" => Segment::Word,
+                        "kind" => Segment::Kind,
+                        "index" => Segment::Index,
+                        "pronunciation" => Segment::Pronunciation,
+                        _ => return Err(format!("unknown placeholder {{{}}}", name)),
+                    };
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                    segments.push(segment);
+                    chars = rest.chars();
+                }
+                '}' => return Err("unmatched }, use }} for a brace".to_string()),
+                _ => text.push(c),
+            }
+        }
+        segments.push(Segment::Text(text));
+        Ok(Template(segments))
+    }
+}
+
+impl FromStr for PhonePattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<PhonePattern, String> {
+        let sizes = s
+            .split(['-', ' '])
+            .filter(|size| !size.is_empty())
+            .map(|size| size.parse().ok().filter(|size| *size > 0))
+            .collect::<Option<Vec<usize>>>()
+            .filter(|sizes| !sizes.is_empty());
+        sizes
+            .map(PhonePattern)
+            .ok_or_else(|| format!("Invalid pattern {}, expected group sizes like 3-3-4", s))
+    }
+}
+
+impl Template {
+    /// Fill in the template for the `index`th code word. Code words without a
+    /// pronunciation use the code word as pronunciation.
+    fn render(&self, spelling: &salph::Spelling, index: usize, theme: &Theme) -> String {
+        self.0
             .iter()
-            .map(|w| {
-                if cli.disable_color {
-                    w.to_string()
-                } else if w.is_number {
-                    w.spelling.yellow().to_string()
-                } else {
-                    w.spelling.green().to_string()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Char => spelling.source.clone(),
+                Segment::Word => theme.paint(spelling),
+                Segment::Kind => spelling.kind.to_string(),
+                Segment::Index => index.to_string(),
+                Segment::Pronunciation => spelling
+                    .pronunciation
+                    .clone()
+                    .unwrap_or_else(|| spelling.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// Whether to print colors. Without a choice, colors are used if stdout is a
+/// terminal and NO_COLOR isn't set (see https://no-color.org).
+fn use_color(color: Option<Color>) -> bool {
+    match color.unwrap_or(Color::Auto) {
+        Color::Always => true,
+        Color::Never => false,
+        Color::Auto => env_var("NO_COLOR").is_none() && std::io::stdout().is_terminal(),
+    }
+}
+
+/// The path of the config file: $SALPH_CONFIG, or config.toml in the config
+/// directory
+fn config_path() -> Option<PathBuf> {
+    match env_var("SALPH_CONFIG") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => config_dir().map(|dir| dir.join("config.toml")),
+    }
+}
+
+/// The config directory of salph: salph in $XDG_CONFIG_HOME or ~/.config
+fn config_dir() -> Option<PathBuf> {
+    let dir = env_var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env_var("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(dir.join("salph"))
+}
+
+/// An environment variable, if it's set and not empty
+fn env_var(name: &str) -> Option<std::ffi::OsString> {
+    std::env::var_os(name).filter(|value| !value.is_empty())
+}
+
+/// Register the alphabet files in the directories of $SALPH_ALPHABET_PATH and in
+/// the alphabets directory of the config directory, named after the file without
+/// its extension. If multiple directories contain an alphabet with the same name,
+/// the first one is used. Files that can't be loaded are reported and skipped.
+fn register_user_alphabets() {
+    let dirs = env_var("SALPH_ALPHABET_PATH")
+        .map(|path| std::env::split_paths(&path).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .chain(config_dir().map(|dir| dir.join("alphabets")));
+
+    let mut registered = std::collections::HashSet::new();
+    for dir in dirs {
+        // Missing directories are fine, there just aren't any user alphabets
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut files = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        files.sort();
+        for file in files {
+            let Some(name) = file.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') || registered.contains(name) {
+                continue;
+            }
+            let result = salph::SpellingAlphabet::from_file(&file)
+                .and_then(|alphabet| salph::register(name, alphabet));
+            match result {
+                Ok(()) => {
+                    registered.insert(name.to_string());
+                }
+                Err(e) => eprintln!("{}: {}", file.display(), e),
+            }
+        }
+    }
+}
+
+impl AlphabetArgs {
+    /// Load the selected alphabet, with any overrides applied. Without an
+    /// alphabet on the command line, the one from the config file is used.
+    fn load(&self, config: &Config) -> salph::SpellingAlphabet {
+        let name = self
+            .alphabet
+            .as_deref()
+            .or(config.alphabet.as_deref())
+            .unwrap_or("auto");
+        let alphabet = if let Some(file) = &self.alphabet_file {
+            load_file(file)
+        } else if name == "auto" {
+            salph::SpellingAlphabet::from_locale(&system_locale())
+        } else {
+            salph::SpellingAlphabet::from_str(name).unwrap()
+        };
+
+        match &self.override_file {
+            Some(file) => alphabet.overlay(&load_file(file)),
+            None => alphabet,
+        }
+    }
+}
+
+/// Spell the sentence or the lines of the input files and print the result
+fn spell_sentences(args: SpellArgs, input: InputArgs, config: &Config) {
+    let mut printer = Printer::new(args, config);
+    printer.begin();
+    // Unreadable files are reported, after which the other files are spelled
+    let mut failed = false;
+    for sentence in sentences(input.sentence, &input.file) {
+        match sentence {
+            Ok(sentence) => {
+                if let Err(e) = printer.print(sentence) {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
                 }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+    printer.end();
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+impl Printer {
+    /// Build a speller that tries the fallback alphabets after the primary one, if
+    /// there are any fallbacks
+    fn fallback_speller(
+        alphabet: &salph::SpellingAlphabet,
+        fallbacks: &[String],
+        config: &Config,
+    ) -> Option<salph::FallbackSpeller> {
+        let name = |alphabet: &salph::SpellingAlphabet| alphabet.metadata().name;
+        (!fallbacks.is_empty()).then(|| {
+            fallbacks.iter().fold(
+                salph::FallbackSpeller::new(name(alphabet).unwrap_or_default(), alphabet.clone()),
+                |speller, fallback| {
+                    let alphabet = AlphabetArgs {
+                        alphabet: Some(fallback.clone()),
+                        ..Default::default()
+                    }
+                    .load(config);
+                    speller.fallback(name(&alphabet).unwrap_or(fallback.clone()), alphabet)
+                },
+            )
+        })
+    }
+
+    fn new(mut args: SpellArgs, config: &Config) -> Printer {
+        let alphabet = args.alphabet.load(config);
+        let fallback = Printer::fallback_speller(&alphabet, &args.fallback, config);
+        let comparison = args
+            .compare
+            .iter()
+            .map(|name| {
+                let alphabet = salph::SpellingAlphabet::from_str(name).unwrap_or_else(|e| {
+                    eprintln!("Unable to load alphabet {}: {}", name, e);
+                    std::process::exit(1);
+                });
+                (name.clone(), alphabet)
             })
-            .collect::<Vec<String>>()
-            .join(&cli.separator);
-        let word = if cli.disable_color {
-            word.clone()
+            .collect();
+
+        let mut unmatched = config.unmatched.clone().unwrap_or_default();
+        // Dropping characters of a password would make it useless
+        if args.password && unmatched == salph::Unmatched::Skip {
+            unmatched = salph::Unmatched::Keep;
+        }
+        let options = salph::SpellingOptions::new()
+            .spell_spaces(args.spell_spaces)
+            .announce_case(args.announce_case)
+            .fold_diacritics(args.fold_diacritics)
+            .announce_class(args.password)
+            .spell_addresses(args.smart)
+            .variant(args.variant)
+            .unmatched(unmatched.clone())
+            .digit_style(if args.radio_digits {
+                salph::DigitStyle::Icao
+            } else if args.numbers {
+                salph::DigitStyle::Number
+            } else if args.password {
+                // Passwords are read digit by digit
+                salph::DigitStyle::Alphabet
+            } else {
+                config.digits.unwrap_or_default()
+            });
+        #[cfg(feature = "unicode-names")]
+        let options = options.name_symbols(args.name_symbols);
+
+        // Settings from the config file only apply if they aren't given as flags
+        args.separator = args.separator.or_else(|| config.separator.clone());
+        let theme = match args.theme {
+            Some(name) => Theme::new(name),
+            None => config
+                .theme
+                .clone()
+                .unwrap_or_else(|| Theme::new(ThemeName::Default)),
+        };
+
+        #[cfg(feature = "tts")]
+        let speaker = args.speak.then(|| {
+            Speaker::new(&alphabet, args.rate, args.pause).unwrap_or_else(|e| {
+                eprintln!("Unable to start text-to-speech: {}", e);
+                std::process::exit(1);
+            })
+        });
+
+        Printer {
+            strict: args.strict || unmatched == salph::Unmatched::Error,
+            args,
+            alphabet,
+            fallback,
+            options,
+            theme,
+            index: 0,
+            comparison,
+            compared: false,
+            #[cfg(feature = "tts")]
+            speaker,
+            #[cfg(feature = "audio")]
+            recorded: Vec::new(),
+        }
+    }
+
+    /// Whether sentences are printed as parts of a single table
+    fn is_table(&self) -> bool {
+        !self.args.signals
+            && !self.args.as_in
+            && self.args.format.is_none()
+            && self.args.output != Output::Json
+    }
+
+    /// Print what comes before the first sentence, like the header of a table
+    fn begin(&self) {
+        if self.is_table() {
+            print_header(self.args.output, &table_header(&self.args));
+        }
+    }
+
+    /// Print the spelling of a sentence. Fails in strict mode if a character can't
+    /// be spelled.
+    fn print(&mut self, sentence: Vec<String>) -> Result<(), String> {
+        let (args, alphabet, options) = (&self.args, &self.alphabet, &self.options);
+        // Code words are spelled by the alphabet and its fallbacks, if there are any
+        let speller: &dyn Speller = match &self.fallback {
+            Some(fallback) => fallback,
+            None => alphabet,
+        };
+        let sentence = if args.romaji {
+            sentence
+                .iter()
+                .map(|word| salph::kana::from_romaji(word))
+                .collect()
+        } else {
+            sentence
+        };
+        // When spelling spaces, the whole sentence is spelled as a single row. When
+        // grouping, every group is spelled as a word.
+        let sentence = if let Some(size) = args.group {
+            alphabet
+                .groups_with(&sentence.join(" "), size.get(), options)
+                .map_err(|e| e.to_string())?
+                .iter()
+                .map(|group| group.iter().map(|s| s.source.as_str()).collect())
+                .collect()
+        } else if args.spell_spaces {
+            vec![sentence.join(" ")]
+        } else {
+            sentence
+        };
+
+        // Refuse to print lossy output in strict mode
+        if self.strict {
+            for word in &sentence {
+                let spelled = match &self.fallback {
+                    // Only characters that none of the alphabets can spell are refused
+                    Some(fallback) => fallback
+                        .spell_with(word, &options.clone().unmatched(salph::Unmatched::Error))
+                        .map(drop)
+                        .map_err(|e| e.to_string()),
+                    None => alphabet
+                        .try_str_to_spellings_with(word, options)
+                        .map(drop)
+                        .map_err(|e| e.to_string()),
+                };
+                spelled.map_err(|e| format!("{}: {}", word, e))?;
+            }
+        }
+
+        // Words or groups are separated by the separator word, if there is one
+        let prowords = args.prowords.then(|| alphabet.prowords());
+        let separator =
+            (args.group_separator.clone()).or_else(|| Some(prowords.as_ref()?.separator.clone()));
+        if let Some(prowords) = &prowords {
+            println!("{}", prowords.intro);
+        }
+
+        if args.phone {
+            let region = alphabet.metadata().region;
+            let pattern = match &args.phone_pattern {
+                Some(PhonePattern(pattern)) => pattern.as_slice(),
+                None => salph::phone::pattern(region.as_deref().unwrap_or_default()),
+            };
+            let separator = separator.as_deref().unwrap_or("(pause)");
+            print_phone(&sentence.join(" "), pattern, separator, &self.theme, args);
+        } else if args.signals {
+            print_signals(speller, &sentence, options);
+        } else if args.as_in {
+            let phrase = alphabet
+                .as_in_with(&sentence.join(" "), options)
+                .map_err(|e| e.to_string())?;
+            println!("{}", phrase);
+        } else if let Some(template) = &args.format {
+            for (i, word) in sentence.iter().enumerate() {
+                if let (true, Some(separator)) = (i > 0, &separator) {
+                    println!("{}", separator);
+                }
+                for spelling in spell(speller, word, options) {
+                    self.index += 1;
+                    println!("{}", template.render(&spelling, self.index, &self.theme));
+                }
+            }
+        } else if !args.compare.is_empty() {
+            // Other formats print the header at the beginning
+            let header =
+                (args.output == Output::Table && !self.compared).then(|| table_header(args));
+            print_comparison(&sentence, &self.comparison, options, header, args.output)
+                .map_err(|e| e.to_string())?;
+            self.compared = true;
+        } else if args.output == Output::Json {
+            print_json(speller, &sentence, options);
+        } else {
+            // Only terminal tables are colored
+            let theme = (args.output == Output::Table).then_some(&self.theme);
+            let mut rows = Vec::new();
+            for (i, word) in sentence.iter().enumerate() {
+                // The separator gets a row of its own between groups
+                if let (true, Some(separator)) = (i > 0, &separator) {
+                    let mut row = vec![String::new(); table_header(args).len()];
+                    row[1] = separator.clone();
+                    rows.push(row);
+                }
+                let word = std::slice::from_ref(word);
+                rows.extend(spelling_rows(speller, word, options, theme, args));
+            }
+            if args.output == Output::Table {
+                for cell in rows.iter_mut().flatten() {
+                    *cell = bidi_cell(cell, args.bidi);
+                }
+            }
+            print_rows(args.output, &rows);
+        }
+        if let Some(prowords) = &prowords {
+            println!("{}", prowords.end);
+        }
+        if args.warn_ambiguous {
+            print_ambiguities(alphabet, &sentence.join(" "));
+        }
+
+        #[cfg(any(feature = "tts", feature = "audio"))]
+        let spellings = sentence
+            .iter()
+            .flat_map(|word| spell(speller, word, options))
+            .collect::<Vec<_>>();
+        #[cfg(feature = "tts")]
+        if let Some(speaker) = &mut self.speaker {
+            speaker
+                .say(&spellings)
+                .map_err(|e| format!("Unable to speak: {}", e))?;
+        }
+        #[cfg(feature = "audio")]
+        if args.audio_out.is_some() {
+            self.recorded.extend(spellings);
+        }
+        Ok(())
+    }
+
+    /// Print what comes after the last sentence, like the end of a table, and
+    /// write the audio file
+    fn end(&self) {
+        if self.is_table() {
+            print_footer(self.args.output);
+        }
+
+        #[cfg(feature = "audio")]
+        if let Some(path) = &self.args.audio_out {
+            let Some(dir) =
+                (self.args.recordings.clone()).or_else(|| Some(config_dir()?.join("recordings")))
+            else {
+                eprintln!("No directory with recordings, use --recordings");
+                std::process::exit(1);
+            };
+            let recordings = salph::audio::Recordings::new(dir);
+            if let Err(e) = recordings.write(&self.recorded, path) {
+                eprintln!("Unable to write {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tts")]
+impl Speaker {
+    /// Start the text-to-speech engine. A voice for the language of the alphabet is
+    /// used if there is one. The rate is relative to the range of the engine.
+    fn new(
+        alphabet: &salph::SpellingAlphabet,
+        rate: i8,
+        pause: u64,
+    ) -> Result<Speaker, tts::Error> {
+        let mut tts = tts::Tts::default()?;
+        let features = tts.supported_features();
+        if features.rate {
+            let (normal, rate) = (tts.normal_rate(), f32::from(rate) / 100.0);
+            let limit = if rate < 0.0 {
+                tts.min_rate()
+            } else {
+                tts.max_rate()
+            };
+            tts.set_rate(normal + (limit - normal) * rate.abs())?;
+        }
+        if let (true, Some(language)) = (features.voice, alphabet.metadata().language) {
+            let voice = tts
+                .voices()?
+                .into_iter()
+                .find(|voice| voice.language().primary_language() == language);
+            if let Some(voice) = voice {
+                tts.set_voice(&voice)?;
+            }
+        }
+        Ok(Speaker {
+            tts,
+            pause: std::time::Duration::from_millis(pause),
+        })
+    }
+
+    /// Say the code words one at a time, waiting for every word to finish
+    fn say(&mut self, spellings: &[salph::Spelling]) -> Result<(), tts::Error> {
+        // Engines that can't tell whether they're speaking queue the words instead
+        let wait = self.tts.supported_features().is_speaking;
+        for spelling in spellings {
+            self.tts.speak(spelling.to_string(), false)?;
+            while wait && self.tts.is_speaking()? {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            std::thread::sleep(self.pause);
+        }
+        Ok(())
+    }
+}
+
+/// Spell sentences entered at a prompt until the input ends. Lines starting with
+/// `:` are commands, see `REPL_HELP`. History is kept in the config directory,
+/// except when reading out passwords.
+fn repl(args: SpellArgs, config: &Config) {
+    let keep_history = !args.password;
+    let mut printer = Printer::new(args, config);
+    let mut editor = rustyline::DefaultEditor::new().unwrap_or_else(|e| {
+        eprintln!("Unable to start the prompt: {}", e);
+        std::process::exit(1);
+    });
+    let history = (config_dir().filter(|_| keep_history)).map(|dir| dir.join("history"));
+    if let Some(history) = &history {
+        // There's no history the first time
+        let _ = editor.load_history(history);
+    }
+
+    println!("Enter a sentence to spell it, or :help for commands");
+    let mut decode = false;
+    loop {
+        let line = match editor.readline("salph> ") {
+            Ok(line) => line,
+            // Ctrl-C discards the current line
+            Err(rustyline::error::ReadlineError::Interrupted) => continue,
+            Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("Unable to read input: {}", e);
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if keep_history {
+            let _ = editor.add_history_entry(line);
+        }
+
+        let result = match line.strip_prefix(':') {
+            Some(command) => match command.split_whitespace().collect::<Vec<_>>()[..] {
+                ["alphabet"] => {
+                    let name = printer.alphabet.metadata().name;
+                    println!("{}", name.as_deref().unwrap_or("Custom alphabet"));
+                    Ok(())
+                }
+                ["alphabet", name] => validate_alphabet(name).map(|name| {
+                    printer.alphabet = if name == "auto" {
+                        salph::SpellingAlphabet::from_locale(&system_locale())
+                    } else {
+                        salph::SpellingAlphabet::from_str(&name).unwrap()
+                    };
+                    // The fallbacks are tried after the new alphabet
+                    printer.fallback = Printer::fallback_speller(
+                        &printer.alphabet,
+                        &printer.args.fallback,
+                        config,
+                    );
+                }),
+                ["decode", "on"] => {
+                    decode = true;
+                    Ok(())
+                }
+                ["decode", "off"] => {
+                    decode = false;
+                    Ok(())
+                }
+                ["help"] => {
+                    println!("{}", REPL_HELP);
+                    Ok(())
+                }
+                ["quit"] | ["q"] => break,
+                _ => Err(format!("Unknown command :{}, see :help", command)),
+            },
+            None if decode => {
+                println!("{}", printer.alphabet.spellings_to_str(line));
+                Ok(())
+            }
+            None => {
+                let words = line.split_whitespace().map(String::from).collect();
+                printer.begin();
+                let result = printer.print(words);
+                printer.end();
+                result
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+        }
+    }
+
+    if let Some(history) = &history {
+        // Losing the history isn't worth failing for
+        let _ = std::fs::create_dir_all(config_dir().unwrap_or_default());
+        let _ = editor.save_history(history);
+    }
+}
+
+/// Ask for the code words of random letters (or for the letters of random code
+/// words in reverse mode) and print the score and the missed letters at the end.
+/// Letters are drawn without repetition until all of them have been asked.
+fn quiz(alphabet: &salph::SpellingAlphabet, reverse: bool, rounds: usize) {
+    let mut letters = alphabet
+        .entries()
+        .filter(|(key, _)| salph::SpellingKind::classify(key) == salph::SpellingKind::Letter)
+        .map(|(key, word)| (key.to_string(), word.to_string()))
+        .collect::<Vec<_>>();
+    if letters.is_empty() {
+        eprintln!("The alphabet has no letters to practice");
+        std::process::exit(1);
+    }
+
+    let mut answers = stdin().lines();
+    let mut missed = Vec::new();
+    let mut asked = 0;
+    let mut deck = Vec::new();
+    while asked < rounds {
+        if deck.is_empty() {
+            fastrand::shuffle(&mut letters);
+            deck = letters.clone();
+        }
+        let (key, word) = deck.pop().expect("the deck was refilled");
+        asked += 1;
+        if reverse {
+            print!("[{}/{}] Which letter is {}? ", asked, rounds, word.bold());
         } else {
-            word.bright_cyan().bold().to_string()
+            let letter = key.to_uppercase();
+            print!(
+                "[{}/{}] What's the code word for {}? ",
+                asked,
+                rounds,
+                letter.bold()
+            );
+        }
+        // The question needs to be visible before the answer is typed
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+
+        let Some(Ok(answer)) = answers.next() else {
+            println!();
+            asked -= 1;
+            break;
         };
-        table.add_row(Row::new().with_cell(&word).with_cell(spellings));
+        // Alternates and different casing are accepted as well
+        let correct = if reverse {
+            answer.trim().to_lowercase() == key
+        } else {
+            alphabet.spellings_to_str(&answer).to_lowercase() == key
+        };
+        if correct {
+            println!("{}", "Correct!".green());
+        } else {
+            let expected = if reverse {
+                key.to_uppercase()
+            } else {
+                word.clone()
+            };
+            println!("{} It's {}", "Wrong.".red(), expected);
+            missed.push((key, word));
+        }
+    }
+
+    println!();
+    println!("Score: {}/{}", asked - missed.len(), asked);
+    if !missed.is_empty() {
+        let missed = missed
+            .iter()
+            .map(|(key, word)| format!("{} ({})", key.to_uppercase(), word))
+            .collect::<Vec<_>>();
+        println!("Missed: {}", missed.join(", "));
+    }
+}
+
+/// Commands of the interactive prompt
+const REPL_HELP: &str = "\
+:alphabet         Show the current alphabet
+:alphabet <name>  Switch to another alphabet
+:decode on|off    Decode code words instead of spelling
+:help             Show this help
+:quit             Quit (or press Ctrl-D)";
+
+/// Print the start of a table in the given output format: the header for formats
+/// that have one
+fn print_header(output: Output, header: &[String]) {
+    match output {
+        Output::Markdown => {
+            println!("{}", markdown_line(header));
+            println!("{}", markdown_line(&vec!["---"; header.len()]));
+        }
+        Output::Html => {
+            println!("<table>");
+            println!("  <thead>");
+            println!("    {}", html_line("th", header));
+            println!("  </thead>");
+            println!("  <tbody>");
+        }
+        Output::Csv => print_csv(&[header]),
+        Output::Table | Output::Json => {}
+    }
+}
+
+/// Print rows of a table in the given output format
+fn print_rows(output: Output, rows: &[Vec<String>]) {
+    match output {
+        Output::Markdown => {
+            for row in rows {
+                // Pipes would end the cell
+                let row = row
+                    .iter()
+                    .map(|c| c.replace('|', "\\|"))
+                    .collect::<Vec<_>>();
+                println!("{}", markdown_line(&row));
+            }
+        }
+        Output::Html => {
+            for row in rows {
+                println!("    {}", html_line("td", row));
+            }
+        }
+        Output::Csv => print_csv(rows),
+        Output::Table | Output::Json => print!("{}", format_table(rows)),
+    }
+}
+
+/// Print the entries of an alphabet as a table, with a labelled column for the
+/// alternates, pronunciations and IPA transcriptions if the alphabet has them
+fn print_entries(alphabet: &salph::SpellingAlphabet) {
+    let mut rows: Vec<Vec<String>> = alphabet
+        .entries()
+        .map(|(key, word)| {
+            // A key always matches itself, so it's spelled by its own entry
+            let spelling = alphabet.str_to_spellings(key).into_iter().next();
+            let spelling = spelling.as_ref();
+            vec![
+                key.to_uppercase(),
+                word.to_string(),
+                spelling
+                    .map(|s| s.alternates.join(", "))
+                    .unwrap_or_default(),
+                spelling
+                    .and_then(|s| s.pronunciation.clone())
+                    .unwrap_or_default(),
+                spelling.and_then(|s| s.ipa.clone()).unwrap_or_default(),
+            ]
+        })
+        .collect();
+    let header = ["Key", "Code word", "Alternates", "Pronunciation", "IPA"];
+    rows.insert(0, header.iter().map(|h| h.to_string()).collect());
+
+    // Leave out the columns no entry has a value for
+    let used: Vec<bool> = (0..header.len())
+        .map(|i| i < 2 || rows.iter().skip(1).any(|row| !row[i].is_empty()))
+        .collect();
+    let rows: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .zip(&used)
+                .filter(|(_, used)| **used)
+                .map(|(cell, _)| cell)
+                .collect()
+        })
+        .collect();
+    print!("{}", format_table(&rows));
+}
+
+/// Format rows as a terminal table with aligned columns, separated by two spaces
+fn format_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| display_width(cell))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let mut table = String::new();
+    for row in rows {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            line.push_str(cell);
+            // The last column isn't padded
+            if i + 1 < row.len() {
+                line.push_str(&" ".repeat(widths[i] - display_width(cell)));
+            }
+        }
+        table.push_str(line.trim_end_matches(' '));
+        table.push('\n');
+    }
+    table
+}
+
+/// The number of terminal columns a string takes up. Wide characters, like CJK
+/// characters and most emoji, take up two columns and colors take up none.
+fn display_width(s: &str) -> usize {
+    let mut visible = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the escape sequence of a color, which ends in a letter
+            chars.find(char::is_ascii_alphabetic);
+        } else {
+            visible.push(c);
+        }
+    }
+    visible.width()
+}
+
+/// Prepare a cell of a terminal table that contains right-to-left text
+fn bidi_cell(cell: &str, order: BidiOrder) -> String {
+    if !cell.chars().any(is_rtl) {
+        return cell.to_string();
+    }
+    match order {
+        // Isolate the text, so terminals don't let it flow into the next column
+        BidiOrder::Logical => format!("\u{2068}{}\u{2069}", cell),
+        BidiOrder::Visual => visual_order(cell),
     }
-    print!("{}", table);
 }
 
-/// Read a sentence from stdin and convert it to a Vector of Strings
-fn read_from_stdin() -> Vec<String> {
-    let mut input = String::new();
-    stdin().read_line(&mut input).unwrap();
-    input.trim().split(' ').map(|s| s.to_string()).collect()
+/// Reverse the runs of right-to-left text in a left-to-right line, including the
+/// spaces and punctuation between their characters, so they read correctly in
+/// terminals without support for bidirectional text
+fn visual_order(s: &str) -> String {
+    let graphemes = s.graphemes(true).collect::<Vec<_>>();
+    let rtl = |g: &str| g.chars().next().is_some_and(is_rtl);
+    let neutral = |g: &str| !g.chars().any(char::is_alphanumeric);
+    let mut ordered = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < graphemes.len() {
+        if !rtl(graphemes[i]) {
+            ordered.push_str(graphemes[i]);
+            i += 1;
+            continue;
+        }
+        // A run ends at the last right-to-left grapheme before one that isn't
+        // neutral
+        let mut end = i + 1;
+        let mut j = end;
+        while j < graphemes.len() && (rtl(graphemes[j]) || neutral(graphemes[j])) {
+            j += 1;
+            if rtl(graphemes[j - 1]) {
+                end = j;
+            }
+        }
+        graphemes[i..end]
+            .iter()
+            .rev()
+            .for_each(|g| ordered.push_str(g));
+        i = end;
+    }
+    ordered
+}
+
+/// Whether a character belongs to a right-to-left script, like Hebrew or Arabic
+fn is_rtl(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}')
+}
+
+/// Print the end of a table in the given output format
+fn print_footer(output: Output) {
+    if output == Output::Html {
+        println!("  </tbody>");
+        println!("</table>");
+    }
+}
+
+/// A row of a Markdown table
+fn markdown_line(cells: &[impl AsRef<str>]) -> String {
+    let cells = cells.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// A row of an HTML table, with cells of the given tag
+fn html_line(tag: &str, cells: &[impl AsRef<str>]) -> String {
+    let cells = cells
+        .iter()
+        .map(|cell| {
+            let cell = cell
+                .as_ref()
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;");
+            format!("<{}>{}</{}>", tag, cell, tag)
+        })
+        .collect::<String>();
+    format!("<tr>{}</tr>", cells)
+}
+
+/// Print rows as CSV
+fn print_csv<R: AsRef<[C]>, C: AsRef<[u8]>>(rows: &[R]) {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    let result = rows
+        .iter()
+        .try_for_each(|row| writer.write_record(row.as_ref()))
+        .and_then(|_| writer.flush().map_err(csv::Error::from));
+    if let Err(e) = result {
+        eprintln!("Unable to write CSV: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// The header of the spelling table, see [`spelling_rows`]
+fn table_header(cli: &SpellArgs) -> Vec<String> {
+    if !cli.compare.is_empty() {
+        let alphabets = cli.compare.iter().cloned();
+        return std::iter::once("Input".to_string())
+            .chain(alphabets)
+            .collect();
+    }
+    let mut header = match cli.layout {
+        Layout::Horizontal => vec!["Input", "Code words"],
+        Layout::Vertical => vec!["Character", "Code word"],
+    };
+    let extras = [
+        (cli.phonetic, "Pronunciation"),
+        (cli.ipa, "IPA"),
+        (cli.morse, "Morse"),
+        (cli.braille, "Braille"),
+        (cli.tap, "Tap code"),
+        (cli.telegraph, "Telegraph code"),
+    ];
+    header.extend(
+        extras
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, name)| name),
+    );
+    header.into_iter().map(str::to_string).collect()
+}
+
+/// The rows of the spelling table, with a column for the input, the code words and
+/// every enabled extra. The horizontal layout has a row per word, the vertical
+/// layout a row per character. The input and code words are styled if a theme is
+/// given.
+fn spelling_rows(
+    speller: &dyn Speller,
+    sentence: &[String],
+    options: &salph::SpellingOptions,
+    theme: Option<&Theme>,
+    cli: &SpellArgs,
+) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for word in sentence {
+        let spellings = spell(speller, word, options);
+        match cli.layout {
+            Layout::Horizontal => rows.push(spelling_row(word, &spellings, theme, cli)),
+            Layout::Vertical => rows.extend(spellings.iter().map(|spelling| {
+                spelling_row(&spelling.source, std::slice::from_ref(spelling), theme, cli)
+            })),
+        }
+    }
+    rows
+}
+
+/// A row of the spelling table with the spellings of `input`
+fn spelling_row(
+    input: &str,
+    spellings: &[salph::Spelling],
+    theme: Option<&Theme>,
+    cli: &SpellArgs,
+) -> Vec<String> {
+    let separator = cli.separator.as_deref().unwrap_or(" ");
+    let words = spellings
+        .iter()
+        .map(|w| theme.map_or_else(|| w.to_string(), |theme| theme.paint(w)))
+        .collect::<Vec<String>>()
+        .join(separator);
+    let painted = theme.map_or_else(|| input.to_string(), |theme| theme.input.paint(input));
+    let mut row = vec![painted, words];
+    if cli.phonetic {
+        // Code words without a pronunciation are printed as is
+        let pronunciations = spellings
+            .iter()
+            .map(|w| w.pronunciation.as_deref().unwrap_or(&w.spelling))
+            .collect::<Vec<_>>()
+            .join(separator);
+        row.push(pronunciations);
+    }
+    if cli.ipa {
+        // Code words without a transcription are left out
+        let transcriptions = spellings
+            .iter()
+            .filter_map(|w| w.ipa.as_deref())
+            .collect::<Vec<_>>()
+            .join(separator);
+        row.push(transcriptions);
+    }
+    if cli.morse {
+        row.push(salph::morse::encode(input));
+    }
+    if cli.braille {
+        row.push(salph::braille::encode(input));
+    }
+    if cli.tap {
+        row.push(salph::tap::encode(input));
+    }
+    if cli.telegraph {
+        row.push(salph::telegraph::encode(input));
+    }
+    row
+}
+
+/// Print a table with the code word, signal flag and semaphore positions of every
+/// letter
+fn print_signals(speller: &dyn Speller, sentence: &[String], options: &salph::SpellingOptions) {
+    let mut rows = Vec::new();
+    for word in sentence {
+        for spelling in spell(speller, word, options) {
+            let c = spelling.source.chars().next().unwrap_or_default();
+            let flag = salph::signals::flag(c);
+            let semaphore = salph::signals::semaphore(c)
+                .map(|(first, second)| format!("{} {}", first, second))
+                .unwrap_or_default();
+            rows.push(vec![
+                spelling.source.clone(),
+                spelling.to_string(),
+                flag.map(|f| f.description).unwrap_or_default().to_string(),
+                semaphore,
+                flag.and_then(|f| f.meaning).unwrap_or_default().to_string(),
+            ]);
+        }
+    }
+    print!("{}", format_table(&rows));
+}
+
+/// Print every word and its spellings as JSON
+fn print_json(speller: &dyn Speller, sentence: &[String], options: &salph::SpellingOptions) {
+    let output = sentence
+        .iter()
+        .map(|word| {
+            let spellings = spell(speller, word, options)
+                .iter()
+                .map(|w| {
+                    let mut spelling = json!({"char": w.source, "word": w.to_string(), "kind": w.kind.to_string(), "alternates": w.alternates, "pronunciation": w.pronunciation, "ipa": w.ipa});
+                    // Only spellings from a fallback speller come with their alphabet
+                    if let Some(alphabet) = &w.alphabet {
+                        spelling["alphabet"] = json!(alphabet);
+                    }
+                    spelling
+                })
+                .collect::<Vec<_>>();
+            json!({"input": word, "spellings": spellings})
+        })
+        .collect::<Vec<_>>();
+    println!("{}", serde_json::Value::Array(output));
+}
+
+/// Print the spellings of every word in multiple alphabets, as a table with a
+/// column per alphabet or as JSON. The header is printed as the first row if it's
+/// given.
+fn print_comparison(
+    sentence: &[String],
+    alphabets: &[(String, salph::SpellingAlphabet)],
+    options: &salph::SpellingOptions,
+    header: Option<Vec<String>>,
+    output: Output,
+) -> Result<(), salph::SalphError> {
+    let mut rows = Vec::from_iter(header);
+    let mut json = Vec::new();
+    for word in sentence {
+        let words = alphabets
+            .iter()
+            .map(|(name, alphabet)| {
+                let spellings = alphabet.str_to_spellings_with(word, options)?;
+                let words = spellings.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+                Ok((name.clone(), words))
+            })
+            .collect::<Result<Vec<_>, salph::SalphError>>()?;
+        let alphabets = words
+            .iter()
+            .map(|(alphabet, words)| (alphabet.clone(), json!(words)))
+            .collect::<serde_json::Map<_, _>>();
+        json.push(json!({"input": word, "alphabets": alphabets}));
+        let cells = words.into_iter().map(|(_, words)| words.join(" "));
+        rows.push(
+            std::iter::once(word.clone())
+                .chain(cells)
+                .collect::<Vec<_>>(),
+        );
+    }
+    match output {
+        Output::Json => println!("{}", serde_json::Value::Array(json)),
+        output => print_rows(output, &rows),
+    }
+    Ok(())
+}
+
+/// Print the groups of a phone number with their radio digits, separated by a
+/// pause marker
+fn print_phone(number: &str, pattern: &[usize], marker: &str, theme: &Theme, args: &SpellArgs) {
+    let groups = salph::phone::group(number, pattern);
+    let words = salph::phone::spell(number, pattern);
+    if args.output == Output::Json {
+        let output = groups
+            .iter()
+            .zip(&words)
+            .map(|(group, words)| json!({"group": group, "words": words}))
+            .collect::<Vec<_>>();
+        println!("{}", serde_json::Value::Array(output));
+        return;
+    }
+
+    // Only terminal tables are colored
+    let paint = |style: &Style, s: &str| match args.output {
+        Output::Table => style.paint(s),
+        _ => s.to_string(),
+    };
+    let separator = args.separator.as_deref().unwrap_or(" ");
+    let mut rows = Vec::new();
+    for (i, (group, words)) in groups.iter().zip(&words).enumerate() {
+        if i > 0 {
+            rows.push(vec![String::new(), marker.to_string()]);
+        }
+        let words = paint(&theme.digits, &words.join(separator));
+        rows.push(vec![paint(&theme.input, group), words]);
+    }
+    print_rows(args.output, &rows);
+}
+
+/// Spell a word using the given options
+fn spell(
+    speller: &dyn Speller,
+    word: &str,
+    options: &salph::SpellingOptions,
+) -> Vec<salph::Spelling> {
+    // Unspellable input is refused before printing in strict mode, so spelling
+    // can't fail
+    speller.spell_with(word, options).unwrap_or_default()
+}
+
+/// The sentence from the arguments or, if there are none, every line of the files
+/// as a sentence. Without files, stdin is read. Lines are read as they come in, so
+/// output can be printed right away. Empty lines are skipped and files that can't
+/// be read result in an error.
+fn sentences(
+    sentence: Vec<String>,
+    files: &[PathBuf],
+) -> Box<dyn Iterator<Item = Result<Vec<String>, String>>> {
+    if !sentence.is_empty() {
+        return Box::new(std::iter::once(Ok(sentence)));
+    }
+    let files = match files {
+        [] => vec![PathBuf::from("-")],
+        files => files.to_vec(),
+    };
+    let lines = files.into_iter().flat_map(|file| read_lines(&file));
+    Box::new(
+        lines
+            .map(|line| line.map(|line| line.split_whitespace().map(String::from).collect()))
+            .filter(|words| {
+                words
+                    .as_ref()
+                    .map_or(true, |words: &Vec<_>| !words.is_empty())
+            }),
+    )
+}
+
+/// The lines of a file, or of stdin for "-". Reading stops at the first error.
+fn read_lines(file: &Path) -> Box<dyn Iterator<Item = Result<String, String>>> {
+    let (name, reader): (_, Box<dyn BufRead>) = if file == Path::new("-") {
+        ("stdin".to_string(), Box::new(stdin().lock()))
+    } else {
+        let name = file.display().to_string();
+        match std::fs::File::open(file) {
+            Ok(f) => (name, Box::new(std::io::BufReader::new(f))),
+            Err(e) => return Box::new(std::iter::once(Err(format!("{}: {}", name, e)))),
+        }
+    };
+    let mut failed = false;
+    Box::new(reader.lines().map_while(move |line| {
+        if failed {
+            return None;
+        }
+        failed = line.is_err();
+        Some(line.map_err(|e| format!("{}: {}", name, e)))
+    }))
+}
+
+/// Load an alphabet from a file, exiting with an error if that fails
+fn load_file(file: &Path) -> salph::SpellingAlphabet {
+    salph::SpellingAlphabet::from_file(file).unwrap_or_else(|e| {
+        eprintln!("{}: {}", file.display(), e);
+        std::process::exit(1);
+    })
+}
+
+/// Validate an alphabet name, accepting "auto" as well
+fn validate_alphabet(s: &str) -> Result<String, String> {
+    if s == "auto" {
+        return Ok(s.to_string());
+    }
+    salph::SpellingAlphabet::validate(s)
+}
+
+/// The locale of the system, taken from LC_ALL or LANG
+fn system_locale() -> String {
+    ["LC_ALL", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|locale| !locale.is_empty())
+        .unwrap_or_default()
+}
+
+/// Print a warning for every character of the input that is commonly confused
+fn print_ambiguities(alphabet: &salph::SpellingAlphabet, s: &str) {
+    for ambiguity in alphabet.ambiguity_report(s).ambiguities {
+        let mut warning = format!(
+            "Warning: {} can be confused with {}",
+            ambiguity.character,
+            ambiguity.confusable.join(", ")
+        );
+        if let Some(clarifier) = &ambiguity.clarifier {
+            warning.push_str(&format!(", say \"{}\"", clarifier));
+        }
+        eprintln!("{}", warning);
+    }
+}
+
+/// Print the lint warnings of an alphabet file, exiting with a non-zero exit code
+/// if there are any
+fn lint(file: &Path) {
+    let alphabet = load_file(file);
+    let warnings = alphabet.lint();
+    if warnings.is_empty() {
+        println!("{}: no problems found", file.display());
+        return;
+    }
+    for warning in warnings {
+        println!("{}: {}", file.display(), warning);
+    }
+    std::process::exit(1);
+}
+
+/// Print the differences between two alphabets: removed entries in red, added
+/// entries in green and changed entries in yellow
+fn diff(from: &str, to: &str) {
+    let from = salph::SpellingAlphabet::from_str(from).unwrap();
+    let to = salph::SpellingAlphabet::from_str(to).unwrap();
+    let diff = from.diff(&to);
+    let mut rows = Vec::new();
+    for (key, word) in &diff.removed {
+        rows.push(vec!["-".to_string(), key.clone(), word.red().to_string()]);
+    }
+    for (key, word) in &diff.added {
+        rows.push(vec!["+".to_string(), key.clone(), word.green().to_string()]);
+    }
+    for (key, old, new) in &diff.changed {
+        let change = format!("{} -> {}", old.red(), new.green());
+        rows.push(vec!["~".to_string(), key.clone(), change]);
+    }
+    for (key, old, new) in &diff.alternates_changed {
+        let change = format!(
+            "alternates {} -> {}",
+            old.join(", ").red(),
+            new.join(", ").green()
+        );
+        rows.push(vec!["~".to_string(), key.clone(), change]);
+    }
+    print!("{}", format_table(&rows));
+}
+
+/// Print a completion script for `shell`. Alphabet names are completed with the
+/// alphabets that are available when the script is generated.
+fn completions(shell: Shell) {
+    let names = std::iter::once("auto".to_string())
+        .chain(
+            salph::SpellingAlphabet::list()
+                .into_iter()
+                .flat_map(|(name, metadata)| std::iter::once(name).chain(metadata.aliases)),
+        )
+        .collect::<Vec<_>>();
+    let mut command = complete_alphabets(Cli::command(), &names);
+    clap_complete::generate(shell, &mut command, "salph", &mut std::io::stdout());
+}
+
+/// Print the man page of salph, or write the pages of salph and its commands to
+/// `dir`
+fn manpage(dir: Option<&Path>) {
+    let result = match dir {
+        Some(dir) => clap_mangen::generate_to(Cli::command(), dir),
+        None => clap_mangen::Man::new(Cli::command()).render(&mut std::io::stdout()),
+    };
+    if let Err(e) = result {
+        eprintln!("Unable to write man page: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Set the possible values of every argument that takes an alphabet name
+fn complete_alphabets(command: clap::Command, names: &[String]) -> clap::Command {
+    command
+        .mut_args(|arg| match arg.get_id().as_str() {
+            "alphabet" | "from" | "to" => {
+                arg.value_parser(PossibleValuesParser::new(names.iter().cloned()))
+            }
+            _ => arg,
+        })
+        .mut_subcommands(|subcommand| complete_alphabets(subcommand, names))
+}
+
+/// List all available alphabets, leaving out historical ones unless asked for
+fn list_alphabets(output: Output, include_historical: bool) {
+    let alphabets = salph::SpellingAlphabet::list()
+        .into_iter()
+        .filter(|(_, metadata)| include_historical || !metadata.is_historical())
+        .collect::<Vec<_>>();
+    if output == Output::Table {
+        println!("Available alphabets: ");
+        print_alphabets(alphabets);
+        return;
+    }
+
+    let mut rows = Vec::new();
+    let mut json = Vec::new();
+    for (name, metadata) in alphabets {
+        let alphabet = salph::SpellingAlphabet::from_str(&name).unwrap();
+        let ranges = alphabet.char_ranges();
+        // Ranges of a single character are printed as the character
+        let characters = ranges
+            .iter()
+            .map(|range| match (range.start(), range.end()) {
+                (start, end) if start == end => start.to_string(),
+                (start, end) => format!("{}..{}", start, end),
+            })
+            .collect::<Vec<_>>();
+        let tag = metadata.language_tag();
+        rows.push(vec![
+            name.clone(),
+            metadata.name.clone().unwrap_or_default(),
+            tag.clone().unwrap_or_default(),
+            alphabet.len().to_string(),
+            characters.join(" "),
+        ]);
+        let ranges = ranges
+            .iter()
+            .map(|range| json!([range.start(), range.end()]))
+            .collect::<Vec<_>>();
+        json.push(json!({"code": name, "name": metadata.name, "language_tag": tag, "aliases": metadata.aliases, "since": metadata.since, "until": metadata.until, "deprecated": metadata.deprecated, "entries": alphabet.len(), "characters": ranges}));
+    }
+    if output == Output::Json {
+        println!("{}", serde_json::Value::Array(json));
+        return;
+    }
+    let header = ["Code", "Name", "Language", "Entries", "Characters"].map(String::from);
+    print_header(output, &header);
+    print_rows(output, &rows);
+    print_footer(output);
+}
+
+/// Print the alphabets matching a query, exiting with a non-zero exit code if
+/// there are none
+fn search_alphabets(query: &str) {
+    let alphabets = salph::SpellingAlphabet::search(query);
+    if alphabets.is_empty() {
+        eprintln!("No alphabets match {}", query);
+        std::process::exit(1);
+    }
+    print_alphabets(alphabets);
 }
 
-/// List all available alphabets
-fn list_alphabets() {
-    println!("Available alphabets: ");
-    for alphabet in salph::SpellingAlphabet::list() {
-        println!("  - {}: {}", alphabet.0, alphabet.1);
+/// Print alphabets with their name, language tag and source
+fn print_alphabets(alphabets: Vec<(String, salph::AlphabetMetadata)>) {
+    for (name, metadata) in alphabets {
+        let mut line = format!(
+            "  - {}: {}",
+            name,
+            metadata.name.as_deref().unwrap_or_default()
+        );
+        if let Some(tag) = metadata.language_tag() {
+            line.push_str(&format!(" [{}]", tag));
+        }
+        if let Some(source) = &metadata.source {
+            line.push_str(&format!(" (source: {})", source));
+        }
+        if !metadata.aliases.is_empty() {
+            line.push_str(&format!(" (aliases: {})", metadata.aliases.join(", ")));
+        }
+        if metadata.is_historical() {
+            let year = |year: Option<u16>| year.map(|y| y.to_string()).unwrap_or_default();
+            line.push_str(&format!(
+                " (historical, {}-{})",
+                year(metadata.since),
+                year(metadata.until)
+            ));
+        }
+        println!("{}", line);
     }
 }
@@ -0,0 +1,93 @@
+//! Uncontracted (grade 1) English braille using Unicode braille patterns.
+//!
+//! Uppercase letters are preceded by the capital indicator `⠠` and runs of digits
+//! by the numeric indicator `⠼`. Characters without a braille cell are skipped.
+//! ```
+//! use salph::braille;
+//!
+//! assert_eq!(braille::encode("abc"), "⠁⠃⠉");
+//! assert_eq!(braille::encode("Flight 42"), "⠠⠋⠇⠊⠛⠓⠞ ⠼⠙⠃");
+//! ```
+
+// Cells of the letters a to z
+const LETTERS: [char; 26] = [
+    '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊', '⠚', '⠅', '⠇', '⠍', '⠝', '⠕', '⠏', '⠟', '⠗', '⠎',
+    '⠞', '⠥', '⠧', '⠺', '⠭', '⠽', '⠵',
+];
+
+// Cells of punctuation marks
+const PUNCTUATION: &[(char, char)] = &[
+    (',', '⠂'),
+    (';', '⠆'),
+    (':', '⠒'),
+    ('.', '⠲'),
+    ('?', '⠦'),
+    ('!', '⠖'),
+    ('\'', '⠄'),
+    ('-', '⠤'),
+    ('/', '⠌'),
+];
+
+const CAPITAL: char = '⠠';
+const NUMBER: char = '⠼';
+// Marks a letter a to j following a digit as a letter rather than a digit
+const LETTER: char = '⠰';
+
+/// The braille cell for a character, without any indicators. Letters are matched
+/// case insensitively and digits use the cells of the letters a to j.
+/// ```
+/// use salph::braille;
+///
+/// assert_eq!(braille::encode_char('W'), Some('⠺'));
+/// assert_eq!(braille::encode_char('1'), Some('⠁'));
+/// assert_eq!(braille::encode_char('€'), None);
+/// ```
+pub fn encode_char(c: char) -> Option<char> {
+    if c.is_ascii_alphabetic() {
+        return Some(LETTERS[usize::from(c.to_ascii_lowercase() as u8 - b'a')]);
+    }
+    if let Some(digit) = c.to_digit(10) {
+        // 0 uses the cell of j, the other digits those of a to i
+        return Some(LETTERS[(digit as usize + 9) % 10]);
+    }
+    PUNCTUATION
+        .iter()
+        .find(|(punctuation, _)| *punctuation == c)
+        .map(|(_, cell)| *cell)
+}
+
+/// Encode a string as braille. Whitespace is collapsed into a single space.
+pub fn encode(s: &str) -> String {
+    let mut braille = String::new();
+    let mut in_number = false;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !braille.is_empty() && !braille.ends_with(' ') {
+                braille.push(' ');
+            }
+            in_number = false;
+            continue;
+        }
+        let Some(cell) = encode_char(c) else {
+            continue;
+        };
+        if c.is_ascii_digit() {
+            if !in_number {
+                braille.push(NUMBER);
+                in_number = true;
+            }
+        } else if c.is_ascii_alphabetic() {
+            if in_number && ('a'..='j').contains(&c.to_ascii_lowercase()) {
+                braille.push(LETTER);
+            }
+            if c.is_ascii_uppercase() {
+                braille.push(CAPITAL);
+            }
+            in_number = false;
+        } else {
+            in_number = false;
+        }
+        braille.push(cell);
+    }
+    braille.trim_end().to_string()
+}
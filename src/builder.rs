@@ -0,0 +1,124 @@
+use crate::{format, Alphabet, AlphabetMetadata, SalphError, SpellingAlphabet, WordMap};
+use std::borrow::Cow;
+
+/// Builder for constructing a [`SpellingAlphabet`] programmatically, either from
+/// scratch or by overriding entries of an embedded alphabet.
+/// ```
+/// use salph::{AlphabetBuilder, Alphabet};
+///
+/// let spelling_alphabet = AlphabetBuilder::from_alphabet(Alphabet::nato)
+///     .unwrap()
+///     .add("x", "Xylophone")
+///     .build()
+///     .unwrap();
+/// let words = spelling_alphabet
+///         .str_to_spellings("ax")
+///         .iter()
+///         .map(|x| x.spelling.clone())
+///         .collect::<Vec<_>>();
+/// assert_eq!(words, ["Alpha", "Xylophone"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AlphabetBuilder {
+    words: WordMap,
+    pub(crate) directives: WordMap,
+}
+
+impl AlphabetBuilder {
+    /// Create an empty builder
+    pub fn new() -> AlphabetBuilder {
+        AlphabetBuilder::default()
+    }
+
+    /// Create a builder that starts out with all entries of an embedded alphabet
+    pub fn from_alphabet(alphabet: Alphabet) -> Result<AlphabetBuilder, SalphError> {
+        let spelling_alphabet = SpellingAlphabet::load(alphabet)?;
+        Ok(AlphabetBuilder {
+            words: spelling_alphabet.words,
+            directives: spelling_alphabet.directives,
+        })
+    }
+
+    /// Add a `(key, word)` pair. Keys are case insensitive and an existing entry
+    /// for the same key is overridden, keeping its original position.
+    pub fn add(mut self, key: &str, word: &str) -> AlphabetBuilder {
+        self.words
+            .insert(Cow::Owned(key.to_lowercase()), Cow::Owned(word.to_string()));
+        self
+    }
+
+    /// Set the word used to spell whitespace
+    pub fn space(mut self, word: &str) -> AlphabetBuilder {
+        self.directives
+            .insert(Cow::Borrowed("space"), Cow::Owned(word.to_string()));
+        self
+    }
+
+    /// Set the word used to announce uppercase letters
+    pub fn capital(mut self, word: &str) -> AlphabetBuilder {
+        self.directives
+            .insert(Cow::Borrowed("capital"), Cow::Owned(word.to_string()));
+        self
+    }
+
+    /// Set the descriptive information of the alphabet, replacing any existing
+    /// metadata
+    /// ```
+    /// use salph::{AlphabetBuilder, AlphabetMetadata};
+    ///
+    /// let mut metadata = AlphabetMetadata::default();
+    /// metadata.name = Some("Acme Corp".to_string());
+    /// metadata.language = Some("en".to_string());
+    /// let spelling_alphabet = AlphabetBuilder::new()
+    ///     .add("a", "Acme")
+    ///     .metadata(metadata.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(spelling_alphabet.metadata(), metadata);
+    /// ```
+    pub fn metadata(mut self, metadata: AlphabetMetadata) -> AlphabetBuilder {
+        for name in [
+            "name",
+            "language",
+            "region",
+            "source",
+            "aliases",
+            "years",
+            "deprecated",
+        ] {
+            self.directives.shift_remove(name);
+        }
+        for (name, value) in metadata.directives() {
+            self.directives
+                .insert(Cow::Borrowed(name), Cow::Owned(value));
+        }
+        self
+    }
+
+    /// Remove the entry for a key, if it exists
+    pub fn remove(mut self, key: &str) -> AlphabetBuilder {
+        self.words.shift_remove(key.to_lowercase().as_str());
+        self
+    }
+
+    /// Build the [`SpellingAlphabet`]. Fails when the alphabet has no entries
+    /// or when an entry has an empty key or word.
+    /// ```
+    /// use salph::AlphabetBuilder;
+    ///
+    /// assert_eq!(AlphabetBuilder::new().build().is_err(), true);
+    /// assert_eq!(AlphabetBuilder::new().add("a", "").build().is_err(), true);
+    /// ```
+    pub fn build(self) -> Result<SpellingAlphabet, SalphError> {
+        if self.words.is_empty() && !self.directives.contains_key("extends") {
+            return Err(SalphError::EmptyAlphabet);
+        }
+        for (key, word) in &self.words {
+            if key.is_empty() {
+                return Err(SalphError::parse(0, "missing key"));
+            }
+            format::check_word(word).map_err(|reason| SalphError::parse(0, reason))?;
+        }
+        SpellingAlphabet::from_words(self.words, self.directives).resolve_extends()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{Alphabet, SalphError, Spelling, SpellingAlphabet, SpellingOptions};
+
+impl SpellingAlphabet {
+    /// Spell a string in multiple alphabets, to show how they differ. Characters
+    /// without a mapping are skipped.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let compared = SpellingAlphabet::compare("ab", &[Alphabet::nato, Alphabet::de]);
+    /// let (alphabet, spellings) = &compared[1];
+    /// assert_eq!(*alphabet, Alphabet::de);
+    /// assert_eq!(spellings[0].spelling, "Anton");
+    /// assert_eq!(spellings[1].spelling, "Berta");
+    /// # }
+    /// ```
+    pub fn compare(s: &str, alphabets: &[Alphabet]) -> Vec<(Alphabet, Vec<Spelling>)> {
+        SpellingAlphabet::compare_with(s, alphabets, &SpellingOptions::new())
+            .expect("skipping unmatched characters can't fail")
+    }
+
+    /// Spell a string in multiple alphabets like [`SpellingAlphabet::compare`],
+    /// using the given options
+    pub fn compare_with(
+        s: &str,
+        alphabets: &[Alphabet],
+        options: &SpellingOptions,
+    ) -> Result<Vec<(Alphabet, Vec<Spelling>)>, SalphError> {
+        alphabets
+            .iter()
+            .map(|alphabet| {
+                let spellings =
+                    SpellingAlphabet::cached(alphabet.clone()).str_to_spellings_with(s, options)?;
+                Ok((alphabet.clone(), spellings))
+            })
+            .collect()
+    }
+}
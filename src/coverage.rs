@@ -0,0 +1,37 @@
+/// Which characters of an input an alphabet can spell, as returned by
+/// [`SpellingAlphabet::coverage`](crate::SpellingAlphabet::coverage). Every
+/// category lists distinct grapheme clusters in order of first appearance.
+/// Whitespace isn't included.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct CoverageReport {
+    /// Characters the alphabet has a code word for
+    pub covered: Vec<String>,
+    /// Characters that can only be spelled after folding their diacritics (e.g.
+    /// "é" as "Echo")
+    pub needs_folding: Vec<String>,
+    /// Characters that can't be spelled at all
+    pub unmappable: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Whether every character can be spelled without folding
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert!(spelling_alphabet.coverage("KL 1234").is_complete());
+    /// assert!(!spelling_alphabet.coverage("café").is_complete());
+    /// ```
+    pub fn is_complete(&self) -> bool {
+        self.needs_folding.is_empty() && self.unmappable.is_empty()
+    }
+
+    // Add a character to a category, unless it was seen before
+    pub(crate) fn add(category: &mut Vec<String>, grapheme: &str) {
+        if !category.iter().any(|g| g == grapheme) {
+            category.push(grapheme.to_string());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{code_word, SpellingAlphabet};
+
+/// The differences between two alphabets, as returned by
+/// [`SpellingAlphabet::diff`]. Entries are listed in the order of the alphabet
+/// they come from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct AlphabetDiff {
+    /// `(key, word)` pairs that only the other alphabet has
+    pub added: Vec<(String, String)>,
+    /// `(key, word)` pairs that only this alphabet has
+    pub removed: Vec<(String, String)>,
+    /// `(key, old word, new word)` for keys with a different code word
+    pub changed: Vec<(String, String, String)>,
+    /// `(key, old alternates, new alternates)` for keys with the same code word,
+    /// but different alternates
+    pub alternates_changed: Vec<(String, Vec<String>, Vec<String>)>,
+}
+
+impl AlphabetDiff {
+    /// Whether both alphabets have the same code words and alternates
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.alternates_changed.is_empty()
+    }
+}
+
+impl SpellingAlphabet {
+    /// Compare the code words of this alphabet to those of another alphabet. Keys
+    /// are compared by their code word, while alternates (e.g. the "Alfa" of
+    /// "Alpha") are reported separately for keys with the same code word.
+    /// Pronunciations and IPA transcriptions aren't compared.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let us_alt = SpellingAlphabet::load(Alphabet::en_USalt).unwrap();
+    /// let diff = nato.diff(&us_alt);
+    /// assert!(diff.changed.contains(&(
+    ///     "a".to_string(),
+    ///     "Alpha".to_string(),
+    ///     "Able".to_string()
+    /// )));
+    /// assert!(nato.diff(&nato).is_empty());
+    ///
+    /// let custom = SpellingAlphabet::parse("# extends: nato\nA Alpha").unwrap();
+    /// let diff = nato.diff(&custom);
+    /// assert!(diff.changed.is_empty());
+    /// assert_eq!(
+    ///     diff.alternates_changed,
+    ///     [("a".to_string(), vec!["Alfa".to_string()], vec![])]
+    /// );
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &SpellingAlphabet) -> AlphabetDiff {
+        let mut diff = AlphabetDiff::default();
+        for (key, text) in &self.words {
+            let word = code_word(text, 0);
+            match other.words.get(key) {
+                None => diff
+                    .removed
+                    .push((key.to_string(), word.spelling.to_string())),
+                Some(other_text) => {
+                    let other_word = code_word(other_text, 0);
+                    if word.spelling != other_word.spelling {
+                        diff.changed.push((
+                            key.to_string(),
+                            word.spelling.to_string(),
+                            other_word.spelling.to_string(),
+                        ));
+                    } else if word.alternates != other_word.alternates {
+                        let owned = |alternates: Vec<&str>| {
+                            alternates.into_iter().map(str::to_string).collect()
+                        };
+                        diff.alternates_changed.push((
+                            key.to_string(),
+                            owned(word.alternates),
+                            owned(other_word.alternates),
+                        ));
+                    }
+                }
+            }
+        }
+        for (key, text) in &other.words {
+            if !self.words.contains_key(key) {
+                let word = code_word(text, 0).spelling;
+                diff.added.push((key.to_string(), word.to_string()));
+            }
+        }
+        diff
+    }
+}
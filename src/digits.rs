@@ -0,0 +1,219 @@
+// Code words for digits, used for alphabets that don't define their own
+
+/// English code words for the digits 0 to 9
+pub(crate) const ENGLISH_DIGITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// The English code word for a grapheme, if it's a single ASCII digit
+pub(crate) fn english_digit(grapheme: &str) -> Option<&'static str> {
+    match grapheme.as_bytes() {
+        [digit @ b'0'..=b'9'] => Some(ENGLISH_DIGITS[usize::from(digit - b'0')]),
+        _ => None,
+    }
+}
+
+/// Code words for the digits 0 to 9 in the ICAO radiotelephony pronunciation
+pub(crate) const ICAO_DIGITS: [&str; 10] = [
+    "zero", "one", "two", "tree", "four", "fife", "six", "seven", "eight", "niner",
+];
+
+// Whether a grapheme is a single ASCII digit
+pub(crate) fn is_digit(grapheme: &str) -> bool {
+    matches!(grapheme.as_bytes(), [b'0'..=b'9'])
+}
+
+// The ICAO code word for an ASCII digit
+pub(crate) fn icao_digit(digit: u8) -> &'static str {
+    ICAO_DIGITS[usize::from(digit - b'0')]
+}
+
+// Spell a number consisting of whole hundreds or thousands the ICAO way, by
+// spelling the digits of the thousands and hundreds followed by "tousand" and
+// "hundred" (e.g. "2500" as "two tousand fife hundred"). Returns `None` for other
+// numbers, which are spelled digit by digit.
+pub(crate) fn icao_number(digits: &str) -> Option<String> {
+    if digits.len() < 3 || digits.starts_with('0') || !digits.ends_with("00") {
+        return None;
+    }
+    let (thousands, hundreds) = digits[..digits.len() - 2].split_at(digits.len() - 3);
+    let mut words: Vec<&str> = Vec::new();
+    if !thousands.is_empty() {
+        words.extend(thousands.bytes().map(icao_digit));
+        words.push("tousand");
+    }
+    if hundreds != "0" {
+        words.push(icao_digit(hundreds.as_bytes()[0]));
+        words.push("hundred");
+    }
+    Some(words.join(" "))
+}
+
+/// The most digits a number can have to be spelled as it's spoken
+pub(crate) const MAX_NUMBER_LEN: usize = 12;
+
+// Spell a number as it's spoken (e.g. "23" as "twenty-three"). German numbers are
+// spelled in German, all other languages fall back to English. Returns `None` for
+// numbers with leading zeros or more than twelve digits, which are spelled digit
+// by digit.
+pub(crate) fn number_words(digits: &str, language: Option<&str>) -> Option<String> {
+    if digits.len() > MAX_NUMBER_LEN || digits.len() > 1 && digits.starts_with('0') {
+        return None;
+    }
+    let n = digits.parse().ok()?;
+    Some(match language {
+        Some("de") => german_number(n),
+        _ => english_number(n),
+    })
+}
+
+fn english_number(n: u64) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const SCALES: [(u64, &str); 3] = [
+        (1_000_000_000, "billion"),
+        (1_000_000, "million"),
+        (1_000, "thousand"),
+    ];
+
+    // Spell a number between 1 and 999
+    let below_thousand = |n: u64| {
+        let mut words = Vec::new();
+        if n >= 100 {
+            words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+        }
+        match (n % 100) as usize {
+            0 => {}
+            rest @ 1..=19 => words.push(ONES[rest].to_string()),
+            rest if rest % 10 == 0 => words.push(TENS[rest / 10].to_string()),
+            rest => words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+        }
+        words.join(" ")
+    };
+
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut n = n;
+    let mut words = Vec::new();
+    for (scale, name) in SCALES {
+        if n >= scale {
+            words.push(format!("{} {}", below_thousand(n / scale), name));
+            n %= scale;
+        }
+    }
+    if n > 0 {
+        words.push(below_thousand(n));
+    }
+    words.join(" ")
+}
+
+fn german_number(n: u64) -> String {
+    const ONES: [&str; 20] = [
+        "null",
+        "eins",
+        "zwei",
+        "drei",
+        "vier",
+        "fünf",
+        "sechs",
+        "sieben",
+        "acht",
+        "neun",
+        "zehn",
+        "elf",
+        "zwölf",
+        "dreizehn",
+        "vierzehn",
+        "fünfzehn",
+        "sechzehn",
+        "siebzehn",
+        "achtzehn",
+        "neunzehn",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig",
+        "neunzig",
+    ];
+    const SCALES: [(u64, &str, &str); 2] = [
+        (1_000_000_000, "Milliarde", "Milliarden"),
+        (1_000_000, "Million", "Millionen"),
+    ];
+
+    // Spell a number between 1 and 999 as a single word. A trailing one is "eins"
+    // at the end of a number and "ein" in front of e.g. "tausend".
+    let below_thousand = |n: u64, trailing: bool| {
+        let mut word = String::new();
+        if n >= 100 {
+            let hundreds = (n / 100) as usize;
+            word.push_str(if hundreds == 1 { "ein" } else { ONES[hundreds] });
+            word.push_str("hundert");
+        }
+        match (n % 100) as usize {
+            0 => {}
+            1 => word.push_str(if trailing { "eins" } else { "ein" }),
+            rest @ 2..=19 => word.push_str(ONES[rest]),
+            rest => {
+                match rest % 10 {
+                    0 => {}
+                    1 => word.push_str("einund"),
+                    ones => word.push_str(&format!("{}und", ONES[ones])),
+                }
+                word.push_str(TENS[rest / 10]);
+            }
+        }
+        word
+    };
+
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut n = n;
+    let mut words = Vec::new();
+    for (scale, singular, plural) in SCALES {
+        if n >= scale {
+            let count = n / scale;
+            words.push(match count {
+                1 => format!("eine {}", singular),
+                count => format!("{} {}", below_thousand(count, true), plural),
+            });
+            n %= scale;
+        }
+    }
+    let mut word = String::new();
+    if n >= 1_000 {
+        word.push_str(&below_thousand(n / 1_000, false));
+        word.push_str("tausend");
+        n %= 1_000;
+    }
+    if n > 0 {
+        word.push_str(&below_thousand(n, true));
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words.join(" ")
+}
@@ -0,0 +1,97 @@
+use std::{error::Error, fmt, io};
+
+/// Errors returned by salph
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SalphError {
+    /// The requested alphabet doesn't exist
+    NotFound(String),
+    /// The name can't be registered, because it belongs to an embedded alphabet
+    Reserved(String),
+    /// An alphabet definition couldn't be parsed. `line` starts at 1, or is 0 when
+    /// the error isn't tied to a line.
+    ParseError { line: usize, reason: String },
+    /// An alphabet definition doesn't contain any entries
+    EmptyAlphabet,
+    /// An alphabet definition isn't valid UTF-8
+    InvalidUtf8,
+    /// An alphabet file couldn't be read
+    Io(io::Error),
+    /// A character couldn't be spelled and the unmatched policy is
+    /// [`Unmatched::Error`](crate::Unmatched::Error). `position` is counted in
+    /// grapheme clusters.
+    Unmatched { position: usize, grapheme: String },
+}
+
+impl SalphError {
+    pub(crate) fn parse(line: usize, reason: &str) -> SalphError {
+        SalphError::ParseError {
+            line,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SalphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SalphError::NotFound(name) => write!(f, "Unknown alphabet: {}", name),
+            SalphError::Reserved(name) => {
+                write!(
+                    f,
+                    "Alphabet name {} is reserved for an embedded alphabet",
+                    name
+                )
+            }
+            SalphError::ParseError { line: 0, reason } => write!(f, "Parse error: {}", reason),
+            SalphError::ParseError { line, reason } => {
+                write!(f, "Parse error on line {}: {}", line, reason)
+            }
+            SalphError::EmptyAlphabet => write!(f, "Alphabet contains no entries"),
+            SalphError::InvalidUtf8 => write!(f, "Alphabet is not valid UTF-8"),
+            SalphError::Io(e) => write!(f, "Unable to read alphabet: {}", e),
+            SalphError::Unmatched { position, grapheme } => {
+                write!(f, "Unable to spell '{}' at position {}", grapheme, position)
+            }
+        }
+    }
+}
+
+impl Error for SalphError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SalphError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SalphError {
+    fn from(e: io::Error) -> SalphError {
+        SalphError::Io(e)
+    }
+}
+
+/// Error returned by
+/// [`try_str_to_spellings`](crate::SpellingAlphabet::try_str_to_spellings) when
+/// one or more characters couldn't be spelled. `positions` are counted in grapheme
+/// clusters and line up with `chars`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnspellableError {
+    pub positions: Vec<usize>,
+    pub chars: Vec<String>,
+}
+
+impl fmt::Display for UnspellableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unspellable = self
+            .chars
+            .iter()
+            .zip(&self.positions)
+            .map(|(c, position)| format!("'{}' at position {}", c, position))
+            .collect::<Vec<_>>();
+        write!(f, "Unable to spell {}", unspellable.join(", "))
+    }
+}
+
+impl Error for UnspellableError {}
@@ -0,0 +1,238 @@
+use crate::{code_word, format, SpellingAlphabet, SpellingKind};
+use serde_json::json;
+
+// Sections of the structured formats, named after the kind of their keys
+pub(crate) const SECTIONS: &[(&str, SpellingKind)] = &[
+    ("letters", SpellingKind::Letter),
+    ("digits", SpellingKind::Digit),
+    ("punctuation", SpellingKind::Punctuation),
+];
+
+// An entry of an alphabet, split into its columns
+struct Entry<'a> {
+    key: &'a str,
+    kind: SpellingKind,
+    word: &'a str,
+    alternates: Vec<&'a str>,
+    pronunciation: Option<&'a str>,
+    ipa: Option<&'a str>,
+}
+
+impl SpellingAlphabet {
+    /// Export the alphabet as JSON. Directives (like the name, language and space
+    /// word) are stored as top-level strings and entries are grouped into
+    /// `letters`, `digits` and `punctuation` arrays:
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let json = SpellingAlphabet::load(Alphabet::nato).unwrap().to_json();
+    /// let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(value["name"], "NATO");
+    /// assert_eq!(value["letters"][0]["word"], "Alpha");
+    /// assert_eq!(value["letters"][0]["alternates"][0], "Alfa");
+    /// assert_eq!(value["digits"][3]["pronunciation"], "TREE");
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut object = serde_json::Map::new();
+        for (name, value) in &self.directives {
+            object.insert(name.to_string(), json!(value));
+        }
+        for (section, kind) in SECTIONS {
+            let entries = self
+                .export_entries()
+                .filter(|entry| entry.kind == *kind)
+                .map(|entry| {
+                    let mut object = serde_json::Map::new();
+                    object.insert("key".to_string(), json!(entry.key));
+                    object.insert("word".to_string(), json!(entry.word));
+                    if !entry.alternates.is_empty() {
+                        object.insert("alternates".to_string(), json!(entry.alternates));
+                    }
+                    if let Some(pronunciation) = entry.pronunciation {
+                        object.insert("pronunciation".to_string(), json!(pronunciation));
+                    }
+                    if let Some(ipa) = entry.ipa {
+                        object.insert("ipa".to_string(), json!(ipa));
+                    }
+                    serde_json::Value::Object(object)
+                })
+                .collect::<Vec<_>>();
+            if !entries.is_empty() {
+                object.insert(section.to_string(), serde_json::Value::Array(entries));
+            }
+        }
+        serde_json::to_string_pretty(&object).expect("JSON values always serialize")
+    }
+
+    /// Export the alphabet as TOML, in version 2 of the alphabet file format that
+    /// is used by the files in the alphabets directory (see
+    /// [`SpellingAlphabet::parse`]). Directives go in the `[metadata]` and `[words]`
+    /// tables and entries in the `[letters]`, `[digits]` and `[punctuation]` tables,
+    /// with their alternates, pronunciations and IPA transcriptions in tables of
+    /// their own:
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let toml = nato.to_toml();
+    /// assert!(toml.starts_with("[metadata]\nname = \"NATO\""));
+    /// assert!(toml.contains("[letters]\na = \"Alpha\""));
+    /// assert!(toml.contains("[alternates]\na = [\"Alfa\"]"));
+    ///
+    /// let parsed = SpellingAlphabet::parse(&toml).unwrap();
+    /// assert!(parsed.entries().eq(nato.entries()));
+    /// ```
+    pub fn to_toml(&self) -> String {
+        let mut metadata = toml::Table::new();
+        let mut words = toml::Table::new();
+        for (name, value) in &self.directives {
+            let table = if format::METADATA.contains(&name.as_ref()) {
+                &mut metadata
+            } else {
+                &mut words
+            };
+            table.insert(name.to_string(), directive_value(name, value));
+        }
+
+        // The file has to start with the metadata for it to be read as version 2
+        let mut table = toml::Table::new();
+        table.insert("metadata".to_string(), toml::Value::Table(metadata));
+        if !words.is_empty() {
+            table.insert("words".to_string(), toml::Value::Table(words));
+        }
+        for (section, kind) in SECTIONS {
+            let entries = self
+                .export_entries()
+                .filter(|entry| entry.kind == *kind)
+                .map(|entry| (entry.key.to_string(), toml::Value::from(entry.word)))
+                .collect::<toml::Table>();
+            if !entries.is_empty() {
+                table.insert(section.to_string(), toml::Value::Table(entries));
+            }
+        }
+        let column = |name: &str, entry: Entry| match name {
+            "alternates" => {
+                (!entry.alternates.is_empty()).then(|| toml::Value::from(entry.alternates))
+            }
+            "pronunciation" => entry.pronunciation.map(toml::Value::from),
+            _ => entry.ipa.map(toml::Value::from),
+        };
+        for name in format::COLUMN_TABLES {
+            let values = self
+                .export_entries()
+                .filter_map(|entry| Some((entry.key.to_string(), column(name, entry)?)))
+                .collect::<toml::Table>();
+            if !values.is_empty() {
+                table.insert(name.to_string(), toml::Value::Table(values));
+            }
+        }
+        toml::to_string(&table).expect("TOML tables always serialize")
+    }
+
+    /// Export the entries of the alphabet as CSV, with a header row. Alternates are
+    /// separated by `|`. Directives aren't included.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let csv = SpellingAlphabet::load(Alphabet::nato).unwrap().to_csv();
+    /// let mut lines = csv.lines();
+    /// assert_eq!(lines.next(), Some("key,kind,word,alternates,pronunciation,ipa"));
+    /// assert_eq!(lines.next(), Some("a,letter,Alpha,Alfa,AL-fah,ˈælfə"));
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        let header = ["key", "kind", "word", "alternates", "pronunciation", "ipa"];
+        let mut rows = vec![header.map(String::from)];
+        rows.extend(self.export_entries().map(|entry| {
+            [
+                entry.key.to_string(),
+                entry.kind.to_string(),
+                entry.word.to_string(),
+                entry.alternates.join("|"),
+                entry.pronunciation.unwrap_or_default().to_string(),
+                entry.ipa.unwrap_or_default().to_string(),
+            ]
+        }));
+        for row in rows {
+            writer
+                .write_record(&row)
+                .expect("writing to a Vec doesn't fail");
+        }
+        let bytes = writer.into_inner().expect("writing to a Vec doesn't fail");
+        String::from_utf8(bytes).expect("CSV is written from strings")
+    }
+
+    /// Export the entries of the alphabet as flashcards that can be imported into
+    /// [Anki](https://apps.ankiweb.net). Every card has the key on the front and
+    /// its code word on the back and is reversed as well. With `pronunciation`,
+    /// the pronunciation (or the IPA transcription if there is none) is added to
+    /// the back. The deck is named after the alphabet.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let cards = spelling_alphabet.to_anki(false);
+    /// assert!(cards.contains("#deck:NATO\n"));
+    /// assert!(cards.contains("\nA\tAlpha\n"));
+    ///
+    /// let cards = spelling_alphabet.to_anki(true);
+    /// assert!(cards.contains("\nA\tAlpha (AL-fah)\n"));
+    /// ```
+    pub fn to_anki(&self, pronunciation: bool) -> String {
+        let deck = self.metadata().name.unwrap_or_else(|| "salph".to_string());
+        // Fields can't contain the separator
+        let field = |s: &str| s.replace(['\t', '\n'], " ");
+        let mut cards = format!(
+            "#separator:tab\n#html:false\n#notetype:Basic (and reversed card)\n#deck:{}\n",
+            field(&deck)
+        );
+        for entry in self.export_entries() {
+            let mut back = entry.word.to_string();
+            if let Some(hint) = entry.pronunciation.or(entry.ipa).filter(|_| pronunciation) {
+                back = format!("{} ({})", back, hint);
+            }
+            cards.push_str(&format!(
+                "{}\t{}\n",
+                field(&entry.key.to_uppercase()),
+                field(&back)
+            ));
+        }
+        cards
+    }
+
+    // The entries of the alphabet, split into their columns
+    fn export_entries(&self) -> impl Iterator<Item = Entry<'_>> {
+        self.words.iter().map(|(key, text)| {
+            let word = code_word(text, 0);
+            // Entries that aren't letters or digits end up in the punctuation section
+            let kind = match SpellingKind::classify(key) {
+                SpellingKind::Letter => SpellingKind::Letter,
+                SpellingKind::Digit => SpellingKind::Digit,
+                _ => SpellingKind::Punctuation,
+            };
+            Entry {
+                key,
+                kind,
+                word: word.spelling,
+                alternates: word.alternates,
+                pronunciation: word.pronunciation,
+                ipa: word.ipa,
+            }
+        })
+    }
+}
+
+// The value of a directive in a version 2 file: lists for directives that hold
+// several values and booleans for flags, the reverse of what the parser does
+fn directive_value(name: &str, value: &str) -> toml::Value {
+    let list = |separator: &str| {
+        toml::Value::from(value.split(separator).map(str::trim).collect::<Vec<_>>())
+    };
+    match (name, value) {
+        ("aliases", _) => list(","),
+        ("prowords", _) => list("|"),
+        (_, "true") => toml::Value::Boolean(true),
+        (_, "false") => toml::Value::Boolean(false),
+        _ => toml::Value::from(value),
+    }
+}
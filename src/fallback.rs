@@ -0,0 +1,122 @@
+use crate::{
+    SalphError, Speller, Spelling, SpellingAlphabet, SpellingKind, SpellingOptions, Unmatched,
+};
+use std::sync::Arc;
+
+/// A [`Speller`] that chains multiple alphabets. Characters are spelled with the
+/// primary alphabet, and characters it has no code word for are spelled with the
+/// fallback alphabets, in the order they were added. Every spelling is tagged with
+/// the name of the alphabet it comes from in [`Spelling::alphabet`].
+/// ```
+/// # #[cfg(feature = "all-alphabets")] {
+/// use salph::{SpellingAlphabet, Alphabet, FallbackSpeller, Speller};
+///
+/// let speller = FallbackSpeller::new("nato", SpellingAlphabet::cached(Alphabet::nato))
+///     .fallback("sv", SpellingAlphabet::cached(Alphabet::sv));
+/// let spellings = speller.spell("KÅ");
+/// assert_eq!(spellings[0].spelling, "Kilo");
+/// assert_eq!(spellings[0].alphabet.as_deref(), Some("nato"));
+/// assert_eq!(spellings[1].spelling, "Åke");
+/// assert_eq!(spellings[1].alphabet.as_deref(), Some("sv"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FallbackSpeller {
+    alphabets: Vec<(String, Arc<SpellingAlphabet>)>,
+}
+
+impl FallbackSpeller {
+    /// Spell with a primary alphabet, named `name`. The alphabet can be shared by
+    /// passing an `Arc`, like the one returned by [`SpellingAlphabet::cached`].
+    pub fn new(name: impl Into<String>, alphabet: impl Into<Arc<SpellingAlphabet>>) -> Self {
+        FallbackSpeller {
+            alphabets: vec![(name.into(), alphabet.into())],
+        }
+    }
+
+    /// Add an alphabet to spell the characters with that the alphabets before it
+    /// have no code word for
+    pub fn fallback(
+        mut self,
+        name: impl Into<String>,
+        alphabet: impl Into<Arc<SpellingAlphabet>>,
+    ) -> Self {
+        self.alphabets.push((name.into(), alphabet.into()));
+        self
+    }
+}
+
+impl Speller for FallbackSpeller {
+    /// Spell a string using the given options. Characters that none of the
+    /// alphabets can spell are handled by the unmatched policy of the options, the
+    /// way the primary alphabet handles them.
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        // Keep unmatched characters, so they can be passed on to the fallbacks
+        let keep = options.clone().unmatched(Unmatched::Keep);
+        let ((primary_name, primary), fallbacks) = self
+            .alphabets
+            .split_first()
+            .expect("there is always a primary alphabet");
+
+        let mut spellings = tag(primary.str_to_spellings_with(s, &keep)?, primary_name, 0);
+        for (name, alphabet) in fallbacks {
+            spellings = respell(spellings, |source, position| {
+                Ok(tag(
+                    alphabet.str_to_spellings_with(source, &keep)?,
+                    name,
+                    position,
+                ))
+            })?;
+        }
+
+        // What's left is unmatched by any alphabet
+        respell(spellings, |source, position| {
+            match primary.str_to_spellings_with(source, options) {
+                Ok(spellings) => Ok(offset(spellings, position)),
+                Err(SalphError::Unmatched { grapheme, .. }) => {
+                    Err(SalphError::Unmatched { position, grapheme })
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+// Spell the unmatched characters among `spellings` again, using a function of
+// their source and position
+fn respell(
+    spellings: Vec<Spelling>,
+    mut spell: impl FnMut(&str, usize) -> Result<Vec<Spelling>, SalphError>,
+) -> Result<Vec<Spelling>, SalphError> {
+    let mut respelled = Vec::with_capacity(spellings.len());
+    for spelling in spellings {
+        if spelling.kind == SpellingKind::Unknown {
+            respelled.extend(spell(&spelling.source, spelling.position)?);
+        } else {
+            respelled.push(spelling);
+        }
+    }
+    Ok(respelled)
+}
+
+// Tag the code words of spellings with the name of their alphabet and move them to
+// `position`
+fn tag(spellings: Vec<Spelling>, name: &str, position: usize) -> Vec<Spelling> {
+    offset(spellings, position)
+        .into_iter()
+        .map(|mut spelling| {
+            if spelling.kind != SpellingKind::Unknown {
+                spelling.alphabet = Some(name.to_string());
+            }
+            spelling
+        })
+        .collect()
+}
+
+// Move the positions of spellings of a part of the input to `position`
+fn offset(mut spellings: Vec<Spelling>, position: usize) -> Vec<Spelling> {
+    for spelling in &mut spellings {
+        spelling.position += position;
+    }
+    spellings
+}
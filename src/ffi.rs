@@ -0,0 +1,184 @@
+//! C bindings, enabled through the `ffi` feature.
+//!
+//! The shared library that can be linked from C, C++ or Swift is built with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib`. The matching header is kept in `include/salph.h`
+//! and can be regenerated with [cbindgen](https://github.com/mozilla/cbindgen):
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/salph.h
+//! ```
+//! Example:
+//! ```c
+//! #include "salph.h"
+//!
+//! SalphSpellings *spellings = salph_spell("ab", "nato");
+//! for (size_t i = 0; i < spellings->len; i++) {
+//!     printf("%s\n", spellings->spellings[i].spelling);
+//! }
+//! salph_free(spellings);
+//! ```
+use crate::{SpellingAlphabet, SpellingKind};
+use std::{
+    ffi::{c_char, CStr, CString},
+    ptr,
+    str::FromStr,
+};
+
+/// What kind of character a code word spells, see [`SpellingKind`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SalphKind {
+    Letter,
+    Digit,
+    Punctuation,
+    Whitespace,
+    Unknown,
+    Symbol,
+}
+
+impl From<SpellingKind> for SalphKind {
+    fn from(kind: SpellingKind) -> SalphKind {
+        match kind {
+            SpellingKind::Letter => SalphKind::Letter,
+            SpellingKind::Digit => SalphKind::Digit,
+            SpellingKind::Punctuation => SalphKind::Punctuation,
+            SpellingKind::Whitespace => SalphKind::Whitespace,
+            SpellingKind::Unknown => SalphKind::Unknown,
+            SpellingKind::Symbol => SalphKind::Symbol,
+        }
+    }
+}
+
+/// A single code word, see [`Spelling`](crate::Spelling). `spelling` includes the
+/// announced prefix (e.g. "capital Alpha").
+#[repr(C)]
+pub struct SalphSpelling {
+    pub spelling: *mut c_char,
+    pub source: *mut c_char,
+    pub position: usize,
+    pub kind: SalphKind,
+}
+
+/// The spellings of a string, returned by [`salph_spell`]
+#[repr(C)]
+pub struct SalphSpellings {
+    pub spellings: *mut SalphSpelling,
+    pub len: usize,
+}
+
+/// The names of the available alphabets, returned by [`salph_list_alphabets`]
+#[repr(C)]
+pub struct SalphAlphabets {
+    pub names: *mut *mut c_char,
+    pub len: usize,
+}
+
+/// Spell `text` using the alphabet named `alphabet`. Characters without a mapping
+/// are skipped. Returns NULL if either argument is NULL or not valid UTF-8, or if
+/// the alphabet doesn't exist. The result needs to be freed with [`salph_free`].
+///
+/// # Safety
+///
+/// `text` and `alphabet` need to be NULL or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn salph_spell(
+    text: *const c_char,
+    alphabet: *const c_char,
+) -> *mut SalphSpellings {
+    let (Some(text), Some(alphabet)) = (to_str(text), to_str(alphabet)) else {
+        return ptr::null_mut();
+    };
+    let Ok(alphabet) = SpellingAlphabet::from_str(alphabet) else {
+        return ptr::null_mut();
+    };
+    let spellings = alphabet
+        .spellings(text)
+        .map(|spelling| SalphSpelling {
+            spelling: to_c_string(&spelling.to_string()),
+            source: to_c_string(&spelling.source),
+            position: spelling.position,
+            kind: spelling.kind.into(),
+        })
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+    let len = spellings.len();
+    Box::into_raw(Box::new(SalphSpellings {
+        spellings: Box::into_raw(spellings).cast(),
+        len,
+    }))
+}
+
+/// Free the result of [`salph_spell`]. Does nothing if `spellings` is NULL.
+///
+/// # Safety
+///
+/// `spellings` needs to be NULL or returned by [`salph_spell`], and can't be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn salph_free(spellings: *mut SalphSpellings) {
+    if spellings.is_null() {
+        return;
+    }
+    let spellings = Box::from_raw(spellings);
+    let items = Box::from_raw(ptr::slice_from_raw_parts_mut(
+        spellings.spellings,
+        spellings.len,
+    ));
+    for item in items.iter() {
+        drop(CString::from_raw(item.spelling));
+        drop(CString::from_raw(item.source));
+    }
+}
+
+/// List the names of the available alphabets. The result needs to be freed with
+/// [`salph_free_alphabets`].
+#[no_mangle]
+pub extern "C" fn salph_list_alphabets() -> *mut SalphAlphabets {
+    let names = SpellingAlphabet::list()
+        .into_iter()
+        .map(|(name, _)| to_c_string(&name))
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+    let len = names.len();
+    Box::into_raw(Box::new(SalphAlphabets {
+        names: Box::into_raw(names).cast(),
+        len,
+    }))
+}
+
+/// Free the result of [`salph_list_alphabets`]. Does nothing if `alphabets` is
+/// NULL.
+///
+/// # Safety
+///
+/// `alphabets` needs to be NULL or returned by [`salph_list_alphabets`], and
+/// can't be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn salph_free_alphabets(alphabets: *mut SalphAlphabets) {
+    if alphabets.is_null() {
+        return;
+    }
+    let alphabets = Box::from_raw(alphabets);
+    let names = Box::from_raw(ptr::slice_from_raw_parts_mut(
+        alphabets.names,
+        alphabets.len,
+    ));
+    for name in names.iter() {
+        drop(CString::from_raw(*name));
+    }
+}
+
+// Borrow a C string as a &str, if it isn't NULL and is valid UTF-8
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+// Copy a string into a C string. Interior NUL bytes can't be represented and are
+// removed.
+fn to_c_string(s: &str) -> *mut c_char {
+    CString::new(s.replace('\0', ""))
+        .expect("NUL bytes were removed")
+        .into_raw()
+}
@@ -0,0 +1,45 @@
+use crate::kana::to_katakana;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+// Letters that don't decompose into a base letter and combining marks
+const SPECIAL_FOLDS: &[(char, &str)] = &[
+    ('ß', "ss"),
+    ('æ', "ae"),
+    ('Æ', "AE"),
+    ('œ', "oe"),
+    ('Œ', "OE"),
+    ('ø', "o"),
+    ('Ø', "O"),
+    ('đ', "d"),
+    ('Đ', "D"),
+    ('ł', "l"),
+    ('Ł', "L"),
+    ('ı', "i"),
+    ('þ', "th"),
+    ('Þ', "TH"),
+];
+
+/// Fold diacritics in a string (e.g. "é" → "e", "ß" → "ss") by decomposing it and
+/// dropping all combining marks. Letters that don't decompose are folded using a
+/// small table of common transliterations.
+pub(crate) fn fold_diacritics(s: &str) -> String {
+    s.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .map(
+            |c| match SPECIAL_FOLDS.iter().find(|(from, _)| *from == c) {
+                Some((_, to)) => to.to_string(),
+                None => c.to_string(),
+            },
+        )
+        .collect()
+}
+
+// Fold the case of a character to match it against keys, which are lowercase.
+// Unlike lowercasing, this also maps the Greek final sigma to the regular sigma and
+// hiragana to katakana.
+pub(crate) fn fold_case(c: char) -> impl Iterator<Item = char> {
+    c.to_lowercase().map(|c| match c {
+        'ς' => 'σ',
+        c => to_katakana(c),
+    })
+}
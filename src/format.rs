@@ -0,0 +1,327 @@
+// Parser for the alphabet file formats. This module is shared between the library
+// and build.rs, which parses the embedded alphabets at compile time, so it can't
+// depend on anything outside of std and toml, which is a build dependency as well.
+//
+// There are two versions of the format. Version 1 is line based: every line holds a
+// key and a code word, with directives in comments (e.g. `# name: NATO`). Version 2
+// is TOML and starts with a `[metadata]` table, see `parse_v2`. Both are parsed into
+// the same directives and entries.
+
+/// Directives that are recognized in alphabet files
+pub(crate) const DIRECTIVES: &[&str] = &[
+    "space",
+    "capital",
+    "lowercase",
+    "digit",
+    "symbol",
+    "connector",
+    "prowords",
+    "name",
+    "language",
+    "region",
+    "source",
+    "aliases",
+    "years",
+    "deprecated",
+    "extends",
+];
+
+/// Sections that can be started with a `[name]` line. Entries before the first
+/// section header are letters.
+pub(crate) const SECTIONS: &[&str] = &["letters", "digits"];
+
+/// Directives that go in the `[metadata]` table of a version 2 file. The others go
+/// in the `[words]` table.
+pub(crate) const METADATA: &[&str] = &[
+    "name",
+    "language",
+    "region",
+    "source",
+    "aliases",
+    "years",
+    "deprecated",
+    "extends",
+];
+
+/// Tables of a version 2 file mapping keys to code words
+pub(crate) const ENTRY_TABLES: &[&str] = &["letters", "digits", "punctuation"];
+
+/// Tables of a version 2 file holding the other columns of entries, by key
+pub(crate) const COLUMN_TABLES: &[&str] = &["alternates", "pronunciation", "ipa"];
+
+/// Contents of a parsed alphabet file
+#[derive(Debug, Default)]
+pub(crate) struct ParsedAlphabet {
+    /// `(name, value)` pairs of the recognized directives. For files without a
+    /// `name` directive, a first line that is a plain comment is used as the name.
+    pub directives: Vec<(String, String)>,
+    /// `(key, word)` pairs in the order they appear in. Keys are lowercased.
+    pub entries: Vec<(String, String)>,
+}
+
+/// Parse an alphabet file in either version of the format. On failure, the line
+/// number (starting at 1, or 0 if the error isn't tied to a line) and the reason
+/// are returned.
+pub(crate) fn parse(s: &str) -> Result<ParsedAlphabet, (usize, String)> {
+    if is_v2(s) {
+        parse_v2(s)
+    } else {
+        parse_v1(s).map_err(|(line, reason)| (line, reason.to_string()))
+    }
+}
+
+/// Whether an alphabet file uses version 2 of the format, which it does when the
+/// first line that isn't empty or a comment is the `[metadata]` header
+pub(crate) fn is_v2(s: &str) -> bool {
+    s.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .is_some_and(|line| line == "[metadata]")
+}
+
+// Parse a version 1 (line based) alphabet file
+fn parse_v1(s: &str) -> Result<ParsedAlphabet, (usize, &'static str)> {
+    let mut parsed = ParsedAlphabet::default();
+    let mut header = None;
+    let mut section = "letters";
+    for (i, line) in s.lines().enumerate() {
+        // Filter empty lines and comments, picking up the header and any directives
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            let directive = comment
+                .split_once(':')
+                .map(|(name, value)| (name.trim(), value.trim()))
+                .filter(|(name, _)| DIRECTIVES.contains(name));
+            match directive {
+                Some((name, value)) => parsed
+                    .directives
+                    .push((name.to_string(), value.to_string())),
+                None if i == 0 => header = Some(comment.trim().to_string()),
+                None => {}
+            }
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = SECTIONS
+                .iter()
+                .find(|section| **section == name.trim())
+                .ok_or((i + 1, "unknown section"))?;
+            continue;
+        }
+
+        let (key, word) = match line.split_once(' ') {
+            Some((key, word)) => (key, word.trim()),
+            None => (line, ""),
+        };
+        if key.is_empty() {
+            return Err((i + 1, "missing key"));
+        }
+        check_word(word).map_err(|reason| (i + 1, reason))?;
+        if section == "digits" && !key.chars().all(|c| c.is_ascii_digit()) {
+            return Err((i + 1, "digit keys must be numbers"));
+        }
+        parsed.entries.push((key.to_lowercase(), word.to_string()));
+    }
+
+    if let Some(header) = header {
+        if !parsed.directives.iter().any(|(name, _)| name == "name") {
+            parsed.directives.push(("name".to_string(), header));
+        }
+    }
+    Ok(parsed)
+}
+
+/// Check the text of an entry: a code word with optional alternates separated by
+/// `|`, optionally followed by a pronunciation and an IPA transcription, each
+/// after a `;` (e.g. `Alpha|Alfa ; AL-fah ; ˈælfə`)
+pub(crate) fn check_word(text: &str) -> Result<(), &'static str> {
+    let mut columns = text.split(';');
+    let word = columns.next().unwrap_or_default();
+    if word.split('|').any(|w| w.trim().is_empty()) {
+        return Err("missing code word");
+    }
+    if columns.count() > 2 {
+        return Err("too many columns");
+    }
+    Ok(())
+}
+
+// Parse a version 2 (TOML) alphabet file:
+//
+// ```toml
+// [metadata]
+// name = "NATO"
+// aliases = ["icao", "aviation"]
+//
+// [words]
+// space = "space"
+// prowords = ["I spell", "break", "over"]
+//
+// [letters]
+// a = "Alpha"
+//
+// [digits]
+// 1 = "One"
+//
+// [punctuation]
+// "." = "Stop"
+//
+// [alternates]
+// a = ["Alfa"]
+//
+// [pronunciation]
+// a = "AL-fah"
+//
+// [ipa]
+// a = "ˈælfə"
+// ```
+//
+// Lists in `[metadata]` and `[words]` are joined the way directives separate them.
+// Alternates, pronunciations and IPA transcriptions are merged into the text of the
+// entry with the same key.
+fn parse_v2(s: &str) -> Result<ParsedAlphabet, (usize, String)> {
+    let table: toml::Table = s.parse().map_err(|e: toml::de::Error| {
+        let line = e.span().map_or(0, |span| s[..span.start].lines().count());
+        (line, e.message().to_string())
+    })?;
+    let error =
+        |section: &str, key: &str, reason: &str| (line_of(s, section, key), reason.to_string());
+    let mut parsed = ParsedAlphabet::default();
+    let mut entries = Vec::new();
+    let mut columns: Vec<(&str, &str, &toml::Value)> = Vec::new();
+    for (section, value) in &table {
+        let toml::Value::Table(values) = value else {
+            return Err(error(section, "", "expected a table"));
+        };
+        for (key, value) in values {
+            match section.as_str() {
+                "metadata" | "words" => {
+                    let directive = DIRECTIVES
+                        .iter()
+                        .find(|d| **d == key && METADATA.contains(d) == (section == "metadata"))
+                        .ok_or_else(|| error(section, key, "unknown directive"))?;
+                    let value = directive_value(directive, value)
+                        .ok_or_else(|| error(section, key, "expected a string"))?;
+                    parsed.directives.push((directive.to_string(), value));
+                }
+                section if ENTRY_TABLES.contains(&section) => {
+                    let toml::Value::String(word) = value else {
+                        return Err(error(section, key, "expected a string"));
+                    };
+                    if section == "digits" && !key.chars().all(|c| c.is_ascii_digit()) {
+                        return Err(error(section, key, "digit keys must be numbers"));
+                    }
+                    entries.push((section, key.as_str(), word.as_str()));
+                }
+                section if COLUMN_TABLES.contains(&section) => {
+                    columns.push((section, key.as_str(), value));
+                }
+                _ => return Err(error(section, "", "unknown section")),
+            }
+        }
+    }
+
+    for (section, key, _) in &columns {
+        if !entries.iter().any(|(_, k, _)| k == key) {
+            return Err(error(section, key, "no code word for this key"));
+        }
+    }
+    for (section, key, word) in entries {
+        let column = |name: &str| {
+            columns
+                .iter()
+                .find(|(section, k, _)| *section == name && *k == key)
+                .map(|(_, _, value)| *value)
+        };
+        let alternates = match column("alternates") {
+            None => Vec::new(),
+            Some(toml::Value::Array(alternates)) => alternates
+                .iter()
+                .map(|a| a.as_str().ok_or("alternates must be strings"))
+                .collect::<Result<_, _>>()
+                .map_err(|reason| error("alternates", key, reason))?,
+            Some(_) => return Err(error("alternates", key, "expected a list")),
+        };
+        let string = |name: &str| match column(name) {
+            None => Ok(None),
+            Some(toml::Value::String(s)) => Ok(Some(s.as_str())),
+            Some(_) => Err(error(name, key, "expected a string")),
+        };
+        let text = entry_text(word, &alternates, string("pronunciation")?, string("ipa")?)
+            .map_err(|reason| error(section, key, reason))?;
+        check_word(&text).map_err(|reason| error(section, key, reason))?;
+        parsed.entries.push((key.to_lowercase(), text));
+    }
+    Ok(parsed)
+}
+
+// The value of a directive in a version 2 file. Lists are joined with the separator
+// the directive uses, booleans and numbers are written out.
+fn directive_value(directive: &str, value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::Array(values) => {
+            let separator = if directive == "prowords" { " | " } else { ", " };
+            let values = values
+                .iter()
+                .map(|v| v.as_str())
+                .collect::<Option<Vec<_>>>()?;
+            Some(values.join(separator))
+        }
+        _ => None,
+    }
+}
+
+// The line (starting at 1) of a key in a table of a TOML file, falling back to the
+// line of the table header and 0 if neither is found
+fn line_of(s: &str, section: &str, key: &str) -> usize {
+    let mut current = "";
+    let mut header = 0;
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = name.trim();
+            if current == section {
+                header = i + 1;
+            }
+            continue;
+        }
+        let name = line.split('=').next().unwrap_or_default().trim();
+        if current == section && !key.is_empty() && name.trim_matches('"') == key {
+            return i + 1;
+        }
+    }
+    header
+}
+
+/// The text of an entry in the version 1 format, which is how entries are stored
+/// (e.g. "Alpha|Alfa ; AL-fah ; ˈælfə")
+pub(crate) fn entry_text(
+    word: &str,
+    alternates: &[&str],
+    pronunciation: Option<&str>,
+    ipa: Option<&str>,
+) -> Result<String, &'static str> {
+    let mut fields = alternates.iter().chain(&pronunciation).chain(&ipa);
+    if word.contains(['|', ';']) || fields.any(|f| f.contains(['|', ';'])) {
+        return Err("fields can't contain '|' or ';'");
+    }
+    let mut text = std::iter::once(word.trim())
+        .chain(alternates.iter().map(|a| a.trim()))
+        .collect::<Vec<_>>()
+        .join("|");
+    let pronunciation = pronunciation.map(str::trim).unwrap_or_default();
+    let ipa = ipa.map(str::trim).unwrap_or_default();
+    if !ipa.is_empty() {
+        text = format!("{} ; {} ; {}", text, pronunciation, ipa);
+    } else if !pronunciation.is_empty() {
+        text = format!("{} ; {}", text, pronunciation);
+    }
+    Ok(text)
+}
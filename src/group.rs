@@ -0,0 +1,57 @@
+use crate::{SalphError, Spelling, SpellingAlphabet, SpellingOptions};
+
+impl SpellingAlphabet {
+    /// Spell a string in groups of `size` code words, the way coded messages are
+    /// transmitted. Whitespace is ignored, so groups continue across the words of
+    /// the input. The last group can be smaller. Characters without a mapping are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let groups = spelling_alphabet.groups("abc de fgh", 3);
+    /// let words = groups
+    ///     .iter()
+    ///     .map(|group| group.iter().map(|s| s.spelling.as_str()).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(words[0], ["Alpha", "Bravo", "Charlie"]);
+    /// assert_eq!(words[1], ["Delta", "Echo", "Foxtrot"]);
+    /// assert_eq!(words[2], ["Golf", "Hotel"]);
+    /// ```
+    pub fn groups(&self, s: &str, size: usize) -> Vec<Vec<Spelling>> {
+        self.groups_with(s, size, &SpellingOptions::new())
+            .expect("skipping unmatched characters can't fail")
+    }
+
+    /// Spell a string in groups like [`SpellingAlphabet::groups`], using the given
+    /// options. Spelled whitespace is left out as well, while characters without a
+    /// mapping that are kept or replaced count as part of a group.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions, Unmatched};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().unmatched(Unmatched::Keep);
+    /// let groups = spelling_alphabet.groups_with("a☃ b", 2, &options).unwrap();
+    /// assert_eq!(groups[0][1].spelling, "☃");
+    /// assert_eq!(groups[1][0].spelling, "Bravo");
+    /// ```
+    pub fn groups_with(
+        &self,
+        s: &str,
+        size: usize,
+        options: &SpellingOptions,
+    ) -> Result<Vec<Vec<Spelling>>, SalphError> {
+        assert!(size > 0, "group size can't be 0");
+        let spellings = self
+            .str_to_spellings_with(s, options)?
+            .into_iter()
+            // Kept whitespace is left out as well
+            .filter(|spelling| !spelling.source.chars().all(char::is_whitespace))
+            .collect::<Vec<_>>();
+        Ok(spellings.chunks(size).map(<[_]>::to_vec).collect())
+    }
+}
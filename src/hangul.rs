@@ -0,0 +1,142 @@
+//! Hangul, as spelled with the Korean alphabet (`Alphabet::ko`). Its code words are
+//! keyed by jamo, the letters that make up a syllable block. A syllable that has no
+//! code word of its own is spelled letter by letter:
+//!
+//! 1. The syllable is split into its initial consonant, its vowel and its final
+//!    consonant, if it has one (e.g. "한" into "ㅎ", "ㅏ" and "ㄴ"), see
+//!    [`decompose`].
+//! 2. Letters without a code word that are made of other letters, like double
+//!    consonants and diphthongs, are split into those letters (e.g. "ㄲ" into "ㄱ"
+//!    and "ㄱ" and "ㅘ" into "ㅗ" and "ㅏ"), see [`split_compound`].
+//!
+//! A syllable is only spelled if every letter it ends up with has a code word.
+//! Decomposed jamo in the input are composed into syllables first.
+//! ```
+//! # #[cfg(feature = "all-alphabets")] {
+//! use salph::{SpellingAlphabet, Alphabet};
+//!
+//! let spelling_alphabet = SpellingAlphabet::load(Alphabet::ko).unwrap();
+//! let words = spelling_alphabet
+//!         .str_to_spellings("한")
+//!         .iter()
+//!         .map(|x| x.spelling.clone())
+//!         .collect::<Vec<_>>();
+//! assert_eq!(words, ["하늘의 히읗", "아버지의 아", "나비의 니은"]);
+//! # }
+//! ```
+
+// Code point of the first syllable block, "가"
+const FIRST_SYLLABLE: u32 = 0xAC00;
+// Number of syllable blocks
+const SYLLABLES: u32 = 11172;
+
+// Initial consonants, vowels and final consonants in the order syllable blocks are
+// numbered in
+const INITIALS: &[char] = &[
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+const VOWELS: &[char] = &[
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+const FINALS: &[char] = &[
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+// Letters that are made of other letters
+const COMPOUNDS: &[(char, &str)] = &[
+    ('ㄲ', "ㄱㄱ"),
+    ('ㄸ', "ㄷㄷ"),
+    ('ㅃ', "ㅂㅂ"),
+    ('ㅆ', "ㅅㅅ"),
+    ('ㅉ', "ㅈㅈ"),
+    ('ㄳ', "ㄱㅅ"),
+    ('ㄵ', "ㄴㅈ"),
+    ('ㄶ', "ㄴㅎ"),
+    ('ㄺ', "ㄹㄱ"),
+    ('ㄻ', "ㄹㅁ"),
+    ('ㄼ', "ㄹㅂ"),
+    ('ㄽ', "ㄹㅅ"),
+    ('ㄾ', "ㄹㅌ"),
+    ('ㄿ', "ㄹㅍ"),
+    ('ㅀ', "ㄹㅎ"),
+    ('ㅄ', "ㅂㅅ"),
+    ('ㅐ', "ㅏㅣ"),
+    ('ㅒ', "ㅑㅣ"),
+    ('ㅔ', "ㅓㅣ"),
+    ('ㅖ', "ㅕㅣ"),
+    ('ㅘ', "ㅗㅏ"),
+    ('ㅙ', "ㅗㅏㅣ"),
+    ('ㅚ', "ㅗㅣ"),
+    ('ㅝ', "ㅜㅓ"),
+    ('ㅞ', "ㅜㅓㅣ"),
+    ('ㅟ', "ㅜㅣ"),
+    ('ㅢ', "ㅡㅣ"),
+];
+
+/// Split a Hangul syllable block into its letters: the initial consonant, the
+/// vowel and the final consonant, if there is one. Letters are returned as
+/// compatibility jamo (e.g. "ㄱ" rather than the conjoining "ᄀ"). Returns `None`
+/// for characters that aren't syllable blocks.
+/// ```
+/// use salph::hangul;
+///
+/// assert_eq!(hangul::decompose('한'), Some(vec!['ㅎ', 'ㅏ', 'ㄴ']));
+/// assert_eq!(hangul::decompose('과'), Some(vec!['ㄱ', 'ㅘ']));
+/// assert_eq!(hangul::decompose('ㄱ'), None);
+/// ```
+pub fn decompose(syllable: char) -> Option<Vec<char>> {
+    let index = (syllable as u32).checked_sub(FIRST_SYLLABLE)?;
+    if index >= SYLLABLES {
+        return None;
+    }
+    let (vowels, finals) = (VOWELS.len() as u32, FINALS.len() as u32 + 1);
+    let mut letters = vec![
+        INITIALS[(index / (vowels * finals)) as usize],
+        VOWELS[(index % (vowels * finals) / finals) as usize],
+    ];
+    // Final consonants are numbered from 1, 0 means there is none
+    if let Some(last) = (index % finals).checked_sub(1) {
+        letters.push(FINALS[last as usize]);
+    }
+    Some(letters)
+}
+
+/// Split a double consonant, consonant cluster or diphthong into the basic letters
+/// it's made of. Returns `None` for basic letters.
+/// ```
+/// use salph::hangul;
+///
+/// assert_eq!(hangul::split_compound('ㄲ'), Some("ㄱㄱ"));
+/// assert_eq!(hangul::split_compound('ㅘ'), Some("ㅗㅏ"));
+/// assert_eq!(hangul::split_compound('ㄱ'), None);
+/// ```
+pub fn split_compound(letter: char) -> Option<&'static str> {
+    COMPOUNDS
+        .iter()
+        .find(|(compound, _)| *compound == letter)
+        .map(|(_, letters)| *letters)
+}
+
+// The letters to spell a grapheme holding Hangul with, splitting compound letters
+// that `has_key` has no key for. Returns `None` if the grapheme holds no Hangul.
+pub(crate) fn letters(grapheme: &str, has_key: impl Fn(char) -> bool) -> Option<String> {
+    let is_letter = |c: char| matches!(c, '\u{3131}'..='\u{3163}');
+    let chars: Vec<char> = grapheme
+        .chars()
+        .flat_map(|c| decompose(c).unwrap_or_else(|| vec![c]))
+        .collect();
+    if !chars.iter().any(|c| is_letter(*c)) {
+        return None;
+    }
+    let letters = chars
+        .into_iter()
+        .flat_map(|c| match split_compound(c) {
+            Some(letters) if !has_key(c) => letters.chars().collect(),
+            _ => vec![c],
+        })
+        .collect();
+    Some(letters)
+}
@@ -0,0 +1,210 @@
+use crate::{
+    export::SECTIONS,
+    format::{self, entry_text},
+    AlphabetBuilder, SalphError, SpellingAlphabet,
+};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+impl SpellingAlphabet {
+    /// Parse an alphabet from JSON, using the structure written by
+    /// [`SpellingAlphabet::to_json`]: directives (like `name`, `space` and
+    /// `connector`, see [`SpellingAlphabet::parse`]) as top-level strings and
+    /// `letters`, `digits` and `punctuation` arrays of entries. Every
+    /// entry needs a `key` and a `word` and can have `alternates`, a
+    /// `pronunciation` and an `ipa` transcription.
+    /// ```
+    /// use salph::{SpellingAlphabet, SalphError};
+    ///
+    /// let json = r#"{
+    ///     "name": "Custom",
+    ///     "letters": [{"key": "a", "word": "Amsterdam", "alternates": ["Antwerpen"]}],
+    ///     "digits": [{"key": "1", "word": "een"}]
+    /// }"#;
+    /// let spelling_alphabet = SpellingAlphabet::from_json(json).unwrap();
+    /// let spellings = spelling_alphabet.str_to_spellings("a1");
+    /// assert_eq!(spellings[0].spelling, "Amsterdam");
+    /// assert_eq!(spellings[0].alternates, ["Antwerpen"]);
+    /// assert_eq!(spellings[1].spelling, "een");
+    ///
+    /// let res = SpellingAlphabet::from_json(r#"{"letters": [{"key": "a"}]}"#);
+    /// assert!(matches!(res, Err(SalphError::ParseError { .. })));
+    /// ```
+    pub fn from_json(s: &str) -> Result<SpellingAlphabet, SalphError> {
+        let value: Value =
+            serde_json::from_str(s).map_err(|e| SalphError::parse(e.line(), &e.to_string()))?;
+        from_structured(value)
+    }
+
+    /// Parse an alphabet from TOML in version 2 of the alphabet file format, as
+    /// written by [`SpellingAlphabet::to_toml`] and used by the files in the
+    /// alphabets directory. See [`SpellingAlphabet::parse`] for the tables.
+    ///
+    /// The flat schema of earlier versions, with the directives at the top level
+    /// and `[[letters]]` arrays of entries like [`SpellingAlphabet::from_json`],
+    /// is deprecated. It's still read for now, so older exports keep working.
+    /// ```
+    /// use salph::SpellingAlphabet;
+    ///
+    /// let toml = r#"
+    /// [metadata]
+    /// name = "Custom"
+    ///
+    /// [words]
+    /// space = "gap"
+    ///
+    /// [letters]
+    /// a = "Amsterdam"
+    ///
+    /// [pronunciation]
+    /// a = "AM-ster-dam"
+    /// "#;
+    /// let spelling_alphabet = SpellingAlphabet::from_toml(toml).unwrap();
+    /// assert_eq!(spelling_alphabet.space_word(), "gap");
+    /// let spellings = spelling_alphabet.str_to_spellings("a");
+    /// assert_eq!(spellings[0].pronunciation.as_deref(), Some("AM-ster-dam"));
+    /// ```
+    pub fn from_toml(s: &str) -> Result<SpellingAlphabet, SalphError> {
+        if format::is_v2(s) {
+            return SpellingAlphabet::parse(s);
+        }
+        // The deprecated flat schema
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| {
+            let line = e.span().map_or(0, |span| s[..span.start].lines().count());
+            SalphError::parse(line, e.message())
+        })?;
+        let value =
+            serde_json::to_value(table).map_err(|e| SalphError::parse(0, &e.to_string()))?;
+        from_structured(value)
+    }
+
+    /// Parse the entries of an alphabet from CSV, using the columns written by
+    /// [`SpellingAlphabet::to_csv`]. The header row names the columns: `key` and
+    /// `word` are required, `alternates` (separated by `|`), `pronunciation` and
+    /// `ipa` are optional and other columns are ignored.
+    /// ```
+    /// use salph::{SpellingAlphabet, SalphError};
+    ///
+    /// let csv = "key,word,alternates\na,Amsterdam,Antwerpen|Arnhem\nb,Berlin,\n";
+    /// let spelling_alphabet = SpellingAlphabet::from_csv(csv).unwrap();
+    /// let spellings = spelling_alphabet.str_to_spellings("ab");
+    /// assert_eq!(spellings[0].alternates, ["Antwerpen", "Arnhem"]);
+    /// assert_eq!(spellings[1].spelling, "Berlin");
+    ///
+    /// let res = SpellingAlphabet::from_csv("key,code\na,Amsterdam\n");
+    /// assert!(matches!(res, Err(SalphError::ParseError { line: 1, .. })));
+    /// ```
+    pub fn from_csv(s: &str) -> Result<SpellingAlphabet, SalphError> {
+        let csv_error = |e: csv::Error| {
+            let line = e.position().map_or(0, |position| position.line() as usize);
+            SalphError::parse(line, &e.to_string())
+        };
+        let mut reader = csv::Reader::from_reader(s.as_bytes());
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let column = |name: &str| headers.iter().position(|header| header.trim() == name);
+        let (key, word) = match (column("key"), column("word")) {
+            (Some(key), Some(word)) => (key, word),
+            _ => return Err(SalphError::parse(1, "missing key or word column")),
+        };
+        let (alternates, pronunciation, ipa) =
+            (column("alternates"), column("pronunciation"), column("ipa"));
+
+        let mut builder = AlphabetBuilder::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let line = record.position().map_or(0, |p| p.line() as usize);
+            let field =
+                |column: Option<usize>| column.and_then(|c| record.get(c)).unwrap_or_default();
+            let alternates = field(alternates)
+                .split('|')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .collect::<Vec<_>>();
+            let text = entry_text(
+                field(Some(word)),
+                &alternates,
+                Some(field(pronunciation)),
+                Some(field(ipa)),
+            )
+            .map_err(|reason| SalphError::parse(line, reason))?;
+            builder = builder.add(field(Some(key)).trim(), &text);
+        }
+        builder.build()
+    }
+}
+
+// Build an alphabet from a JSON value with the structured schema
+fn from_structured(value: Value) -> Result<SpellingAlphabet, SalphError> {
+    let Value::Object(object) = value else {
+        return Err(SalphError::parse(
+            0,
+            "expected a table of directives and entries",
+        ));
+    };
+    let mut builder = AlphabetBuilder::new();
+    for (name, value) in &object {
+        if let Some((section, _)) = SECTIONS.iter().find(|(section, _)| section == name) {
+            let Value::Array(entries) = value else {
+                return Err(SalphError::parse(
+                    0,
+                    &format!("'{}' must be a list of entries", section),
+                ));
+            };
+            for entry in entries {
+                let (key, text) = structured_entry(entry).map_err(|reason| {
+                    SalphError::parse(0, &format!("{} in '{}'", reason, section))
+                })?;
+                if *section == "digits" && !key.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(SalphError::parse(0, "digit keys must be numbers"));
+                }
+                builder = builder.add(key, &text);
+            }
+        } else if format::DIRECTIVES.contains(&name.as_str()) {
+            let Value::String(value) = value else {
+                return Err(SalphError::parse(
+                    0,
+                    &format!("'{}' must be a string", name),
+                ));
+            };
+            builder
+                .directives
+                .insert(Cow::Owned(name.clone()), Cow::Owned(value.clone()));
+        } else {
+            return Err(SalphError::parse(0, &format!("unknown field '{}'", name)));
+        }
+    }
+    builder.build()
+}
+
+// The key and entry text of a structured entry
+fn structured_entry(entry: &Value) -> Result<(&str, String), &'static str> {
+    let Value::Object(entry) = entry else {
+        return Err("entries must be tables");
+    };
+    let key = string(entry, "key")?.ok_or("missing key")?;
+    let word = string(entry, "word")?.ok_or("missing code word")?;
+    let alternates = match entry.get("alternates") {
+        None => Vec::new(),
+        Some(Value::Array(alternates)) => alternates
+            .iter()
+            .map(|a| a.as_str().ok_or("alternates must be strings"))
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err("alternates must be a list"),
+    };
+    let text = entry_text(
+        word,
+        &alternates,
+        string(entry, "pronunciation")?,
+        string(entry, "ipa")?,
+    )?;
+    Ok((key.trim(), text))
+}
+
+// An optional string field of a structured entry
+fn string<'a>(entry: &'a Map<String, Value>, name: &str) -> Result<Option<&'a str>, &'static str> {
+    match entry.get(name) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.as_str())),
+        Some(_) => Err("entry fields must be strings"),
+    }
+}
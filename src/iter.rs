@@ -0,0 +1,495 @@
+use crate::{
+    address::{address_ranges, address_word},
+    code_word,
+    digits::{
+        english_digit, icao_digit, icao_number, is_digit, number_words, ENGLISH_DIGITS,
+        MAX_NUMBER_LEN,
+    },
+    fold::{fold_case, fold_diacritics},
+    hangul, kana, DigitStyle, Normalization, SalphError, SpellingAlphabet, SpellingKind,
+    SpellingOptions, SpellingRef, Unmatched,
+};
+use std::{borrow::Cow, collections::VecDeque, ops::Range};
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+/// Iterator that lazily maps a string to [`SpellingRef`]s.
+///
+/// The algorithm works as follows (using "foobar" as an input):
+/// - We fill a lookahead buffer with `max_ngram_len` graphemes ("foo")
+/// - We walk the alphabet's trie with the buffered graphemes to find the longest
+///   key that is a prefix of the buffer (e.g. in Spanish, "ll" needs to match
+///   before "l")
+/// - If we do match, we yield the result and drop all graphemes that were part
+///   of the match from the lookahead buffer. Otherwise the first grapheme is
+///   handled as unmatched.
+///
+/// All indexing happens on grapheme clusters rather than bytes, so that
+/// multi-byte characters (e.g. "é" or "ß") and characters consisting of
+/// multiple code points (e.g. "e" followed by a combining accent) are treated
+/// as a single unit. Only `max_ngram_len` graphemes are buffered at any time, or
+/// one more than `MAX_NUMBER_LEN` while looking for the end of a number.
+/// Graphemes are normalized as they enter the buffer, since normalization never
+/// crosses grapheme boundaries.
+///
+/// Code words are borrowed from the alphabet and sources are borrowed from the
+/// input whenever normalization didn't change them, so in the common case no
+/// allocations happen per character.
+pub(crate) struct SpellingIter<'a> {
+    alphabet: &'a SpellingAlphabet,
+    options: &'a SpellingOptions,
+    input: &'a str,
+    graphemes: Graphemes<'a>,
+    // Lookahead buffer holding (at least) `max_ngram_len` normalized graphemes
+    buffer: VecDeque<Cow<'a, str>>,
+    // Position of the first grapheme in the buffer
+    position: usize,
+    // Spellings that were produced but not yet returned, used when a single
+    // grapheme results in multiple spellings
+    pending: VecDeque<SpellingRef<'a>>,
+    // Grapheme ranges of the email addresses and URLs in the input, if their
+    // separators are spelled
+    addresses: Vec<Range<usize>>,
+    // Whether the digits at the front of the buffer continue a run that is too
+    // long to be a number
+    long_run: bool,
+}
+
+impl<'a> SpellingIter<'a> {
+    pub(crate) fn new(
+        alphabet: &'a SpellingAlphabet,
+        s: &'a str,
+        options: &'a SpellingOptions,
+    ) -> SpellingIter<'a> {
+        SpellingIter {
+            alphabet,
+            options,
+            input: s,
+            graphemes: s.graphemes(true),
+            buffer: VecDeque::with_capacity(alphabet.max_ngram_len),
+            position: 0,
+            pending: VecDeque::new(),
+            addresses: if options.spell_addresses {
+                // Positions count normalized graphemes, so the addresses are found in
+                // the input as it ends up in the buffer
+                let normalized = s
+                    .graphemes(true)
+                    .map(|g| normalize(options.normalization, g))
+                    .collect::<Vec<_>>();
+                let graphemes = normalized
+                    .iter()
+                    .flat_map(|g| g.graphemes(true))
+                    .collect::<Vec<_>>();
+                address_ranges(&graphemes)
+            } else {
+                Vec::new()
+            },
+            long_run: false,
+        }
+    }
+
+    // The word to announce before the spelling of `source`, if case announcement is
+    // enabled and `source` is uppercase
+    fn case_prefix(&self, source: &str) -> Option<&'a str> {
+        if !self.options.announce_case || !source.chars().any(char::is_uppercase) {
+            return None;
+        }
+        Some(self.capital_word())
+    }
+
+    // The word to announce uppercase letters with
+    fn capital_word(&self) -> &'a str {
+        let options: &'a SpellingOptions = self.options;
+        let alphabet: &'a SpellingAlphabet = self.alphabet;
+        options
+            .capital_word
+            .as_deref()
+            .unwrap_or_else(|| alphabet.capital_word())
+    }
+
+    // The word for a separator of an email address or URL, if the grapheme is part
+    // of one
+    fn address_word(&self, grapheme: &str, position: usize) -> Option<&'static str> {
+        let address = self.addresses.iter().any(|r| r.contains(&position));
+        address_word(grapheme).filter(|_| address)
+    }
+
+    // Normalize a grapheme and add it to the lookahead buffer
+    fn push_normalized(&mut self, grapheme: &'a str) {
+        match normalize(self.options.normalization, grapheme) {
+            Cow::Borrowed(grapheme) => self.buffer.push_back(Cow::Borrowed(grapheme)),
+            // Compatibility normalization can split a grapheme into multiple
+            // graphemes (e.g. "ﬁ" into "f" and "i")
+            Cow::Owned(normalized) => self.buffer.extend(
+                normalized
+                    .graphemes(true)
+                    .map(|g| Cow::Owned(g.to_string())),
+            ),
+        }
+    }
+
+    // Spell a grapheme that didn't match after folding its diacritics. Every
+    // character of the folded grapheme needs a mapping, otherwise `None` is returned.
+    fn spell_folded(
+        &self,
+        grapheme: &Cow<'a, str>,
+        position: usize,
+    ) -> Option<Vec<SpellingRef<'a>>> {
+        let folded: String = fold_diacritics(grapheme).nfc().collect();
+        if folded == *grapheme {
+            return None;
+        }
+        self.spell_chars(grapheme, &folded, position, true)
+    }
+
+    // Spell a grapheme holding kana that didn't match as its plain kana and voicing
+    // marks (e.g. "ガ" as "カ" and "゛")
+    fn spell_kana(&self, grapheme: &Cow<'a, str>, position: usize) -> Option<Vec<SpellingRef<'a>>> {
+        let decomposed = kana::decompose(grapheme)?;
+        self.spell_chars(grapheme, &decomposed, position, false)
+    }
+
+    // Spell a grapheme holding Hangul that didn't match letter by letter, as
+    // described in the `hangul` module
+    fn spell_hangul(
+        &self,
+        grapheme: &Cow<'a, str>,
+        position: usize,
+    ) -> Option<Vec<SpellingRef<'a>>> {
+        let alphabet = self.alphabet;
+        let letters = hangul::letters(grapheme, |c| {
+            alphabet.words.contains_key(c.to_string().as_str())
+        })?;
+        self.spell_chars(grapheme, &letters, position, false)
+    }
+
+    // Spell every character of `chars`, which stands for `grapheme`. Every
+    // character needs a mapping, otherwise `None` is returned.
+    fn spell_chars(
+        &self,
+        grapheme: &Cow<'a, str>,
+        chars: &str,
+        position: usize,
+        folded: bool,
+    ) -> Option<Vec<SpellingRef<'a>>> {
+        let alphabet: &'a SpellingAlphabet = self.alphabet;
+        let prefix = self.case_prefix(grapheme);
+        chars
+            .chars()
+            .map(|c| {
+                let key = fold_case(c).collect::<String>();
+                alphabet.words.get(key.as_str()).map(|word| {
+                    let word = code_word(word, self.options.variant);
+                    SpellingRef {
+                        spelling: Cow::Borrowed(word.spelling),
+                        kind: SpellingKind::classify(&key),
+                        source: grapheme.clone(),
+                        position,
+                        prefix,
+                        folded,
+                        alternates: word.alternates,
+                        pronunciation: word.pronunciation,
+                        ipa: word.ipa,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    // Spell the run of digits at the front of the buffer according to the digit
+    // style. The first spelling is returned and the others are queued.
+    fn spell_digits(&mut self) -> SpellingRef<'a> {
+        // Extend the buffer until it holds the whole run. Runs that are longer than
+        // a number can be are spelled digit by digit, so they don't need to be
+        // buffered in full.
+        while !self.long_run
+            && self.buffer.len() <= MAX_NUMBER_LEN
+            && self.buffer.iter().all(|g| is_digit(g))
+        {
+            match self.graphemes.next() {
+                Some(g) => self.push_normalized(g),
+                None => break,
+            }
+        }
+        let len = self.buffer.iter().take_while(|g| is_digit(g)).count();
+        let long_run = self.long_run || len > MAX_NUMBER_LEN;
+        let position = self.position;
+        let run = self.consume(len);
+
+        // Look at the next grapheme to find out whether the run goes on
+        if self.buffer.is_empty() {
+            if let Some(g) = self.graphemes.next() {
+                self.push_normalized(g);
+            }
+        }
+        self.long_run = long_run && self.buffer.front().is_some_and(|g| is_digit(g));
+
+        let alphabet: &'a SpellingAlphabet = self.alphabet;
+        let grouped = match self.options.digit_style {
+            _ if long_run => None,
+            DigitStyle::Icao => icao_number(&run),
+            DigitStyle::Number => {
+                let language = alphabet.directives.get("language");
+                number_words(&run, language.map(|l| l.as_ref()))
+            }
+            DigitStyle::Alphabet => None,
+        };
+        if let Some(words) = grouped {
+            return SpellingRef {
+                spelling: Cow::Owned(words),
+                kind: SpellingKind::Digit,
+                source: run,
+                position,
+                prefix: None,
+                folded: false,
+                alternates: Vec::new(),
+                pronunciation: None,
+                ipa: None,
+            };
+        }
+
+        for (i, digit) in run.bytes().enumerate() {
+            let source = match run {
+                Cow::Borrowed(s) => Cow::Borrowed(&s[i..=i]),
+                Cow::Owned(ref s) => Cow::Owned(s[i..=i].to_string()),
+            };
+            let word = match self.options.digit_style {
+                DigitStyle::Icao => icao_digit(digit),
+                _ => alphabet
+                    .words
+                    .get(&source[..])
+                    .map_or(ENGLISH_DIGITS[usize::from(digit - b'0')], |word| {
+                        word.as_ref()
+                    }),
+            };
+            let word = code_word(word, self.options.variant);
+            self.pending.push_back(SpellingRef {
+                spelling: Cow::Borrowed(word.spelling),
+                kind: SpellingKind::Digit,
+                source,
+                position: position + i,
+                prefix: None,
+                folded: false,
+                alternates: word.alternates,
+                pronunciation: word.pronunciation,
+                ipa: word.ipa,
+            });
+        }
+        self.pending
+            .pop_front()
+            .expect("a run of digits is never empty")
+    }
+
+    // Remove `n` graphemes from the front of the buffer and return them as a
+    // string. Graphemes that weren't changed by normalization are consecutive
+    // slices of the input, so they can be returned without copying.
+    fn consume(&mut self, n: usize) -> Cow<'a, str> {
+        self.position += n;
+        let borrowed = self
+            .buffer
+            .range(..n)
+            .all(|g| matches!(g, Cow::Borrowed(_)));
+        if !borrowed {
+            return Cow::Owned(self.buffer.drain(..n).collect());
+        }
+
+        let offset = |g: &str| g.as_ptr() as usize - self.input.as_ptr() as usize;
+        let start = offset(&self.buffer[0]);
+        let end = offset(&self.buffer[n - 1]) + self.buffer[n - 1].len();
+        self.buffer.drain(..n);
+        Cow::Borrowed(&self.input[start..end])
+    }
+}
+
+impl<'a> SpellingIter<'a> {
+    // The word announcing the class of a spelled character. Whitespace and
+    // letters without case aren't announced.
+    fn class_prefix(&self, spelling: &SpellingRef<'a>) -> Option<&'a str> {
+        let alphabet: &'a SpellingAlphabet = self.alphabet;
+        match spelling.kind {
+            SpellingKind::Letter if spelling.source.chars().any(char::is_uppercase) => {
+                Some(self.capital_word())
+            }
+            SpellingKind::Letter if spelling.source.chars().any(char::is_lowercase) => {
+                Some(alphabet.lowercase_word())
+            }
+            SpellingKind::Letter => None,
+            SpellingKind::Digit => Some(alphabet.digit_word()),
+            SpellingKind::Punctuation | SpellingKind::Symbol | SpellingKind::Unknown => {
+                Some(alphabet.symbol_word())
+            }
+            SpellingKind::Whitespace => None,
+        }
+    }
+
+    // Produce the next spelling, before the class of the character is announced
+    fn next_spelling(&mut self) -> Option<Result<SpellingRef<'a>, SalphError>> {
+        if let Some(spelling) = self.pending.pop_front() {
+            return Some(Ok(spelling));
+        }
+
+        let alphabet: &'a SpellingAlphabet = self.alphabet;
+        let options: &'a SpellingOptions = self.options;
+        loop {
+            // Top up the lookahead buffer
+            while self.buffer.len() < alphabet.max_ngram_len {
+                match self.graphemes.next() {
+                    Some(g) => self.push_normalized(g),
+                    None => break,
+                }
+            }
+            if self.buffer.is_empty() {
+                return None;
+            }
+
+            if options.digit_style != DigitStyle::Alphabet && is_digit(&self.buffer[0]) {
+                return Some(Ok(self.spell_digits()));
+            }
+
+            // Find the longest key matching the start of the buffer. Keys are stored
+            // composed, so decomposed input needs to be composed for the lookup.
+            let compose = matches!(
+                options.normalization,
+                Normalization::Nfd | Normalization::Nfkd
+            );
+            let longest = alphabet
+                .trie
+                .longest_match(self.buffer.iter().map(|g| g.as_ref()), compose);
+            if let Some((len, index)) = longest {
+                let (key, word) = alphabet.words.get_index(index)?;
+                let position = self.position;
+                let source = self.consume(len);
+                let word = code_word(word, options.variant);
+                return Some(Ok(SpellingRef {
+                    spelling: Cow::Borrowed(word.spelling),
+                    kind: SpellingKind::classify(key),
+                    prefix: self.case_prefix(&source),
+                    source,
+                    position,
+                    folded: false,
+                    alternates: word.alternates,
+                    pronunciation: word.pronunciation,
+                    ipa: word.ipa,
+                }));
+            }
+
+            // Nothing matched, so fall back to English for digits and separators of
+            // addresses, split kana with voicing marks and Hangul syllables, try folding
+            // diacritics, spell whitespace or name symbols if requested. Otherwise apply
+            // the unmatched policy and skip the grapheme.
+            let position = self.position;
+            let grapheme = self.consume(1);
+            if let Some(word) = english_digit(&grapheme) {
+                return Some(Ok(SpellingRef {
+                    spelling: Cow::Borrowed(word),
+                    kind: SpellingKind::Digit,
+                    source: grapheme,
+                    position,
+                    prefix: None,
+                    folded: false,
+                    alternates: Vec::new(),
+                    pronunciation: None,
+                    ipa: None,
+                }));
+            }
+            if let Some(word) = self.address_word(&grapheme, position) {
+                return Some(Ok(SpellingRef {
+                    spelling: Cow::Borrowed(word),
+                    kind: SpellingKind::Punctuation,
+                    source: grapheme,
+                    position,
+                    prefix: None,
+                    folded: false,
+                    alternates: Vec::new(),
+                    pronunciation: None,
+                    ipa: None,
+                }));
+            }
+            let decomposed = self
+                .spell_kana(&grapheme, position)
+                .or_else(|| self.spell_hangul(&grapheme, position));
+            if let Some(spellings) = decomposed {
+                self.pending.extend(spellings);
+                if let Some(spelling) = self.pending.pop_front() {
+                    return Some(Ok(spelling));
+                }
+            }
+            if options.fold_diacritics {
+                if let Some(spellings) = self.spell_folded(&grapheme, position) {
+                    self.pending.extend(spellings);
+                    if let Some(spelling) = self.pending.pop_front() {
+                        return Some(Ok(spelling));
+                    }
+                }
+            }
+            let unmatched = |spelling: Cow<'a, str>, kind: SpellingKind| SpellingRef {
+                spelling,
+                kind,
+                source: grapheme.clone(),
+                position,
+                prefix: None,
+                folded: false,
+                alternates: Vec::new(),
+                pronunciation: None,
+                ipa: None,
+            };
+            if options.spell_spaces && grapheme.chars().all(char::is_whitespace) {
+                let word = alphabet.space_word();
+                return Some(Ok(unmatched(Cow::Borrowed(word), SpellingKind::Whitespace)));
+            }
+            #[cfg(feature = "unicode-names")]
+            if options.name_symbols {
+                if let Some(name) = crate::names::name_grapheme(&grapheme) {
+                    return Some(Ok(unmatched(Cow::Owned(name), SpellingKind::Symbol)));
+                }
+            }
+            match &options.unmatched {
+                Unmatched::Skip => {}
+                Unmatched::Keep => {
+                    return Some(Ok(unmatched(grapheme.clone(), SpellingKind::Unknown)));
+                }
+                Unmatched::Replace(placeholder) => {
+                    return Some(Ok(unmatched(
+                        Cow::Borrowed(placeholder),
+                        SpellingKind::Unknown,
+                    )));
+                }
+                Unmatched::Error => {
+                    return Some(Err(SalphError::Unmatched {
+                        position,
+                        grapheme: grapheme.into_owned(),
+                    }));
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for SpellingIter<'a> {
+    type Item = Result<SpellingRef<'a>, SalphError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let spelling = self.next_spelling();
+        if !self.options.announce_class {
+            return spelling;
+        }
+        Some(spelling?.map(|mut spelling| {
+            spelling.prefix = self.class_prefix(&spelling);
+            spelling
+        }))
+    }
+}
+
+// Normalize a grapheme of the input, borrowing it if normalization doesn't change it
+fn normalize(normalization: Normalization, grapheme: &str) -> Cow<'_, str> {
+    match normalization {
+        Normalization::Nfc if is_nfc_quick(grapheme.chars()) == IsNormalized::Yes => {
+            Cow::Borrowed(grapheme)
+        }
+        Normalization::None => Cow::Borrowed(grapheme),
+        Normalization::Nfc => Cow::Owned(grapheme.nfc().collect()),
+        Normalization::Nfd => Cow::Owned(grapheme.nfd().collect()),
+        Normalization::Nfkc => Cow::Owned(grapheme.nfkc().collect()),
+        Normalization::Nfkd => Cow::Owned(grapheme.nfkd().collect()),
+    }
+}
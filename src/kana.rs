@@ -0,0 +1,301 @@
+//! Japanese kana, as spelled with the Japanese radiotelephony alphabet
+//! (`Alphabet::ja`). Its code words are keyed by katakana. Hiragana are matched as
+//! the katakana they correspond to, and kana with a voicing mark (e.g. "ガ") are
+//! spelled as the plain kana followed by the mark (e.g. "カ" and "゛"). Romaji can be
+//! transliterated to katakana first with [`from_romaji`].
+//! ```
+//! # #[cfg(feature = "all-alphabets")] {
+//! use salph::{SpellingAlphabet, Alphabet};
+//!
+//! let spelling_alphabet = SpellingAlphabet::load(Alphabet::ja).unwrap();
+//! let words = spelling_alphabet
+//!         .str_to_spellings("がす")
+//!         .iter()
+//!         .map(|x| x.spelling.clone())
+//!         .collect::<Vec<_>>();
+//! assert_eq!(words, ["為替のカ", "濁点", "すずめのス"]);
+//! # }
+//! ```
+use unicode_normalization::UnicodeNormalization;
+
+// Romanized syllables and the katakana they're written with, in Hepburn and
+// Kunrei-shiki romanization
+const SYLLABLES: &[(&str, &str)] = &[
+    ("a", "ア"),
+    ("i", "イ"),
+    ("u", "ウ"),
+    ("e", "エ"),
+    ("o", "オ"),
+    ("ka", "カ"),
+    ("ki", "キ"),
+    ("ku", "ク"),
+    ("ke", "ケ"),
+    ("ko", "コ"),
+    ("kya", "キャ"),
+    ("kyu", "キュ"),
+    ("kyo", "キョ"),
+    ("ga", "ガ"),
+    ("gi", "ギ"),
+    ("gu", "グ"),
+    ("ge", "ゲ"),
+    ("go", "ゴ"),
+    ("gya", "ギャ"),
+    ("gyu", "ギュ"),
+    ("gyo", "ギョ"),
+    ("sa", "サ"),
+    ("shi", "シ"),
+    ("si", "シ"),
+    ("su", "ス"),
+    ("se", "セ"),
+    ("so", "ソ"),
+    ("sha", "シャ"),
+    ("shu", "シュ"),
+    ("sho", "ショ"),
+    ("she", "シェ"),
+    ("sya", "シャ"),
+    ("syu", "シュ"),
+    ("syo", "ショ"),
+    ("za", "ザ"),
+    ("ji", "ジ"),
+    ("zi", "ジ"),
+    ("zu", "ズ"),
+    ("ze", "ゼ"),
+    ("zo", "ゾ"),
+    ("ja", "ジャ"),
+    ("ju", "ジュ"),
+    ("jo", "ジョ"),
+    ("je", "ジェ"),
+    ("zya", "ジャ"),
+    ("zyu", "ジュ"),
+    ("zyo", "ジョ"),
+    ("ta", "タ"),
+    ("chi", "チ"),
+    ("ti", "チ"),
+    ("tsu", "ツ"),
+    ("tu", "ツ"),
+    ("te", "テ"),
+    ("to", "ト"),
+    ("cha", "チャ"),
+    ("chu", "チュ"),
+    ("cho", "チョ"),
+    ("che", "チェ"),
+    ("tya", "チャ"),
+    ("tyu", "チュ"),
+    ("tyo", "チョ"),
+    ("da", "ダ"),
+    ("di", "ヂ"),
+    ("du", "ヅ"),
+    ("de", "デ"),
+    ("do", "ド"),
+    ("na", "ナ"),
+    ("ni", "ニ"),
+    ("nu", "ヌ"),
+    ("ne", "ネ"),
+    ("no", "ノ"),
+    ("nya", "ニャ"),
+    ("nyu", "ニュ"),
+    ("nyo", "ニョ"),
+    ("ha", "ハ"),
+    ("hi", "ヒ"),
+    ("fu", "フ"),
+    ("hu", "フ"),
+    ("he", "ヘ"),
+    ("ho", "ホ"),
+    ("hya", "ヒャ"),
+    ("hyu", "ヒュ"),
+    ("hyo", "ヒョ"),
+    ("fa", "ファ"),
+    ("fi", "フィ"),
+    ("fe", "フェ"),
+    ("fo", "フォ"),
+    ("ba", "バ"),
+    ("bi", "ビ"),
+    ("bu", "ブ"),
+    ("be", "ベ"),
+    ("bo", "ボ"),
+    ("bya", "ビャ"),
+    ("byu", "ビュ"),
+    ("byo", "ビョ"),
+    ("pa", "パ"),
+    ("pi", "ピ"),
+    ("pu", "プ"),
+    ("pe", "ペ"),
+    ("po", "ポ"),
+    ("pya", "ピャ"),
+    ("pyu", "ピュ"),
+    ("pyo", "ピョ"),
+    ("ma", "マ"),
+    ("mi", "ミ"),
+    ("mu", "ム"),
+    ("me", "メ"),
+    ("mo", "モ"),
+    ("mya", "ミャ"),
+    ("myu", "ミュ"),
+    ("myo", "ミョ"),
+    ("ya", "ヤ"),
+    ("yu", "ユ"),
+    ("yo", "ヨ"),
+    ("ra", "ラ"),
+    ("ri", "リ"),
+    ("ru", "ル"),
+    ("re", "レ"),
+    ("ro", "ロ"),
+    ("rya", "リャ"),
+    ("ryu", "リュ"),
+    ("ryo", "リョ"),
+    ("wa", "ワ"),
+    ("wo", "ヲ"),
+    ("vu", "ヴ"),
+];
+
+// Small kana and the kana they're a small version of
+const SMALL_KANA: &[(char, char)] = &[
+    ('ァ', 'ア'),
+    ('ィ', 'イ'),
+    ('ゥ', 'ウ'),
+    ('ェ', 'エ'),
+    ('ォ', 'オ'),
+    ('ッ', 'ツ'),
+    ('ャ', 'ヤ'),
+    ('ュ', 'ユ'),
+    ('ョ', 'ヨ'),
+    ('ヮ', 'ワ'),
+    ('ヵ', 'カ'),
+    ('ヶ', 'ケ'),
+];
+
+/// Transliterate romaji to katakana, so it can be spelled with the Japanese
+/// alphabet. Both Hepburn ("shi", "tsu") and Kunrei-shiki ("si", "tu") are
+/// accepted. A doubled consonant is written as a small "ッ", an "n" that isn't
+/// followed by a vowel or "y" as "ン" (use "n'" to separate it from a following
+/// vowel) and vowels with a macron or a "-" as a long vowel. Everything that isn't
+/// romaji is kept as is.
+/// ```
+/// use salph::kana;
+///
+/// assert_eq!(kana::from_romaji("sakura"), "サクラ");
+/// assert_eq!(kana::from_romaji("Tōkyō"), "トーキョー");
+/// assert_eq!(kana::from_romaji("konnichiwa"), "コンニチワ");
+/// assert_eq!(kana::from_romaji("kitte, matcha"), "キッテ, マッチャ");
+/// ```
+pub fn from_romaji(s: &str) -> String {
+    // Long vowels are written as the vowel followed by a "-"
+    let chars: Vec<char> = s
+        .to_lowercase()
+        .nfc()
+        .flat_map(|c| match long_vowel(c) {
+            Some(vowel) => vec![vowel, '-'],
+            None => vec![c],
+        })
+        .collect();
+    let mut kana = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        // A "-" after kana lengthens its vowel
+        if c == '-' && kana.chars().last().is_some_and(is_katakana) {
+            kana.push('ー');
+            i += 1;
+            continue;
+        }
+
+        // Doubled consonants, including the "t" of "tch"
+        if is_consonant(c) && c != 'n' && (next == Some(c) || c == 't' && next == Some('c')) {
+            kana.push('ッ');
+            i += 1;
+            continue;
+        }
+
+        // An "n" that doesn't start a syllable
+        if c == 'n' && !next.is_some_and(|next| is_vowel(next) || next == 'y') {
+            kana.push('ン');
+            i += if next == Some('\'') { 2 } else { 1 };
+            continue;
+        }
+
+        // The longest syllable that matches
+        let matched = (1..=3).rev().find_map(|len| {
+            let romaji: String = chars.get(i..i + len)?.iter().collect();
+            Some((len, syllable(&romaji)?))
+        });
+        match matched {
+            Some((len, syllable)) => {
+                kana.push_str(syllable);
+                i += len;
+            }
+            None => {
+                kana.push(c);
+                i += 1;
+            }
+        }
+    }
+    kana
+}
+
+// The katakana of a romanized syllable
+fn syllable(romaji: &str) -> Option<&'static str> {
+    SYLLABLES
+        .iter()
+        .find(|(r, _)| *r == romaji)
+        .map(|(_, kana)| *kana)
+}
+
+fn is_katakana(c: char) -> bool {
+    matches!(c, '\u{30A1}'..='\u{30FA}')
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+fn is_consonant(c: char) -> bool {
+    c.is_ascii_lowercase() && !is_vowel(c)
+}
+
+// The vowel of a vowel with a macron or circumflex, which is written long
+fn long_vowel(c: char) -> Option<char> {
+    match c {
+        'ā' | 'â' => Some('a'),
+        'ī' | 'î' => Some('i'),
+        'ū' | 'û' => Some('u'),
+        'ē' | 'ê' => Some('e'),
+        'ō' | 'ô' => Some('o'),
+        _ => None,
+    }
+}
+
+// Map a hiragana to the katakana with the same sound. Other characters are
+// returned unchanged.
+pub(crate) fn to_katakana(c: char) -> char {
+    match c {
+        // The hiragana block mirrors the katakana block, 0x60 code points lower
+        '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309E}' => {
+            char::from_u32(c as u32 + 0x60).unwrap_or(c)
+        }
+        _ => c,
+    }
+}
+
+// Split a grapheme holding kana into the kana to spell: the plain katakana and
+// the voicing marks, with small kana written as normal ones. Returns `None` if the
+// grapheme holds no kana.
+pub(crate) fn decompose(grapheme: &str) -> Option<String> {
+    let is_kana = |c: char| matches!(c, '\u{3041}'..='\u{30FF}');
+    if !grapheme.chars().any(is_kana) {
+        return None;
+    }
+    let decomposed = grapheme
+        .nfd()
+        .map(|c| match to_katakana(c) {
+            '\u{3099}' => '゛',
+            '\u{309A}' => '゜',
+            c => SMALL_KANA
+                .iter()
+                .find(|(small, _)| *small == c)
+                .map_or(c, |(_, kana)| *kana),
+        })
+        .collect();
+    Some(decomposed)
+}
@@ -4,19 +4,16 @@
 //!
 //! Usage:
 //! ```
-//! use salph::{SpellingAlphabet, Alphabet, Spelling};
+//! use salph::{SpellingAlphabet, Alphabet, Spelling, SpellingKind};
 //! use std::str::FromStr;
 //!
 //! // Load a spelling alphabet using the Alphabet enum
 //! let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
-//! let word_list = spelling_alphabet.str_to_spellings("abc123");
+//! let word_list = spelling_alphabet.str_to_spellings("ab1");
 //! assert_eq!(word_list, [
-//!     Spelling { spelling: "Alpha".to_string(), is_number: false },
-//!     Spelling { spelling: "Bravo".to_string(), is_number: false },
-//!     Spelling { spelling: "Charlie".to_string(), is_number: false },
-//!     Spelling { spelling: "one".to_string(), is_number: true },
-//!     Spelling { spelling: "two".to_string(), is_number: true },
-//!     Spelling { spelling: "three".to_string(), is_number: true },
+//!     Spelling { spelling: "Alpha".to_string(), kind: SpellingKind::Letter, source: "a".to_string(), position: 0, prefix: None, folded: false, alternates: vec!["Alfa".to_string()], pronunciation: Some("AL-fah".to_string()), ipa: Some("ˈælfə".to_string()), alphabet: None },
+//!     Spelling { spelling: "Bravo".to_string(), kind: SpellingKind::Letter, source: "b".to_string(), position: 1, prefix: None, folded: false, alternates: vec![], pronunciation: Some("BRAH-voh".to_string()), ipa: Some("ˈbrɑːvoʊ".to_string()), alphabet: None },
+//!     Spelling { spelling: "one".to_string(), kind: SpellingKind::Digit, source: "1".to_string(), position: 2, prefix: None, folded: false, alternates: vec![], pronunciation: Some("WUN".to_string()), ipa: Some("wʌn".to_string()), alphabet: None },
 //! ]);
 //!
 //! // Load a spelling alphabet using an &str
@@ -32,36 +29,307 @@
 
 include!(concat!(env!("OUT_DIR"), "/alphabet_kinds.rs"));
 
+mod address;
+mod ambiguity;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod braille;
+mod builder;
+mod compare;
+mod coverage;
+mod diff;
+mod digits;
+mod error;
+mod export;
+mod fallback;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fold;
+mod format;
+mod group;
+pub mod hangul;
+mod import;
+mod iter;
+pub mod kana;
+mod lint;
+mod locale;
+mod metadata;
+pub mod morse;
+#[cfg(feature = "unicode-names")]
+pub mod names;
+mod options;
+#[cfg(feature = "parallel")]
+mod parallel;
+pub mod phone;
+mod phrase;
+mod prowords;
+mod registry;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod signals;
+mod speller;
+mod stats;
+#[cfg(feature = "tokio")]
+pub mod stream;
+pub mod tap;
+pub mod telegraph;
+mod trie;
+
+pub use ambiguity::{Ambiguity, AmbiguityReport};
+pub use builder::AlphabetBuilder;
+pub use coverage::CoverageReport;
+pub use diff::AlphabetDiff;
+pub use error::{SalphError, UnspellableError};
+pub use fallback::FallbackSpeller;
+use iter::SpellingIter;
+pub use lint::LintWarning;
+pub use metadata::AlphabetMetadata;
+pub use options::{DigitStyle, Normalization, SpellingOptions, Unmatched};
+pub use prowords::Prowords;
+pub use registry::register;
+pub use speller::Speller;
+pub use stats::{stats, stats_with, SpellStats};
+use trie::Trie;
+
 use core::fmt;
 use indexmap::IndexMap;
-use rust_embed::RustEmbed;
-use std::{cmp::Reverse, str::FromStr};
-use substring::Substring;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fs,
+    ops::RangeInclusive,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, LazyLock, Mutex, OnceLock},
+};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Data of an embedded alphabet. Embedded alphabets are parsed by build.rs, which
+// generates a static `AlphabetData` for each of them.
+struct AlphabetData {
+    name: &'static str,
+    directives: &'static [(&'static str, &'static str)],
+    entries: &'static [(&'static str, &'static str)],
+}
+
+// Map of keys or directive names to words, borrowing from static data for
+// embedded alphabets
+type WordMap = IndexMap<Cow<'static, str>, Cow<'static, str>>;
+
+// Options used by the methods that don't take any
+static DEFAULT_OPTIONS: SpellingOptions = SpellingOptions::new();
 
-#[derive(RustEmbed)]
-#[folder = "alphabets"]
-struct Asset;
+// Embedded alphabets that were loaded through `SpellingAlphabet::cached`
+static CACHE: LazyLock<Mutex<HashMap<Alphabet, Arc<SpellingAlphabet>>>> =
+    LazyLock::new(Default::default);
 
 // Struct representing an alphabet
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "serialize::SerializedAlphabet",
+        into = "serialize::SerializedAlphabet"
+    )
+)]
 pub struct SpellingAlphabet {
-    words: IndexMap<String, String>,
+    words: WordMap,
     max_ngram_len: usize,
+    // Trie over the keys, used for matching
+    trie: Trie,
+    // Reverse index used for decoding, built on first use
+    reverse: OnceLock<ReverseIndex>,
+    // Directives (`# name: value` lines) such as the space and capital words
+    directives: WordMap,
+    // Keys that were defined more than once in a parsed definition, reported by
+    // `lint`
+    duplicates: Vec<String>,
 }
 
-// Error returned when an alphabet can't be found
-#[derive(Debug)]
-pub struct AlphabetNotFoundError {}
+// Reverse index mapping normalized code words to their key
+#[derive(Debug, Clone)]
+struct ReverseIndex {
+    keys: IndexMap<String, String>,
+    // Maximum number of whitespace separated tokens in a single code word
+    max_word_tokens: usize,
+}
 
+/// A single code word together with the part of the input it spells
+/// ```
+/// # #[cfg(feature = "all-alphabets")] {
+/// use salph::{SpellingAlphabet, Alphabet};
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::es).unwrap();
+/// let spellings = spelling_alphabet.str_to_spellings("chico");
+/// assert_eq!((spellings[0].source.as_str(), spellings[0].position), ("ch", 0));
+/// assert_eq!((spellings[1].source.as_str(), spellings[1].position), ("i", 2));
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
 pub struct Spelling {
+    /// The code word
     pub spelling: String,
-    pub is_number: bool,
+    /// What kind of character the code word spells
+    pub kind: SpellingKind,
+    /// The part of the input that was matched (e.g. "a" or "ch")
+    pub source: String,
+    /// Position of the match in the input, counted in grapheme clusters
+    pub position: usize,
+    /// Word announced before the code word (e.g. "capital")
+    pub prefix: Option<String>,
+    /// Whether the source had to be transliterated (e.g. "é" to "e") to find a
+    /// code word
+    pub folded: bool,
+    /// Other code words the alphabet allows for the source (e.g. "Alfa" for "Alpha")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alternates: Vec<String>,
+    /// How the code word is pronounced, with the stressed syllables in uppercase
+    /// (e.g. "AL-fah")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pronunciation: Option<String>,
+    /// IPA transcription of the code word (e.g. "ˈælfə")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ipa: Option<String>,
+    /// Name of the alphabet the code word comes from, set by a
+    /// [`FallbackSpeller`] that spells with multiple alphabets
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alphabet: Option<String>,
+}
+
+impl Spelling {
+    /// Whether the code word spells a number
+    pub fn is_number(&self) -> bool {
+        self.kind == SpellingKind::Digit
+    }
+}
+
+/// A borrowed [`Spelling`]. Code words borrow from the alphabet and sources borrow
+/// from the input where possible, so spelling a large input doesn't allocate per
+/// character. Returned by [`SpellingAlphabet::spellings_ref`].
+/// ```
+/// use salph::{SpellingAlphabet, Alphabet};
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+/// let input = String::from("abc");
+/// let spellings: Vec<_> = spelling_alphabet.spellings_ref(&input).collect();
+/// assert_eq!(spellings[0].spelling, "Alpha");
+/// assert_eq!(spellings[2].clone().into_owned().source, "c");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpellingRef<'a> {
+    /// The code word
+    pub spelling: Cow<'a, str>,
+    /// What kind of character the code word spells
+    pub kind: SpellingKind,
+    /// The part of the input that was matched (e.g. "a" or "ch")
+    pub source: Cow<'a, str>,
+    /// Position of the match in the input, counted in grapheme clusters
+    pub position: usize,
+    /// Word announced before the code word (e.g. "capital")
+    pub prefix: Option<&'a str>,
+    /// Whether the source had to be transliterated (e.g. "é" to "e") to find a
+    /// code word
+    pub folded: bool,
+    /// Other code words the alphabet allows for the source (e.g. "Alfa" for "Alpha")
+    pub alternates: Vec<&'a str>,
+    /// How the code word is pronounced, with the stressed syllables in uppercase
+    /// (e.g. "AL-fah")
+    pub pronunciation: Option<&'a str>,
+    /// IPA transcription of the code word (e.g. "ˈælfə")
+    pub ipa: Option<&'a str>,
+}
+
+impl SpellingRef<'_> {
+    /// Convert into an owned [`Spelling`]
+    pub fn into_owned(self) -> Spelling {
+        Spelling {
+            spelling: self.spelling.into_owned(),
+            kind: self.kind,
+            source: self.source.into_owned(),
+            position: self.position,
+            prefix: self.prefix.map(str::to_string),
+            folded: self.folded,
+            alternates: self.alternates.into_iter().map(str::to_string).collect(),
+            pronunciation: self.pronunciation.map(str::to_string),
+            ipa: self.ipa.map(str::to_string),
+            alphabet: None,
+        }
+    }
+
+    /// Whether the code word spells a number
+    pub fn is_number(&self) -> bool {
+        self.kind == SpellingKind::Digit
+    }
+}
+
+impl From<SpellingRef<'_>> for Spelling {
+    fn from(spelling: SpellingRef<'_>) -> Self {
+        spelling.into_owned()
+    }
+}
+
+impl fmt::Display for SpellingRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.prefix {
+            Some(prefix) => write!(f, "{} {}", prefix, self.spelling),
+            None => write!(f, "{}", self.spelling),
+        }
+    }
+}
+
+/// The kind of character a [`Spelling`] represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[strum(serialize_all = "lowercase")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub enum SpellingKind {
+    Letter,
+    Digit,
+    Punctuation,
+    Whitespace,
+    /// An emoji or other symbol spelled by its Unicode name, see
+    /// [`SpellingOptions::name_symbols`]
+    Symbol,
+    /// A character without a mapping in the alphabet
+    Unknown,
+}
+
+impl SpellingKind {
+    /// Classify a matched part of the input
+    /// ```
+    /// use salph::SpellingKind;
+    ///
+    /// assert_eq!(SpellingKind::classify("ch"), SpellingKind::Letter);
+    /// assert_eq!(SpellingKind::classify("7"), SpellingKind::Digit);
+    /// assert_eq!(SpellingKind::classify("@"), SpellingKind::Punctuation);
+    /// assert_eq!(SpellingKind::classify(" "), SpellingKind::Whitespace);
+    /// ```
+    pub fn classify(source: &str) -> SpellingKind {
+        if source.chars().all(|c| c.is_numeric()) {
+            SpellingKind::Digit
+        } else if source.chars().all(|c| c.is_whitespace()) {
+            SpellingKind::Whitespace
+        } else if source.chars().any(|c| c.is_alphabetic()) {
+            SpellingKind::Letter
+        } else {
+            SpellingKind::Punctuation
+        }
+    }
 }
 
 impl fmt::Display for Spelling {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.spelling)
+        match &self.prefix {
+            Some(prefix) => write!(f, "{} {}", prefix, self.spelling),
+            None => write!(f, "{}", self.spelling),
+        }
     }
 }
 
@@ -75,36 +343,464 @@ impl SpellingAlphabet {
     ///
     /// assert_eq!(spelling_alphabet.is_ok(), true);
     /// ```
-    pub fn load(alphabet: Alphabet) -> Result<SpellingAlphabet, AlphabetNotFoundError> {
-        // Load the alphabet from an embedded asset into a utf8 string
-        let embedded_file = match Asset::get(alphabet.to_string().as_str()) {
-            Some(f) => f,
-            None => {
-                return Err(AlphabetNotFoundError {});
+    pub fn load(alphabet: Alphabet) -> Result<SpellingAlphabet, SalphError> {
+        Ok(SpellingAlphabet::builtin(alphabet))
+    }
+
+    /// Load an embedded alphabet. Embedded alphabets are parsed at compile time and
+    /// their keys and code words are borrowed from static data, so unlike
+    /// [`SpellingAlphabet::load`], this can't fail.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::builtin(Alphabet::nato);
+    /// assert_eq!(spelling_alphabet.str_to_spellings("a")[0].spelling, "Alpha");
+    /// ```
+    pub fn builtin(alphabet: Alphabet) -> SpellingAlphabet {
+        let data = alphabet.data();
+        SpellingAlphabet::from_words(borrow_pairs(data.entries), borrow_pairs(data.directives))
+    }
+
+    /// Load the embedded alphabet that best matches a BCP 47 locale (e.g. "de-AT"),
+    /// falling back to the NATO alphabet. See [`Alphabet::from_locale`] for how the
+    /// alphabet is chosen.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::SpellingAlphabet;
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::from_locale("de-AT");
+    /// assert_eq!(spelling_alphabet.str_to_spellings("a")[0].spelling, "Anton");
+    /// # }
+    /// ```
+    pub fn from_locale(locale: &str) -> SpellingAlphabet {
+        SpellingAlphabet::builtin(Alphabet::from_locale(locale))
+    }
+
+    /// Load an embedded alphabet once and share it. Subsequent calls for the same
+    /// alphabet return the same instance, which avoids rebuilding the alphabet in
+    /// e.g. request handlers.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    /// use std::sync::Arc;
+    ///
+    /// let first = SpellingAlphabet::cached(Alphabet::nato);
+    /// let second = SpellingAlphabet::cached(Alphabet::nato);
+    /// assert!(Arc::ptr_eq(&first, &second));
+    /// assert_eq!(first.str_to_spellings("a")[0].spelling, "Alpha");
+    /// ```
+    pub fn cached(alphabet: Alphabet) -> Arc<SpellingAlphabet> {
+        // A poisoned lock only means another thread panicked while inserting, the
+        // map itself is still consistent
+        let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .entry(alphabet)
+            .or_insert_with_key(|alphabet| Arc::new(SpellingAlphabet::builtin(alphabet.clone())))
+            .clone()
+    }
+
+    /// Parse an alphabet from a string. The format is the same as the one used for
+    /// the embedded alphabets: every line contains a key and a code word, separated by
+    /// a space. Empty lines and lines starting with `#` are ignored, except for
+    /// directives. The following directives are supported:
+    /// - `# space: <word>`: the word used to spell whitespace
+    /// - `# capital: <word>`: the word used to announce uppercase letters
+    /// - `# lowercase: <word>`, `# digit: <word>` and `# symbol: <word>`: the words
+    ///   used to announce the other classes of characters, see
+    ///   [`SpellingOptions::announce_class`]
+    /// - `# connector: <word>`: the words between a letter and its code word in
+    ///   phrases like "A as in Alpha", see [`SpellingAlphabet::as_in`]
+    /// - `# prowords: <intro> | <separator> | <end>`: the radio procedure words to
+    ///   transmit a spelling with, see [`SpellingAlphabet::prowords`]
+    /// - `# name:`, `# language:`, `# region:` and `# source:`: descriptive
+    ///   information, see [`AlphabetMetadata`]
+    /// - `# aliases: <name>, <name>`: other names the alphabet can be loaded by once
+    ///   it's embedded or registered, see [`AlphabetMetadata::aliases`]
+    /// - `# years: <first>-<last>` and `# deprecated: true`: when a historical
+    ///   alphabet was in use, see [`AlphabetMetadata::is_historical`]
+    /// - `# extends: <alphabet>`: an embedded or registered alphabet to start from,
+    ///   so only the entries and directives that differ need to be listed
+    ///
+    /// A code word can have alternates, separated by `|` (e.g. `A Alpha|Alfa`). The
+    /// first one is used unless [`SpellingOptions::variant`] picks another one. A
+    /// pronunciation and an IPA transcription can follow the code word, each after a
+    /// `;` (e.g. `A Alpha ; AL-fah ; ˈælfə`).
+    ///
+    /// Entries after a `[digits]` line spell numbers and need to have numeric keys.
+    /// A `[letters]` line switches back to letters. Digits without an entry are
+    /// spelled in English.
+    ///
+    /// Version 2 of the format, which the embedded alphabets use, is TOML that starts
+    /// with a `[metadata]` table holding the descriptive directives (`name`,
+    /// `language`, `region`, `source`, `aliases`, `years`, `deprecated` and
+    /// `extends`). A `[words]` table holds the other directives, and the `[letters]`,
+    /// `[digits]` and `[punctuation]` tables map keys to code words. Alternates,
+    /// pronunciations and IPA transcriptions go in `[alternates]`, `[pronunciation]`
+    /// and `[ipa]` tables, by the key of their entry. Lists can be used for
+    /// `aliases` and `prowords`.
+    /// ```
+    /// use salph::{SpellingAlphabet, SalphError};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::parse("# Custom\nA Amsterdam\nB Berlin").unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("ab")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Amsterdam", "Berlin"]);
+    ///
+    /// let res = SpellingAlphabet::parse("A Amsterdam\nB");
+    /// assert!(matches!(res, Err(SalphError::ParseError { line: 2, .. })));
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::parse("A Anton\n[digits]\n1 eins").unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("a12")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Anton", "eins", "two"]);
+    ///
+    /// let res = SpellingAlphabet::parse("[digits]\nA Anton");
+    /// assert!(matches!(res, Err(SalphError::ParseError { line: 2, .. })));
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::parse("# extends: nato\nX Xylophone").unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("ax")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Alpha", "Xylophone"]);
+    ///
+    /// let v2 = r#"
+    /// [metadata]
+    /// name = "Custom"
+    /// aliases = ["mine"]
+    ///
+    /// [words]
+    /// connector = "like"
+    ///
+    /// [letters]
+    /// a = "Amsterdam"
+    ///
+    /// [digits]
+    /// 1 = "een"
+    ///
+    /// [punctuation]
+    /// "." = "punt"
+    ///
+    /// [alternates]
+    /// a = ["Antwerpen"]
+    ///
+    /// [pronunciation]
+    /// a = "AM-ster-dam"
+    /// "#;
+    /// let spelling_alphabet = SpellingAlphabet::parse(v2).unwrap();
+    /// assert_eq!(spelling_alphabet.metadata().aliases, ["mine"]);
+    /// assert_eq!(spelling_alphabet.as_in("a"), "A like Amsterdam");
+    /// let spellings = spelling_alphabet.str_to_spellings("a1.");
+    /// assert_eq!(spellings[0].alternates, ["Antwerpen"]);
+    /// assert_eq!(spellings[0].pronunciation.as_deref(), Some("AM-ster-dam"));
+    /// assert_eq!(spellings[2].spelling, "punt");
+    ///
+    /// let res = SpellingAlphabet::parse("[metadata]\n[letters]\na = \"Amsterdam\"\n[ipa]\nb = \"b\"");
+    /// assert!(matches!(res, Err(SalphError::ParseError { line: 5, .. })));
+    /// ```
+    pub fn parse(s: &str) -> Result<SpellingAlphabet, SalphError> {
+        let parsed = format::parse(s).map_err(|(line, reason)| SalphError::parse(line, &reason))?;
+        let extends = parsed.directives.iter().any(|(name, _)| name == "extends");
+        if parsed.entries.is_empty() && !extends {
+            return Err(SalphError::EmptyAlphabet);
+        }
+        let mut seen = HashSet::new();
+        let mut duplicates = Vec::new();
+        for (key, _) in &parsed.entries {
+            if !seen.insert(key) && !duplicates.contains(key) {
+                duplicates.push(key.clone());
+            }
+        }
+        let mut alphabet =
+            SpellingAlphabet::from_words(own_pairs(parsed.entries), own_pairs(parsed.directives))
+                .resolve_extends()?;
+        alphabet.duplicates = duplicates;
+        Ok(alphabet)
+    }
+
+    /// Load an alphabet from a file. Files with a `.json`, `.toml` or `.csv`
+    /// extension are read with [`SpellingAlphabet::from_json`],
+    /// [`SpellingAlphabet::from_toml`] or [`SpellingAlphabet::from_csv`]. See
+    /// [`SpellingAlphabet::parse`] for the format of other files.
+    /// ```
+    /// use salph::{SpellingAlphabet, SalphError};
+    ///
+    /// let res = SpellingAlphabet::from_file("nonexistent");
+    /// assert!(matches!(res, Err(SalphError::Io(_))));
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SpellingAlphabet, SalphError> {
+        let path = path.as_ref();
+        let contents = fs::read(path)?;
+        let contents = String::from_utf8(contents).map_err(|_| SalphError::InvalidUtf8)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => SpellingAlphabet::from_json(&contents),
+            Some("toml") => SpellingAlphabet::from_toml(&contents),
+            Some("csv") => SpellingAlphabet::from_csv(&contents),
+            _ => SpellingAlphabet::parse(&contents),
+        }
+    }
+
+    /// Apply another alphabet on top of this one. Entries and directives of `other`
+    /// replace those of this alphabet, keeping their position, and entries this
+    /// alphabet doesn't have are added at the end.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let overrides = SpellingAlphabet::parse("X Xylophone").unwrap();
+    /// let words = nato
+    ///         .overlay(&overrides)
+    ///         .str_to_spellings("wxy")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Whiskey", "Xylophone", "Yankee"]);
+    /// ```
+    pub fn overlay(&self, other: &SpellingAlphabet) -> SpellingAlphabet {
+        let mut words = self.words.clone();
+        words.extend(other.words.clone());
+        let mut directives = self.directives.clone();
+        directives.extend(other.directives.clone());
+        SpellingAlphabet::from_words(words, directives)
+    }
+
+    // Apply the alphabet on top of the alphabet named by its `extends` directive, if
+    // it has one
+    fn resolve_extends(mut self) -> Result<SpellingAlphabet, SalphError> {
+        match self.directives.shift_remove("extends") {
+            Some(base) => {
+                let mut base = SpellingAlphabet::from_str(&base)?;
+                // Aliases name the base, so they aren't inherited
+                base.directives.shift_remove("aliases");
+                Ok(base.overlay(&self))
             }
+            None => Ok(self),
+        }
+    }
+
+    // Create an alphabet from a map of keys to words. Keys need to be lowercase.
+    fn from_words(words: WordMap, directives: WordMap) -> SpellingAlphabet {
+        // Keys are matched in composed form. Embedded alphabets are normally
+        // composed already, in which case nothing needs to be copied.
+        let words: WordMap = if words.keys().all(|key| is_nfc(key)) {
+            words
+        } else {
+            words
+                .into_iter()
+                .map(|(key, word)| (Cow::Owned(key.nfc().collect()), word))
+                .collect()
         };
-        let alphabet_string = String::from_utf8_lossy(&embedded_file.data).to_string();
-
-        // Split the string, filter out empty lines and turn it into a HashMap<String, String>
-        let words: IndexMap<String, String> = alphabet_string
-            .split('\n')
-            .filter(|x| !x.is_empty() && !x.starts_with('#')) // filter empty lines and comments
-            .map(|x| {
-                let n: Vec<String> = x.splitn(2, ' ').map(|x| x.to_string()).collect();
-                (n[0].to_lowercase(), n[1].clone())
-            })
-            .collect();
 
-        let mut prefixes: Vec<_> = words.keys().collect();
-        prefixes.sort_by_key(|b| Reverse(b.len()));
-        let max_ngram_len = prefixes[0].len();
+        // The longest key, counted in grapheme clusters
+        let max_ngram_len = words
+            .keys()
+            .map(|k| k.graphemes(true).count())
+            .max()
+            .unwrap_or(1);
 
-        Ok(SpellingAlphabet {
+        let trie = Trie::new(words.keys().map(|k| k.as_ref()));
+
+        SpellingAlphabet {
             words,
             max_ngram_len,
+            trie,
+            reverse: OnceLock::new(),
+            directives,
+            duplicates: Vec::new(),
+        }
+    }
+
+    // The reverse index, which is built the first time it's needed
+    fn reverse(&self) -> &ReverseIndex {
+        self.reverse.get_or_init(|| {
+            // If multiple keys map to the same word, the first key wins
+            let mut keys: IndexMap<String, String> = IndexMap::new();
+            for (key, word) in &self.words {
+                for variant in variants(split_entry(word).word) {
+                    keys.entry(normalize_word(variant))
+                        .or_insert_with(|| key.to_string());
+                }
+            }
+            if let Some(word) = self.directives.get("space") {
+                keys.entry(normalize_word(word))
+                    .or_insert_with(|| " ".to_string());
+            }
+            // Digits the alphabet doesn't define are spelled in English
+            for (digit, word) in ('0'..='9').zip(digits::ENGLISH_DIGITS) {
+                let digit = digit.to_string();
+                if !self.words.contains_key(digit.as_str()) {
+                    keys.entry(word.to_string()).or_insert(digit);
+                }
+            }
+            let max_word_tokens = keys.keys().map(|w| w.split(' ').count()).max().unwrap_or(1);
+            ReverseIndex {
+                keys,
+                max_word_tokens,
+            }
         })
     }
 
+    /// Iterate over the `(key, code word)` pairs of the alphabet in the order they were
+    /// defined. Keys are lowercase. For code words with alternates, only the first
+    /// variant is returned.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let mut entries = spelling_alphabet.entries();
+    /// assert_eq!(entries.next(), Some(("a", "Alpha")));
+    /// assert_eq!(entries.next(), Some(("b", "Bravo")));
+    /// ```
+    pub fn entries(&self) -> impl ExactSizeIterator<Item = (&str, &str)> + '_ {
+        self.words.iter().map(|(key, word)| {
+            let word = split_entry(word).word;
+            (key.as_ref(), variants(word).next().unwrap_or(word))
+        })
+    }
+
+    /// The number of entries in the alphabet
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(spelling_alphabet.len(), spelling_alphabet.entries().count());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// The characters the keys of the alphabet consist of, as sorted ranges of
+    /// consecutive characters. Keys are lowercase, so uppercase letters aren't
+    /// included.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let ranges = SpellingAlphabet::load(Alphabet::sv).unwrap().char_ranges();
+    /// // "-", ".", "/", the digits and ":" are consecutive
+    /// assert_eq!(ranges, ['-'..=':', '@'..='@', '_'..='_', 'a'..='z', 'ä'..='å', 'ö'..='ö']);
+    /// # }
+    /// ```
+    pub fn char_ranges(&self) -> Vec<RangeInclusive<char>> {
+        let mut chars = self
+            .words
+            .keys()
+            .flat_map(|key| key.chars())
+            .collect::<Vec<_>>();
+        chars.sort_unstable();
+        chars.dedup();
+
+        let mut ranges: Vec<RangeInclusive<char>> = Vec::new();
+        for c in chars {
+            match ranges.last_mut() {
+                Some(range) if char::from_u32(*range.end() as u32 + 1) == Some(c) => {
+                    *range = *range.start()..=c;
+                }
+                _ => ranges.push(c..=c),
+            }
+        }
+        ranges
+    }
+
+    /// Whether the alphabet has no entries. Alphabets that are loaded, parsed or
+    /// built always have at least one entry.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The word used to spell whitespace when
+    /// [`SpellingOptions::spell_spaces`] is enabled. Falls back to "space" when the
+    /// alphabet doesn't define one.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nl_NL).unwrap();
+    /// assert_eq!(spelling_alphabet.space_word(), "spatie");
+    /// # }
+    /// ```
+    pub fn space_word(&self) -> &str {
+        self.directives.get("space").map_or("space", |w| w)
+    }
+
+    /// The word used to announce uppercase letters when
+    /// [`SpellingOptions::announce_case`] is enabled. Falls back to "capital" when
+    /// the alphabet doesn't define one.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nl_NL).unwrap();
+    /// assert_eq!(spelling_alphabet.capital_word(), "hoofdletter");
+    /// # }
+    /// ```
+    pub fn capital_word(&self) -> &str {
+        self.directives.get("capital").map_or("capital", |w| w)
+    }
+
+    /// The word used to announce lowercase letters when
+    /// [`SpellingOptions::announce_class`] is enabled. Falls back to "lowercase"
+    /// when the alphabet doesn't define one.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// assert_eq!(spelling_alphabet.lowercase_word(), "klein");
+    /// # }
+    /// ```
+    pub fn lowercase_word(&self) -> &str {
+        self.directives.get("lowercase").map_or("lowercase", |w| w)
+    }
+
+    /// The word used to announce digits when [`SpellingOptions::announce_class`]
+    /// is enabled. Falls back to "digit" when the alphabet doesn't define one.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nl_NL).unwrap();
+    /// assert_eq!(spelling_alphabet.digit_word(), "cijfer");
+    /// # }
+    /// ```
+    pub fn digit_word(&self) -> &str {
+        self.directives.get("digit").map_or("digit", |w| w)
+    }
+
+    /// The word used to announce punctuation and other symbols when
+    /// [`SpellingOptions::announce_class`] is enabled. Falls back to "symbol" when
+    /// the alphabet doesn't define one.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(spelling_alphabet.symbol_word(), "symbol");
+    /// ```
+    pub fn symbol_word(&self) -> &str {
+        self.directives.get("symbol").map_or("symbol", |w| w)
+    }
+
+    /// The words between a letter and its code word in phrases created by
+    /// [`SpellingAlphabet::as_in`] (e.g. "wie" in "A wie Anton"). Falls back to
+    /// "as in" when the alphabet doesn't define one.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// assert_eq!(spelling_alphabet.connector_word(), "wie");
+    /// # }
+    /// ```
+    pub fn connector_word(&self) -> &str {
+        self.directives.get("connector").map_or("as in", |w| w)
+    }
+
     /// Validate if there's a mapping for the given alphabet
     /// ```
     /// use salph::SpellingAlphabet;
@@ -112,46 +808,103 @@ impl SpellingAlphabet {
     /// let res = SpellingAlphabet::validate("nato");
     /// assert_eq!(res.is_ok(), true);
     ///
+    /// let res = SpellingAlphabet::validate("aviation");
+    /// assert_eq!(res.is_ok(), true);
+    ///
     /// let res = SpellingAlphabet::validate("nonexistent");
     /// assert_eq!(res.is_err(), true);
     ///
     /// ```
     pub fn validate(s: &str) -> Result<String, String> {
-        match Alphabet::from_str(s) {
-            Ok(_) => Ok(s.to_string()),
-            Err(_) => Err(format!("Unknown alphabet: {}", s)),
+        if Alphabet::from_str(s).is_ok() || registry::get(s).is_some() {
+            Ok(s.to_string())
+        } else {
+            Err(format!("Unknown alphabet: {}", s))
         }
     }
 
     /// List all available alphabets. This function returns a [`Vec`] of tuples
-    /// containing the `(alphabet abbreviation, long name)` (e.g. `("fr-BE", "French (Belgium)")`)
+    /// containing the alphabet abbreviation and its metadata (e.g. `"fr_BE"` and
+    /// the metadata with name "French (Belgium)"). Alphabets added with [`register`]
+    /// are listed after the embedded ones.
     /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
     /// use salph::SpellingAlphabet;
     ///
     /// let alphabets = SpellingAlphabet::list();
-    /// assert!(alphabets.len() > 0);
+    /// let (_, metadata) = alphabets.iter().find(|(name, _)| name == "fr_BE").unwrap();
+    /// assert_eq!(metadata.name.as_deref(), Some("French (Belgium)"));
+    /// # }
     /// ```
-    pub fn list() -> Vec<(String, String)> {
-        let files: Vec<String> = Asset::iter().map(|a| a.to_string()).collect();
-        let mut result: Vec<(String, String)> = files
+    pub fn list() -> Vec<(String, AlphabetMetadata)> {
+        ALPHABETS
             .iter()
-            .map(|x| {
-                let file = Asset::get(x).unwrap();
-                let header = &String::from_utf8_lossy(&file.data)[2..];
-                (
-                    x.to_string(),
-                    header.split('\n').next().unwrap().to_string(),
-                )
+            .map(|data| {
+                let metadata = AlphabetMetadata::from_directives(data.directives.iter().copied());
+                (data.name.to_string(), metadata)
             })
-            .collect();
-        result.sort_by(|(a, _), (b, _)| a.cmp(b));
-        result
+            .chain(registry::list())
+            .collect()
     }
 
-    /// Map a String to a vector of `Spelling`s.
+    /// Search the available alphabets by their abbreviation, name, language or
+    /// language tag, case insensitively. The abbreviation, name and language tag
+    /// match if they contain the query, the language and region only if they are
+    /// equal to it. Alphabets are returned in the order of [`SpellingAlphabet::list`].
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::SpellingAlphabet;
+    ///
+    /// let found = SpellingAlphabet::search("french");
+    /// let names = found.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(names, ["fr_BE", "fr_CA", "fr_CH", "fr_FR"]);
+    ///
+    /// let found = SpellingAlphabet::search("nl-BE");
+    /// assert_eq!(found[0].0, "nl_BE");
+    /// assert!(SpellingAlphabet::search("klingon").is_empty());
+    /// # }
+    /// ```
+    pub fn search(query: &str) -> Vec<(String, AlphabetMetadata)> {
+        let query = query.trim().to_lowercase();
+        let contains = |s: &str| s.to_lowercase().contains(&query);
+        let equals = |s: Option<&str>| s.is_some_and(|s| s.eq_ignore_ascii_case(&query));
+        SpellingAlphabet::list()
+            .into_iter()
+            .filter(|(name, metadata)| {
+                contains(name)
+                    || metadata.name.as_deref().is_some_and(contains)
+                    || metadata.language_tag().as_deref().is_some_and(contains)
+                    || equals(metadata.language.as_deref())
+                    || equals(metadata.region.as_deref())
+                    || metadata.aliases.iter().any(|alias| equals(Some(alias)))
+            })
+            .collect()
+    }
+
+    /// Descriptive information about the alphabet, like its name and language
     /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
     /// use salph::{SpellingAlphabet, Alphabet};
     ///
+    /// let metadata = SpellingAlphabet::load(Alphabet::fr_CA).unwrap().metadata();
+    /// assert_eq!(metadata.name.as_deref(), Some("French (Canada)"));
+    /// assert_eq!(metadata.language.as_deref(), Some("fr"));
+    /// assert_eq!(metadata.region.as_deref(), Some("CA"));
+    /// # }
+    /// ```
+    pub fn metadata(&self) -> AlphabetMetadata {
+        AlphabetMetadata::from_directives(
+            self.directives
+                .iter()
+                .map(|(name, value)| (name.as_ref(), value.as_ref())),
+        )
+    }
+
+    /// Map a String to a vector of `Spelling`s.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingKind};
+    ///
     /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
     /// let words = spelling_alphabet
     ///         .str_to_spellings("Abc98")
@@ -159,96 +912,529 @@ impl SpellingAlphabet {
     ///         .map(|x| x.spelling.clone())
     ///         .collect::<Vec<_>>();
     /// assert_eq!(words, ["Alpha", "Bravo", "Charlie", "nine", "eight"]);
+    ///
+    /// // Symbols are spelled as well
+    /// let spellings = spelling_alphabet.str_to_spellings("a@b.c");
+    /// assert_eq!(spellings[1].spelling, "at");
+    /// assert_eq!(spellings[1].kind, SpellingKind::Punctuation);
+    ///
+    /// // Multi-byte characters are matched as a whole
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("größe")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Gustav", "Richard", "Ökonom", "Eszett", "Emil"]);
+    ///
+    /// // Letters of other scripts are matched regardless of their case as well
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::ru).unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("Щука")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Щука", "Ульяна", "Константин", "Анна"]);
+    ///
+    /// // Multi-character keys are matched before single characters, also when
+    /// // surrounded by multi-byte characters
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::es).unwrap();
+    /// let spellings = spelling_alphabet
+    ///         .str_to_spellings("ñllñch")
+    ///         .iter()
+    ///         .map(|x| (x.source.clone(), x.position))
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(spellings, [
+    ///     ("ñ".to_string(), 0),
+    ///     ("ll".to_string(), 1),
+    ///     ("ñ".to_string(), 3),
+    ///     ("ch".to_string(), 4),
+    /// ]);
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nl_NL).unwrap();
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings("IJsselmeer")
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .take(3)
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["IJmuiden", "Simon", "Simon"]);
+    ///
+    /// // Composed and decomposed characters are treated the same
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::es).unwrap();
+    /// assert_eq!(
+    ///     spelling_alphabet.str_to_spellings("\u{f1}"),
+    ///     spelling_alphabet.str_to_spellings("n\u{303}"),
+    /// );
+    /// # }
     /// ```
     pub fn str_to_spellings(&self, s: &str) -> Vec<Spelling> {
-        // Vector we'll eventually return
+        self.spellings(s).collect()
+    }
+
+    /// Map a String to a vector of `Spelling`s using the given [`SpellingOptions`].
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions, Unmatched, Normalization, SalphError};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().unmatched(Unmatched::Replace("?".to_string()));
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings_with("a!", &options)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Alpha", "?"]);
+    ///
+    /// let options = SpellingOptions::new().unmatched(Unmatched::Error);
+    /// let res = spelling_alphabet.str_to_spellings_with("a!", &options);
+    /// assert!(matches!(res, Err(SalphError::Unmatched { position: 1, .. })));
+    ///
+    /// let options = SpellingOptions::new().spell_spaces(true);
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings_with("a b", &options)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|x| x.spelling.clone())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Alpha", "space", "Bravo"]);
+    ///
+    /// let options = SpellingOptions::new().announce_case(true);
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings_with("aB", &options)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|x| x.to_string())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Alpha", "capital Bravo"]);
+    ///
+    /// let options = SpellingOptions::new().fold_diacritics(true);
+    /// let spellings = spelling_alphabet.str_to_spellings_with("é", &options).unwrap();
+    /// assert_eq!((spellings[0].spelling.as_str(), spellings[0].folded), ("Echo", true));
+    ///
+    /// // Compatibility characters are matched after NFKC normalization
+    /// let options = SpellingOptions::new().normalization(Normalization::Nfkc);
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings_with("ﬁ", &options)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|x| x.to_string())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Foxtrot", "India"]);
+    ///
+    /// // The Greek final sigma is matched as a sigma and accented vowels are folded
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::el).unwrap();
+    /// let options = SpellingOptions::new().fold_diacritics(true);
+    /// let words = spelling_alphabet
+    ///         .str_to_spellings_with("λόγος", &options)
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|x| x.to_string())
+    ///         .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Λάμπρος", "Οδυσσεύς", "Γαλή", "Οδυσσεύς", "Σωτήρ"]);
+    /// # }
+    /// ```
+    pub fn str_to_spellings_with(
+        &self,
+        s: &str,
+        options: &SpellingOptions,
+    ) -> Result<Vec<Spelling>, SalphError> {
+        SpellingIter::new(self, s, options)
+            .map(|spelling| spelling.map(SpellingRef::into_owned))
+            .collect()
+    }
+
+    /// Map a String to a vector of `Spelling`s, failing when any character can't
+    /// be spelled. Unlike [`Unmatched::Error`], every unspellable character is
+    /// reported. Whitespace is skipped as usual and isn't reported.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, UnspellableError};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(spelling_alphabet.try_str_to_spellings("a b").unwrap().len(), 2);
+    ///
+    /// let res = spelling_alphabet.try_str_to_spellings("a€b✓");
+    /// assert_eq!(
+    ///     res,
+    ///     Err(UnspellableError {
+    ///         positions: vec![1, 3],
+    ///         chars: vec!["€".to_string(), "✓".to_string()],
+    ///     })
+    /// );
+    /// ```
+    pub fn try_str_to_spellings(&self, s: &str) -> Result<Vec<Spelling>, UnspellableError> {
+        self.try_str_to_spellings_with(s, &DEFAULT_OPTIONS)
+    }
+
+    /// Map a String to a vector of `Spelling`s using the given [`SpellingOptions`],
+    /// failing when any character can't be spelled. The unmatched policy of the
+    /// options is ignored.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert!(spelling_alphabet.try_str_to_spellings("é").is_err());
+    ///
+    /// let options = SpellingOptions::new().fold_diacritics(true);
+    /// let spellings = spelling_alphabet.try_str_to_spellings_with("é", &options).unwrap();
+    /// assert_eq!(spellings[0].spelling, "Echo");
+    /// ```
+    pub fn try_str_to_spellings_with(
+        &self,
+        s: &str,
+        options: &SpellingOptions,
+    ) -> Result<Vec<Spelling>, UnspellableError> {
+        // Keep unmatched graphemes, so all of them can be reported
+        let options = options.clone().unmatched(Unmatched::Keep);
         let mut spellings = Vec::new();
+        let mut error = UnspellableError {
+            positions: Vec::new(),
+            chars: Vec::new(),
+        };
+        for spelling in SpellingIter::new(self, s, &options).flatten() {
+            if spelling.kind != SpellingKind::Unknown {
+                spellings.push(spelling.into_owned());
+            } else if !spelling.source.chars().all(char::is_whitespace) {
+                error.positions.push(spelling.position);
+                error.chars.push(spelling.source.into_owned());
+            }
+        }
+        if error.positions.is_empty() {
+            Ok(spellings)
+        } else {
+            Err(error)
+        }
+    }
 
-        // The algorithm works as follows (using "foobar" as an input):
-        // - We start by creating an ngram the size of `self.max_ngram_len` ("foo")
-        // - If we don't find a match in our alphabet, we decrease the size of our
-        //   ngram ("fo") and try again
-        // - If we do match, we add the result to our result vector and
-        //   advance the start iterator to the character that wasn't part of the
-        //   match.
-
-        // We loop using an explicit iterator here, since we need to
-        // advance the iterator manually
-        let mut it = 0..s.len();
-
-        // Start iterator
-        while let Some(start) = it.next() {
-            // Iterator counting down from `self.max_ngram_len` to 1, since
-            // a the substring function that is used is excluding the end_index.
-            // We start at `self.max_ngram_len`, since we want the largest match to
-            // happen first (e.g. in Spanish, ll needs to match before l).
-            for j in (1..=self.max_ngram_len).rev() {
-                // Define the end index
-                let end = start + j;
+    /// Lazily map a String to `Spelling`s. Characters without a mapping are skipped.
+    /// This is useful for large inputs, since no intermediate [`Vec`] is allocated.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let mut spellings = spelling_alphabet.spellings("abc");
+    /// assert_eq!(spellings.next().unwrap().spelling, "Alpha");
+    /// assert_eq!(spellings.count(), 2);
+    /// ```
+    pub fn spellings<'a>(&'a self, s: &'a str) -> impl Iterator<Item = Spelling> + 'a {
+        // The default options skip unmatched characters, which never produces errors
+        self.spellings_ref(s).map(SpellingRef::into_owned)
+    }
+
+    /// Lazily map a String to borrowed [`SpellingRef`]s. Characters without a mapping
+    /// are skipped. Unlike [`SpellingAlphabet::spellings`], this doesn't allocate for
+    /// every character.
+    pub fn spellings_ref<'a>(&'a self, s: &'a str) -> impl Iterator<Item = SpellingRef<'a>> + 'a {
+        // The default options skip unmatched characters, which never produces errors
+        SpellingIter::new(self, s, &DEFAULT_OPTIONS).filter_map(Result::ok)
+    }
+
+    /// Lazily map a String to `Spelling`s using the given [`SpellingOptions`]. See
+    /// [`SpellingAlphabet::spellings`].
+    pub fn spellings_with<'a>(
+        &'a self,
+        s: &'a str,
+        options: &'a SpellingOptions,
+    ) -> impl Iterator<Item = Result<Spelling, SalphError>> + 'a {
+        self.spellings_ref_with(s, options)
+            .map(|spelling| spelling.map(SpellingRef::into_owned))
+    }
+
+    /// Lazily map a String to borrowed [`SpellingRef`]s using the given
+    /// [`SpellingOptions`]. See [`SpellingAlphabet::spellings_ref`].
+    pub fn spellings_ref_with<'a>(
+        &'a self,
+        s: &'a str,
+        options: &'a SpellingOptions,
+    ) -> impl Iterator<Item = Result<SpellingRef<'a>, SalphError>> + 'a {
+        SpellingIter::new(self, s, options)
+    }
+
+    /// Spell a single character. Characters are matched case insensitively, the same
+    /// way as [`SpellingAlphabet::str_to_spellings`] does.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(spelling_alphabet.spell_char('B').unwrap().spelling, "Bravo");
+    /// assert_eq!(spelling_alphabet.spell_char('€'), None);
+    /// ```
+    pub fn spell_char(&self, c: char) -> Option<Spelling> {
+        let mut buf = [0; 4];
+        let spelling = self.spellings_ref(c.encode_utf8(&mut buf)).next();
+        spelling.map(SpellingRef::into_owned)
+    }
+
+    /// Whether the alphabet has a code word for a character. See
+    /// [`SpellingAlphabet::spell_char`].
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// assert!(spelling_alphabet.contains('Ä'));
+    /// assert!(!spelling_alphabet.contains('€'));
+    /// # }
+    /// ```
+    pub fn contains(&self, c: char) -> bool {
+        let mut buf = [0; 4];
+        let spelling = self.spellings_ref(c.encode_utf8(&mut buf)).next();
+        spelling.is_some()
+    }
+
+    /// Check which characters of a String the alphabet can spell, which need their
+    /// diacritics folded and which can't be spelled at all.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let report = spelling_alphabet.coverage("Zoë €5");
+    /// assert_eq!(report.covered, ["Z", "o", "5"]);
+    /// assert_eq!(report.needs_folding, ["ë"]);
+    /// assert_eq!(report.unmappable, ["€"]);
+    /// ```
+    pub fn coverage(&self, s: &str) -> CoverageReport {
+        let options = SpellingOptions::new()
+            .fold_diacritics(true)
+            .unmatched(Unmatched::Keep);
+        let mut report = CoverageReport::default();
+        for spelling in SpellingIter::new(self, s, &options).flatten() {
+            let category = if spelling.folded {
+                &mut report.needs_folding
+            } else if spelling.kind != SpellingKind::Unknown {
+                &mut report.covered
+            } else if !spelling.source.chars().all(char::is_whitespace) {
+                &mut report.unmappable
+            } else {
+                continue;
+            };
+            CoverageReport::add(category, &spelling.source);
+        }
+        report
+    }
+
+    /// Map a sentence of code words back to the text it spells. Code words are
+    /// matched case insensitively and may consist of multiple words (e.g. "dvojité V"
+    /// in Czech). Words that aren't part of the alphabet are skipped, like the
+    /// words announced by [`SpellingOptions::announce_class`]. A code word preceded
+    /// by the alphabet's capital word is decoded as an uppercase letter.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let text = spelling_alphabet.spellings_to_str("Alpha bravo CHARLIE one");
+    /// assert_eq!(text, "abc1");
+    ///
+    /// let text = spelling_alphabet.spellings_to_str("capital Alpha bravo");
+    /// assert_eq!(text, "Ab");
+    ///
+    /// let text = spelling_alphabet.spellings_to_str("lowercase Echo digit one symbol dash");
+    /// assert_eq!(text, "e1-");
+    /// ```
+    pub fn spellings_to_str(&self, s: &str) -> String {
+        self.words_to_str(&s.split_whitespace().collect::<Vec<_>>())
+    }
+
+    /// Map a slice of code words back to the text it spells. See
+    /// [`SpellingAlphabet::spellings_to_str`].
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::cz).unwrap();
+    /// let text = spelling_alphabet.words_to_str(&["Adam", "dvojité", "V"]);
+    /// assert_eq!(text, "aw");
+    /// # }
+    /// ```
+    pub fn words_to_str(&self, words: &[&str]) -> String {
+        let mut result = String::new();
 
-                // Make sure we don't go past the end of the string
-                if end <= s.len() {
-                    // Create an ngram
-                    let ngram = s.substring(start, end).to_string().to_lowercase();
-
-                    // If we have a match, we add it to our result vector and
-                    // advance the start iterator.
-                    // Extra advancement is only necessary if the ngram was larger than
-                    // one character. We take consume the nth element from the iterator
-                    // where n is the length of the ngram - 2. The number comes from the
-                    // fact that it.nth(0) is the next element and the element we want to
-                    // make sure is consumed is the length - 1.
-                    // E.g. if the ngram was of length 2, we've already consumed the first
-                    // at the start of the iterator and we would only need to consume the next one,
-                    // which is it.nth(0). If the ngram was of length 3, we again, already
-                    // consumed the first element and we need to the next two one (0 and 1),
-                    // hence nth(1) or nth(3-2)
-                    if let Some(word) = self.words.get(&ngram) {
-                        spellings.push(Spelling {
-                            spelling: word.clone(),
-                            is_number: ngram.parse::<i32>().is_ok(),
-                        });
-                        if ngram.len() > 1 {
-                            it.nth(ngram.len() - 2);
-                            // And we break the inner loop, because we need to reset the end
-                            break;
-                        }
-                    };
+        // Similar to `str_to_spellings`, we try to match the largest number of
+        // tokens first, since a code word can consist of multiple words.
+        let reverse = self.reverse();
+        let capital = normalize_word(self.capital_word());
+        let mut uppercase = false;
+
+        let mut start = 0;
+        while start < words.len() {
+            // The capital word applies to the next decoded code word
+            if normalize_word(words[start]) == capital {
+                uppercase = true;
+                start += 1;
+                continue;
+            }
+
+            let mut matched = 1;
+            for j in (1..=reverse.max_word_tokens).rev() {
+                let end = start + j;
+                if end > words.len() {
+                    continue;
+                }
+                let candidate = normalize_word(&words[start..end].join(" "));
+                if let Some(key) = reverse.keys.get(&candidate) {
+                    if uppercase {
+                        result.push_str(&key.to_uppercase());
+                        uppercase = false;
+                    } else {
+                        result.push_str(key);
+                    }
+                    matched = j;
+                    break;
                 }
             }
+            start += matched;
+        }
+        result
+    }
+}
+
+// Turn static `(key, value)` pairs into a map that borrows them
+fn borrow_pairs(pairs: &'static [(&'static str, &'static str)]) -> WordMap {
+    pairs
+        .iter()
+        .map(|(key, value)| (Cow::Borrowed(*key), Cow::Borrowed(*value)))
+        .collect()
+}
+
+// Turn owned `(key, value)` pairs into a map
+fn own_pairs(pairs: Vec<(String, String)>) -> WordMap {
+    pairs
+        .into_iter()
+        .map(|(key, value)| (Cow::Owned(key), Cow::Owned(value)))
+        .collect()
+}
+
+/// The variants of a code word with alternates (e.g. "Alpha|Alfa")
+fn variants(word: &str) -> impl Iterator<Item = &str> {
+    word.split('|').map(str::trim).filter(|w| !w.is_empty())
+}
+
+/// The columns of the text of an entry: the code word (with its alternates), the
+/// pronunciation and the IPA transcription (e.g. "Alpha|Alfa ; AL-fah ; ˈælfə")
+struct EntryText<'a> {
+    word: &'a str,
+    pronunciation: Option<&'a str>,
+    ipa: Option<&'a str>,
+}
+
+fn split_entry(text: &str) -> EntryText<'_> {
+    if !text.contains(';') {
+        return EntryText {
+            word: text,
+            pronunciation: None,
+            ipa: None,
+        };
+    }
+    let mut columns = text.split(';').map(str::trim);
+    let mut column = || columns.next().filter(|c| !c.is_empty());
+    EntryText {
+        word: column().unwrap_or_default(),
+        pronunciation: column(),
+        ipa: column(),
+    }
+}
+
+/// A code word picked from the text of an entry
+pub(crate) struct CodeWord<'a> {
+    pub spelling: &'a str,
+    pub alternates: Vec<&'a str>,
+    pub pronunciation: Option<&'a str>,
+    pub ipa: Option<&'a str>,
+}
+
+/// Pick a variant of a code word, falling back to the first one if there are
+/// fewer variants
+pub(crate) fn code_word(text: &str, variant: usize) -> CodeWord<'_> {
+    let entry = split_entry(text);
+    let mut code_word = CodeWord {
+        spelling: entry.word,
+        alternates: Vec::new(),
+        pronunciation: entry.pronunciation,
+        ipa: entry.ipa,
+    };
+    if entry.word.contains('|') {
+        let mut variants: Vec<&str> = variants(entry.word).collect();
+        if !variants.is_empty() {
+            let chosen = if variant < variants.len() { variant } else { 0 };
+            code_word.spelling = variants.remove(chosen);
+            code_word.alternates = variants;
         }
-        spellings
     }
+    code_word
+}
+
+/// Normalize a code word for lookups in the reverse index
+fn normalize_word(word: &str) -> String {
+    word.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
 }
 
+/// Lists every key with its code word, one per line. Alternates, pronunciations and
+/// IPA transcriptions are left out.
+/// ```
+/// use salph::{SpellingAlphabet, Alphabet};
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+/// assert!(spelling_alphabet.to_string().starts_with("A Alpha\nB Bravo\n"));
+/// ```
 impl std::fmt::Display for SpellingAlphabet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
-            self.words
-                .iter()
-                .map(|s| format!("{} {}", s.0.clone().to_uppercase(), s.1))
+            self.entries()
+                .map(|(key, word)| format!("{} {}", key.to_uppercase(), word))
                 .collect::<Vec<_>>()
                 .join("\n")
         )
     }
 }
 
-/// Load a spelling alphabet from a string
+/// Load a spelling alphabet from a string, which is the name or an alias of an
+/// alphabet
 /// ```
 /// use salph::SpellingAlphabet;
 /// use std::str::FromStr;
 ///
 /// let spelling_alphabet = SpellingAlphabet::from_str("nato");
-///
 /// assert_eq!(spelling_alphabet.is_ok(), true);
+///
+/// let spelling_alphabet = SpellingAlphabet::from_str("icao").unwrap();
+/// assert_eq!(spelling_alphabet.metadata().name.as_deref(), Some("NATO"));
+///
+/// let spelling_alphabet = SpellingAlphabet::from_str("nonexistent");
+/// assert_eq!(spelling_alphabet.is_err(), true);
 /// ```
 impl std::str::FromStr for SpellingAlphabet {
-    type Err = AlphabetNotFoundError;
+    type Err = SalphError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let n = Alphabet::from_str(s).unwrap();
-        SpellingAlphabet::load(n)
+        if let Ok(alphabet) = Alphabet::from_str(s) {
+            return SpellingAlphabet::load(alphabet);
+        }
+        registry::get(s)
+            .map(|alphabet| alphabet.as_ref().clone())
+            .ok_or_else(|| SalphError::NotFound(s.to_string()))
+    }
+}
+
+/// Load a spelling alphabet from a string
+/// ```
+/// use salph::{SpellingAlphabet, SalphError};
+///
+/// let spelling_alphabet = SpellingAlphabet::try_from("nato");
+/// assert_eq!(spelling_alphabet.is_ok(), true);
+///
+/// let spelling_alphabet = SpellingAlphabet::try_from("nonexistent");
+/// assert!(matches!(spelling_alphabet, Err(SalphError::NotFound(_))));
+/// ```
+impl TryFrom<&str> for SpellingAlphabet {
+    type Error = SalphError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        SpellingAlphabet::from_str(s)
     }
 }
@@ -0,0 +1,110 @@
+use crate::{normalize_word, split_entry, variants, SpellingAlphabet};
+use indexmap::IndexMap;
+use std::fmt;
+
+/// A potential problem in an alphabet definition, as reported by
+/// [`SpellingAlphabet::lint`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LintWarning {
+    /// The key is defined more than once. Only the last definition is used.
+    DuplicateKey(String),
+    /// A letter from a to z or a digit has no code word. Letters are only checked
+    /// for alphabets of the Latin script.
+    MissingKey(char),
+    /// The code word is used for multiple keys, so decoding it is ambiguous. The
+    /// first key is the one it decodes to.
+    AmbiguousWord { word: String, keys: Vec<String> },
+    /// The key or its code word contains whitespace other than single spaces (e.g.
+    /// tabs or non-breaking spaces)
+    SuspiciousWhitespace(String),
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintWarning::DuplicateKey(key) => write!(f, "Key '{}' is defined more than once", key),
+            LintWarning::MissingKey(c) => write!(f, "Key '{}' has no code word", c),
+            LintWarning::AmbiguousWord { word, keys } => write!(
+                f,
+                "Code word '{}' is used for keys {}",
+                word,
+                keys.iter()
+                    .map(|key| format!("'{}'", key))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            LintWarning::SuspiciousWhitespace(key) => {
+                write!(f, "Entry '{}' contains suspicious whitespace", key)
+            }
+        }
+    }
+}
+
+impl SpellingAlphabet {
+    /// Check the alphabet for likely mistakes, like duplicate keys, letters or
+    /// digits without a code word, code words that are used for multiple keys and
+    /// whitespace that isn't a single space. Duplicate keys can only be detected
+    /// for alphabets created with [`SpellingAlphabet::parse`] or
+    /// [`SpellingAlphabet::from_file`].
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet, LintWarning};
+    ///
+    /// assert!(SpellingAlphabet::load(Alphabet::nato).unwrap().lint().is_empty());
+    /// assert!(SpellingAlphabet::load(Alphabet::ru).unwrap().lint().is_empty());
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::parse("a Alpha\nb Bravo\na Alfa").unwrap();
+    /// let warnings = spelling_alphabet.lint();
+    /// assert_eq!(warnings[0], LintWarning::DuplicateKey("a".to_string()));
+    /// assert_eq!(warnings[1], LintWarning::MissingKey('c'));
+    /// # }
+    /// ```
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings: Vec<LintWarning> = self
+            .duplicates
+            .iter()
+            .map(|key| LintWarning::DuplicateKey(key.clone()))
+            .collect();
+
+        // Alphabets of other scripts, like Cyrillic, don't need to spell a to z
+        let latin = self
+            .words
+            .keys()
+            .any(|key| key.chars().any(|c| c.is_ascii_alphabetic()));
+        warnings.extend(
+            ('a'..='z')
+                .chain('0'..='9')
+                .filter(|c| latin || c.is_ascii_digit())
+                .filter(|c| !self.words.contains_key(c.to_string().as_str()))
+                .map(LintWarning::MissingKey),
+        );
+
+        let mut keys: IndexMap<String, Vec<String>> = IndexMap::new();
+        for (key, word) in &self.words {
+            for variant in variants(split_entry(word).word) {
+                let keys = keys.entry(normalize_word(variant)).or_default();
+                if !keys.contains(&key.to_string()) {
+                    keys.push(key.to_string());
+                }
+            }
+        }
+        warnings.extend(
+            keys.into_iter()
+                .filter(|(_, keys)| keys.len() > 1)
+                .map(|(word, keys)| LintWarning::AmbiguousWord { word, keys }),
+        );
+
+        warnings.extend(
+            self.words
+                .iter()
+                .filter(|(key, word)| {
+                    key.chars().any(char::is_whitespace)
+                        || word.contains("  ")
+                        || word.chars().any(|c| c.is_whitespace() && c != ' ')
+                })
+                .map(|(key, _)| LintWarning::SuspiciousWhitespace(key.to_string())),
+        );
+        warnings
+    }
+}
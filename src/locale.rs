@@ -0,0 +1,98 @@
+use crate::{Alphabet, AlphabetMetadata, ALPHABETS};
+use std::str::FromStr;
+
+// Languages that are covered by an alphabet under a different code
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[("nb", "no"), ("nn", "no")];
+
+impl Alphabet {
+    /// Find the embedded alphabet that best matches a BCP 47 locale (e.g. "de-AT").
+    /// POSIX locales like "nl_BE.UTF-8" are accepted as well. The first rule that
+    /// matches decides:
+    /// 1. The only alphabet for the language and the region (e.g. "fr-CA" → fr_CA).
+    ///    Regions with several alphabets (e.g. "en-US") skip this rule.
+    /// 2. An alphabet for the language that isn't specific to a region
+    ///    (e.g. "de-AT" → de)
+    /// 3. The alphabet for the region named after the language (e.g. "fr-LU" → fr_FR)
+    /// 4. The only alphabet for the language (e.g. "sv-FI" → sv)
+    /// 5. The NATO alphabet
+    ///
+    /// Historical alphabets (e.g. able_baker) are never picked.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::from_locale("fr-CA"), Alphabet::fr_CA);
+    /// assert_eq!(Alphabet::from_locale("de-AT"), Alphabet::de);
+    /// assert_eq!(Alphabet::from_locale("nl"), Alphabet::nl_NL);
+    /// assert_eq!(Alphabet::from_locale("nl_BE.UTF-8"), Alphabet::nl_BE);
+    /// assert_eq!(Alphabet::from_locale("uk-UA"), Alphabet::uk);
+    /// assert_eq!(Alphabet::from_locale("ja-JP"), Alphabet::ja);
+    /// assert_eq!(Alphabet::from_locale("hi-IN"), Alphabet::nato);
+    /// assert_eq!(Alphabet::from_locale("en-GB"), Alphabet::en_GB);
+    /// assert_eq!(Alphabet::from_locale("en-US"), Alphabet::nato);
+    /// assert_eq!(Alphabet::from_locale("en-AU"), Alphabet::nato);
+    /// assert_eq!(Alphabet::from_locale("en"), Alphabet::nato);
+    /// # }
+    /// ```
+    pub fn from_locale(locale: &str) -> Alphabet {
+        let (language, region) = parse_locale(locale);
+        let language = LANGUAGE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == language)
+            .map_or(language.as_str(), |(_, language)| language);
+
+        let candidates: Vec<(&str, AlphabetMetadata)> = ALPHABETS
+            .iter()
+            .map(|data| {
+                let metadata = AlphabetMetadata::from_directives(data.directives.iter().copied());
+                (data.name, metadata)
+            })
+            .filter(|(_, metadata)| {
+                metadata.language.as_deref() == Some(language) && !metadata.is_historical()
+            })
+            .collect();
+        let with_region = |region: Option<&str>| {
+            candidates
+                .iter()
+                .find(|(_, metadata)| metadata.region.as_deref() == region)
+        };
+
+        let only_with_region = |region: &str| {
+            let mut matches = candidates
+                .iter()
+                .filter(|(_, metadata)| metadata.region.as_deref() == Some(region));
+            match (matches.next(), matches.next()) {
+                (Some(only), None) => Some(only),
+                _ => None,
+            }
+        };
+
+        let best = region
+            .as_deref()
+            .and_then(only_with_region)
+            .or_else(|| with_region(None))
+            .or_else(|| with_region(Some(&language.to_uppercase())))
+            .or(match candidates.as_slice() {
+                [only] => Some(only),
+                _ => None,
+            });
+        best.and_then(|(name, _)| Alphabet::from_str(name).ok())
+            .unwrap_or(Alphabet::nato)
+    }
+}
+
+// Split a locale into its lowercase language and uppercase region. Script subtags
+// (e.g. "Latn" in "sr-Latn-RS") as well as the encoding and modifier of POSIX
+// locales (e.g. ".UTF-8" in "de_DE.UTF-8") are ignored.
+fn parse_locale(locale: &str) -> (String, Option<String>) {
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_lowercase();
+    let region = subtags
+        .find(|subtag| {
+            subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+                || subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit())
+        })
+        .map(str::to_uppercase);
+    (language, region)
+}
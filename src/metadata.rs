@@ -0,0 +1,159 @@
+/// Descriptive information about an alphabet, taken from the `name`, `language`,
+/// `region`, `source`, `aliases`, `years` and `deprecated` directives of its
+/// definition:
+/// ```text
+/// # name: German (Austria)
+/// # language: de
+/// # region: AT
+/// # source: ÖNORM A 1081
+/// # aliases: austrian
+/// ```
+/// For definitions without a `name` directive, a plain comment on the first line
+/// is used as the name. Historical alphabets declare the years they were in use
+/// and that they are no longer current:
+/// ```text
+/// # years: 1941-1956
+/// # deprecated: true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[non_exhaustive]
+pub struct AlphabetMetadata {
+    /// Human readable name (e.g. "Dutch (Belgium)")
+    pub name: Option<String>,
+    /// ISO 639 language code (e.g. "nl")
+    pub language: Option<String>,
+    /// ISO 3166 region code (e.g. "BE")
+    pub region: Option<String>,
+    /// Where the code words come from (e.g. a standard or an organisation)
+    pub source: Option<String>,
+    /// Other names the alphabet can be loaded by (e.g. "icao" for NATO)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub aliases: Vec<String>,
+    /// The year the alphabet came into use
+    pub since: Option<u16>,
+    /// The year the alphabet went out of use
+    pub until: Option<u16>,
+    /// Whether the alphabet is historical and no longer in use
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deprecated: bool,
+}
+
+impl AlphabetMetadata {
+    // Collect the metadata from the directives of an alphabet
+    pub(crate) fn from_directives<'a>(
+        directives: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> AlphabetMetadata {
+        let mut metadata = AlphabetMetadata::default();
+        for (name, value) in directives {
+            let field = match name {
+                "aliases" => {
+                    metadata.aliases = parse_aliases(value);
+                    continue;
+                }
+                "years" => {
+                    (metadata.since, metadata.until) = parse_years(value);
+                    continue;
+                }
+                "deprecated" => {
+                    metadata.deprecated = value == "true";
+                    continue;
+                }
+                "name" => &mut metadata.name,
+                "language" => &mut metadata.language,
+                "region" => &mut metadata.region,
+                "source" => &mut metadata.source,
+                _ => continue,
+            };
+            *field = Some(value.to_string());
+        }
+        metadata
+    }
+
+    // The `(directive, value)` pairs of the fields that are set
+    pub(crate) fn directives(&self) -> Vec<(&'static str, String)> {
+        let mut directives = [
+            ("name", &self.name),
+            ("language", &self.language),
+            ("region", &self.region),
+            ("source", &self.source),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value.clone()?)))
+        .collect::<Vec<_>>();
+        if !self.aliases.is_empty() {
+            directives.push(("aliases", self.aliases.join(", ")));
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let year = |year: Option<u16>| year.map(|y| y.to_string()).unwrap_or_default();
+            directives.push((
+                "years",
+                format!("{}-{}", year(self.since), year(self.until)),
+            ));
+        }
+        if self.deprecated {
+            directives.push(("deprecated", "true".to_string()));
+        }
+        directives
+    }
+
+    /// Whether the alphabet is historical, which it is when it's deprecated
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let metadata = SpellingAlphabet::load(Alphabet::able_baker).unwrap().metadata();
+    /// assert!(metadata.is_historical());
+    /// assert_eq!(metadata.since, Some(1941));
+    /// assert_eq!(metadata.until, Some(1956));
+    ///
+    /// let metadata = SpellingAlphabet::load(Alphabet::nato).unwrap().metadata();
+    /// assert!(!metadata.is_historical());
+    /// # }
+    /// ```
+    pub fn is_historical(&self) -> bool {
+        self.deprecated
+    }
+
+    /// The BCP 47 language tag (e.g. "nl-BE") consisting of the language and the
+    /// region, if the language is known
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let metadata = SpellingAlphabet::load(Alphabet::nl_BE).unwrap().metadata();
+    /// assert_eq!(metadata.language_tag().as_deref(), Some("nl-BE"));
+    ///
+    /// let metadata = SpellingAlphabet::load(Alphabet::nato).unwrap().metadata();
+    /// assert_eq!(metadata.language_tag(), None);
+    /// # }
+    /// ```
+    pub fn language_tag(&self) -> Option<String> {
+        let language = self.language.as_deref()?;
+        Some(match &self.region {
+            Some(region) => format!("{}-{}", language, region),
+            None => language.to_string(),
+        })
+    }
+}
+
+// Split the value of an `aliases` directive into the aliases it lists
+fn parse_aliases(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// Split the value of a `years` directive (e.g. "1941-1956", "1912-" or "1941")
+// into the first and last year. Years that aren't numbers are left out.
+fn parse_years(value: &str) -> (Option<u16>, Option<u16>) {
+    let year = |s: &str| s.trim().parse().ok();
+    match value.split_once('-') {
+        Some((since, until)) => (year(since), year(until)),
+        None => (year(value), None),
+    }
+}
@@ -0,0 +1,106 @@
+//! International Morse code (ITU-R M.1677-1).
+//!
+//! Letters are separated by a space and words by `" / "`. Characters without a
+//! Morse code are skipped.
+//! ```
+//! use salph::morse;
+//!
+//! assert_eq!(morse::encode("SOS"), "... --- ...");
+//! assert_eq!(morse::encode("73 de PA3"), "--... ...-- / -.. . / .--. .- ...--");
+//! ```
+//!
+//! Spelled code words can be encoded as well:
+//! ```
+//! use salph::{morse, SpellingAlphabet, Alphabet};
+//!
+//! let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+//! let spellings = spelling_alphabet.str_to_spellings("e");
+//! assert_eq!(morse::encode(&spellings[0].spelling), ". -.-. .... ---");
+//! ```
+
+// Morse codes of all supported characters
+const CODES: &[(char, &str)] = &[
+    ('a', ".-"),
+    ('b', "-..."),
+    ('c', "-.-."),
+    ('d', "-.."),
+    ('e', "."),
+    ('é', "..-.."),
+    ('f', "..-."),
+    ('g', "--."),
+    ('h', "...."),
+    ('i', ".."),
+    ('j', ".---"),
+    ('k', "-.-"),
+    ('l', ".-.."),
+    ('m', "--"),
+    ('n', "-."),
+    ('o', "---"),
+    ('p', ".--."),
+    ('q', "--.-"),
+    ('r', ".-."),
+    ('s', "..."),
+    ('t', "-"),
+    ('u', "..-"),
+    ('v', "...-"),
+    ('w', ".--"),
+    ('x', "-..-"),
+    ('y', "-.--"),
+    ('z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    (':', "---..."),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('-', "-....-"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('"', ".-..-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('@', ".--.-."),
+];
+
+/// The Morse code for a character. Letters are matched case insensitively.
+/// ```
+/// use salph::morse;
+///
+/// assert_eq!(morse::encode_char('Q'), Some("--.-"));
+/// assert_eq!(morse::encode_char('€'), None);
+/// ```
+pub fn encode_char(c: char) -> Option<&'static str> {
+    let mut lowercase = c.to_lowercase();
+    let c = match (lowercase.next(), lowercase.next()) {
+        (Some(lower), None) => lower,
+        _ => c,
+    };
+    CODES
+        .iter()
+        .find(|(character, _)| *character == c)
+        .map(|(_, code)| *code)
+}
+
+/// Encode a string as Morse code. Words are split on whitespace.
+pub fn encode(s: &str) -> String {
+    s.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(encode_char)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
@@ -0,0 +1,2926 @@
+//! Unicode names of emoji and other symbols, for spelling characters that have no
+//! code word in any alphabet (e.g. "😀" as "grinning face"). Only available with the
+//! `unicode-names` feature.
+//!
+//! Names are taken from the Unicode Character Database (Unicode 14.0) and cover
+//! symbols and punctuation outside of ASCII: Latin-1 symbols, general punctuation,
+//! currency symbols, arrows, technical symbols, geometric shapes, dingbats and the
+//! emoji blocks.
+//! ```
+//! use salph::{SpellingAlphabet, Alphabet, SpellingOptions, SpellingKind};
+//!
+//! let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+//! let options = SpellingOptions::new().name_symbols(true);
+//! let spellings = spelling_alphabet.str_to_spellings_with("hi😀", &options).unwrap();
+//! assert_eq!(spellings[2].spelling, "grinning face");
+//! assert_eq!(spellings[2].kind, SpellingKind::Symbol);
+//! ```
+
+/// The Unicode name of a symbol, in lowercase. Returns `None` for letters, digits,
+/// ASCII and other characters that aren't in the table.
+/// ```
+/// use salph::names;
+///
+/// assert_eq!(names::name('€'), Some("euro sign"));
+/// assert_eq!(names::name('🚀'), Some("rocket"));
+/// assert_eq!(names::name('a'), None);
+/// ```
+pub fn name(c: char) -> Option<&'static str> {
+    NAMES
+        .binary_search_by_key(&c, |(symbol, _)| *symbol)
+        .ok()
+        .map(|index| NAMES[index].1)
+}
+
+/// The name of a grapheme made of symbols, like an emoji with a skin tone or a
+/// sequence of emoji joined into one. The names of its symbols are joined by
+/// `", "`, leaving out joiners and variation selectors. A flag is named by its
+/// region code. Returns `None` if any of the symbols has no name.
+/// ```
+/// use salph::names;
+///
+/// assert_eq!(names::name_grapheme("👍🏽").as_deref(), Some("thumbs up sign, emoji modifier fitzpatrick type-4"));
+/// assert_eq!(names::name_grapheme("❤️").as_deref(), Some("heavy black heart"));
+/// assert_eq!(names::name_grapheme("🇳🇱").as_deref(), Some("flag NL"));
+/// assert_eq!(names::name_grapheme("é"), None);
+/// ```
+pub fn name_grapheme(grapheme: &str) -> Option<String> {
+    // Zero width joiner and variation selectors
+    let ignored = |c: &char| matches!(c, '\u{200D}' | '\u{FE0E}' | '\u{FE0F}');
+    let symbols: Vec<char> = grapheme.chars().filter(|c| !ignored(c)).collect();
+    if symbols.is_empty() {
+        return None;
+    }
+    if symbols.iter().all(|c| regional_indicator(*c).is_some()) {
+        let region: String = symbols
+            .iter()
+            .filter_map(|c| regional_indicator(*c))
+            .collect();
+        return Some(format!("flag {}", region));
+    }
+    let names = symbols.into_iter().map(name).collect::<Option<Vec<_>>>()?;
+    Some(names.join(", "))
+}
+
+// The letter a regional indicator symbol stands for
+fn regional_indicator(c: char) -> Option<char> {
+    let offset = (c as u32)
+        .checked_sub(0x1F1E6)
+        .filter(|offset| *offset < 26)?;
+    char::from_u32('A' as u32 + offset)
+}
+
+// Symbols and their names, sorted by code point
+const NAMES: &[(char, &str)] = &[
+    ('\u{A1}', "inverted exclamation mark"),
+    ('\u{A2}', "cent sign"),
+    ('\u{A3}', "pound sign"),
+    ('\u{A4}', "currency sign"),
+    ('\u{A5}', "yen sign"),
+    ('\u{A6}', "broken bar"),
+    ('\u{A7}', "section sign"),
+    ('\u{A8}', "diaeresis"),
+    ('\u{A9}', "copyright sign"),
+    ('\u{AB}', "left-pointing double angle quotation mark"),
+    ('\u{AC}', "not sign"),
+    ('\u{AE}', "registered sign"),
+    ('\u{AF}', "macron"),
+    ('\u{B0}', "degree sign"),
+    ('\u{B1}', "plus-minus sign"),
+    ('\u{B4}', "acute accent"),
+    ('\u{B6}', "pilcrow sign"),
+    ('\u{B7}', "middle dot"),
+    ('\u{B8}', "cedilla"),
+    ('\u{BB}', "right-pointing double angle quotation mark"),
+    ('\u{BF}', "inverted question mark"),
+    ('\u{2010}', "hyphen"),
+    ('\u{2011}', "non-breaking hyphen"),
+    ('\u{2012}', "figure dash"),
+    ('\u{2013}', "en dash"),
+    ('\u{2014}', "em dash"),
+    ('\u{2015}', "horizontal bar"),
+    ('\u{2016}', "double vertical line"),
+    ('\u{2017}', "double low line"),
+    ('\u{2018}', "left single quotation mark"),
+    ('\u{2019}', "right single quotation mark"),
+    ('\u{201A}', "single low-9 quotation mark"),
+    ('\u{201B}', "single high-reversed-9 quotation mark"),
+    ('\u{201C}', "left double quotation mark"),
+    ('\u{201D}', "right double quotation mark"),
+    ('\u{201E}', "double low-9 quotation mark"),
+    ('\u{201F}', "double high-reversed-9 quotation mark"),
+    ('\u{2020}', "dagger"),
+    ('\u{2021}', "double dagger"),
+    ('\u{2022}', "bullet"),
+    ('\u{2023}', "triangular bullet"),
+    ('\u{2024}', "one dot leader"),
+    ('\u{2025}', "two dot leader"),
+    ('\u{2026}', "horizontal ellipsis"),
+    ('\u{2027}', "hyphenation point"),
+    ('\u{2030}', "per mille sign"),
+    ('\u{2031}', "per ten thousand sign"),
+    ('\u{2032}', "prime"),
+    ('\u{2033}', "double prime"),
+    ('\u{2034}', "triple prime"),
+    ('\u{2035}', "reversed prime"),
+    ('\u{2036}', "reversed double prime"),
+    ('\u{2037}', "reversed triple prime"),
+    ('\u{2038}', "caret"),
+    ('\u{2039}', "single left-pointing angle quotation mark"),
+    ('\u{203A}', "single right-pointing angle quotation mark"),
+    ('\u{203B}', "reference mark"),
+    ('\u{203C}', "double exclamation mark"),
+    ('\u{203D}', "interrobang"),
+    ('\u{203E}', "overline"),
+    ('\u{203F}', "undertie"),
+    ('\u{2040}', "character tie"),
+    ('\u{2041}', "caret insertion point"),
+    ('\u{2042}', "asterism"),
+    ('\u{2043}', "hyphen bullet"),
+    ('\u{2044}', "fraction slash"),
+    ('\u{2045}', "left square bracket with quill"),
+    ('\u{2046}', "right square bracket with quill"),
+    ('\u{2047}', "double question mark"),
+    ('\u{2048}', "question exclamation mark"),
+    ('\u{2049}', "exclamation question mark"),
+    ('\u{204A}', "tironian sign et"),
+    ('\u{204B}', "reversed pilcrow sign"),
+    ('\u{204C}', "black leftwards bullet"),
+    ('\u{204D}', "black rightwards bullet"),
+    ('\u{204E}', "low asterisk"),
+    ('\u{204F}', "reversed semicolon"),
+    ('\u{2050}', "close up"),
+    ('\u{2051}', "two asterisks aligned vertically"),
+    ('\u{2052}', "commercial minus sign"),
+    ('\u{2053}', "swung dash"),
+    ('\u{2054}', "inverted undertie"),
+    ('\u{2055}', "flower punctuation mark"),
+    ('\u{2056}', "three dot punctuation"),
+    ('\u{2057}', "quadruple prime"),
+    ('\u{2058}', "four dot punctuation"),
+    ('\u{2059}', "five dot punctuation"),
+    ('\u{205A}', "two dot punctuation"),
+    ('\u{205B}', "four dot mark"),
+    ('\u{205C}', "dotted cross"),
+    ('\u{205D}', "tricolon"),
+    ('\u{205E}', "vertical four dots"),
+    ('\u{20A0}', "euro-currency sign"),
+    ('\u{20A1}', "colon sign"),
+    ('\u{20A2}', "cruzeiro sign"),
+    ('\u{20A3}', "french franc sign"),
+    ('\u{20A4}', "lira sign"),
+    ('\u{20A5}', "mill sign"),
+    ('\u{20A6}', "naira sign"),
+    ('\u{20A7}', "peseta sign"),
+    ('\u{20A8}', "rupee sign"),
+    ('\u{20A9}', "won sign"),
+    ('\u{20AA}', "new sheqel sign"),
+    ('\u{20AB}', "dong sign"),
+    ('\u{20AC}', "euro sign"),
+    ('\u{20AD}', "kip sign"),
+    ('\u{20AE}', "tugrik sign"),
+    ('\u{20AF}', "drachma sign"),
+    ('\u{20B0}', "german penny sign"),
+    ('\u{20B1}', "peso sign"),
+    ('\u{20B2}', "guarani sign"),
+    ('\u{20B3}', "austral sign"),
+    ('\u{20B4}', "hryvnia sign"),
+    ('\u{20B5}', "cedi sign"),
+    ('\u{20B6}', "livre tournois sign"),
+    ('\u{20B7}', "spesmilo sign"),
+    ('\u{20B8}', "tenge sign"),
+    ('\u{20B9}', "indian rupee sign"),
+    ('\u{20BA}', "turkish lira sign"),
+    ('\u{20BB}', "nordic mark sign"),
+    ('\u{20BC}', "manat sign"),
+    ('\u{20BD}', "ruble sign"),
+    ('\u{20BE}', "lari sign"),
+    ('\u{20BF}', "bitcoin sign"),
+    ('\u{20C0}', "som sign"),
+    ('\u{2100}', "account of"),
+    ('\u{2101}', "addressed to the subject"),
+    ('\u{2103}', "degree celsius"),
+    ('\u{2104}', "centre line symbol"),
+    ('\u{2105}', "care of"),
+    ('\u{2106}', "cada una"),
+    ('\u{2108}', "scruple"),
+    ('\u{2109}', "degree fahrenheit"),
+    ('\u{2114}', "l b bar symbol"),
+    ('\u{2116}', "numero sign"),
+    ('\u{2117}', "sound recording copyright"),
+    ('\u{2118}', "script capital p"),
+    ('\u{211E}', "prescription take"),
+    ('\u{211F}', "response"),
+    ('\u{2120}', "service mark"),
+    ('\u{2121}', "telephone sign"),
+    ('\u{2122}', "trade mark sign"),
+    ('\u{2123}', "versicle"),
+    ('\u{2125}', "ounce sign"),
+    ('\u{2127}', "inverted ohm sign"),
+    ('\u{2129}', "turned greek small letter iota"),
+    ('\u{212E}', "estimated symbol"),
+    ('\u{213A}', "rotated capital q"),
+    ('\u{213B}', "facsimile sign"),
+    ('\u{2140}', "double-struck n-ary summation"),
+    ('\u{2141}', "turned sans-serif capital g"),
+    ('\u{2142}', "turned sans-serif capital l"),
+    ('\u{2143}', "reversed sans-serif capital l"),
+    ('\u{2144}', "turned sans-serif capital y"),
+    ('\u{214A}', "property line"),
+    ('\u{214B}', "turned ampersand"),
+    ('\u{214C}', "per sign"),
+    ('\u{214D}', "aktieselskab"),
+    ('\u{214F}', "symbol for samaritan source"),
+    ('\u{2190}', "leftwards arrow"),
+    ('\u{2191}', "upwards arrow"),
+    ('\u{2192}', "rightwards arrow"),
+    ('\u{2193}', "downwards arrow"),
+    ('\u{2194}', "left right arrow"),
+    ('\u{2195}', "up down arrow"),
+    ('\u{2196}', "north west arrow"),
+    ('\u{2197}', "north east arrow"),
+    ('\u{2198}', "south east arrow"),
+    ('\u{2199}', "south west arrow"),
+    ('\u{219A}', "leftwards arrow with stroke"),
+    ('\u{219B}', "rightwards arrow with stroke"),
+    ('\u{219C}', "leftwards wave arrow"),
+    ('\u{219D}', "rightwards wave arrow"),
+    ('\u{219E}', "leftwards two headed arrow"),
+    ('\u{219F}', "upwards two headed arrow"),
+    ('\u{21A0}', "rightwards two headed arrow"),
+    ('\u{21A1}', "downwards two headed arrow"),
+    ('\u{21A2}', "leftwards arrow with tail"),
+    ('\u{21A3}', "rightwards arrow with tail"),
+    ('\u{21A4}', "leftwards arrow from bar"),
+    ('\u{21A5}', "upwards arrow from bar"),
+    ('\u{21A6}', "rightwards arrow from bar"),
+    ('\u{21A7}', "downwards arrow from bar"),
+    ('\u{21A8}', "up down arrow with base"),
+    ('\u{21A9}', "leftwards arrow with hook"),
+    ('\u{21AA}', "rightwards arrow with hook"),
+    ('\u{21AB}', "leftwards arrow with loop"),
+    ('\u{21AC}', "rightwards arrow with loop"),
+    ('\u{21AD}', "left right wave arrow"),
+    ('\u{21AE}', "left right arrow with stroke"),
+    ('\u{21AF}', "downwards zigzag arrow"),
+    ('\u{21B0}', "upwards arrow with tip leftwards"),
+    ('\u{21B1}', "upwards arrow with tip rightwards"),
+    ('\u{21B2}', "downwards arrow with tip leftwards"),
+    ('\u{21B3}', "downwards arrow with tip rightwards"),
+    ('\u{21B4}', "rightwards arrow with corner downwards"),
+    ('\u{21B5}', "downwards arrow with corner leftwards"),
+    ('\u{21B6}', "anticlockwise top semicircle arrow"),
+    ('\u{21B7}', "clockwise top semicircle arrow"),
+    ('\u{21B8}', "north west arrow to long bar"),
+    (
+        '\u{21B9}',
+        "leftwards arrow to bar over rightwards arrow to bar",
+    ),
+    ('\u{21BA}', "anticlockwise open circle arrow"),
+    ('\u{21BB}', "clockwise open circle arrow"),
+    ('\u{21BC}', "leftwards harpoon with barb upwards"),
+    ('\u{21BD}', "leftwards harpoon with barb downwards"),
+    ('\u{21BE}', "upwards harpoon with barb rightwards"),
+    ('\u{21BF}', "upwards harpoon with barb leftwards"),
+    ('\u{21C0}', "rightwards harpoon with barb upwards"),
+    ('\u{21C1}', "rightwards harpoon with barb downwards"),
+    ('\u{21C2}', "downwards harpoon with barb rightwards"),
+    ('\u{21C3}', "downwards harpoon with barb leftwards"),
+    ('\u{21C4}', "rightwards arrow over leftwards arrow"),
+    ('\u{21C5}', "upwards arrow leftwards of downwards arrow"),
+    ('\u{21C6}', "leftwards arrow over rightwards arrow"),
+    ('\u{21C7}', "leftwards paired arrows"),
+    ('\u{21C8}', "upwards paired arrows"),
+    ('\u{21C9}', "rightwards paired arrows"),
+    ('\u{21CA}', "downwards paired arrows"),
+    ('\u{21CB}', "leftwards harpoon over rightwards harpoon"),
+    ('\u{21CC}', "rightwards harpoon over leftwards harpoon"),
+    ('\u{21CD}', "leftwards double arrow with stroke"),
+    ('\u{21CE}', "left right double arrow with stroke"),
+    ('\u{21CF}', "rightwards double arrow with stroke"),
+    ('\u{21D0}', "leftwards double arrow"),
+    ('\u{21D1}', "upwards double arrow"),
+    ('\u{21D2}', "rightwards double arrow"),
+    ('\u{21D3}', "downwards double arrow"),
+    ('\u{21D4}', "left right double arrow"),
+    ('\u{21D5}', "up down double arrow"),
+    ('\u{21D6}', "north west double arrow"),
+    ('\u{21D7}', "north east double arrow"),
+    ('\u{21D8}', "south east double arrow"),
+    ('\u{21D9}', "south west double arrow"),
+    ('\u{21DA}', "leftwards triple arrow"),
+    ('\u{21DB}', "rightwards triple arrow"),
+    ('\u{21DC}', "leftwards squiggle arrow"),
+    ('\u{21DD}', "rightwards squiggle arrow"),
+    ('\u{21DE}', "upwards arrow with double stroke"),
+    ('\u{21DF}', "downwards arrow with double stroke"),
+    ('\u{21E0}', "leftwards dashed arrow"),
+    ('\u{21E1}', "upwards dashed arrow"),
+    ('\u{21E2}', "rightwards dashed arrow"),
+    ('\u{21E3}', "downwards dashed arrow"),
+    ('\u{21E4}', "leftwards arrow to bar"),
+    ('\u{21E5}', "rightwards arrow to bar"),
+    ('\u{21E6}', "leftwards white arrow"),
+    ('\u{21E7}', "upwards white arrow"),
+    ('\u{21E8}', "rightwards white arrow"),
+    ('\u{21E9}', "downwards white arrow"),
+    ('\u{21EA}', "upwards white arrow from bar"),
+    ('\u{21EB}', "upwards white arrow on pedestal"),
+    (
+        '\u{21EC}',
+        "upwards white arrow on pedestal with horizontal bar",
+    ),
+    (
+        '\u{21ED}',
+        "upwards white arrow on pedestal with vertical bar",
+    ),
+    ('\u{21EE}', "upwards white double arrow"),
+    ('\u{21EF}', "upwards white double arrow on pedestal"),
+    ('\u{21F0}', "rightwards white arrow from wall"),
+    ('\u{21F1}', "north west arrow to corner"),
+    ('\u{21F2}', "south east arrow to corner"),
+    ('\u{21F3}', "up down white arrow"),
+    ('\u{21F4}', "right arrow with small circle"),
+    ('\u{21F5}', "downwards arrow leftwards of upwards arrow"),
+    ('\u{21F6}', "three rightwards arrows"),
+    ('\u{21F7}', "leftwards arrow with vertical stroke"),
+    ('\u{21F8}', "rightwards arrow with vertical stroke"),
+    ('\u{21F9}', "left right arrow with vertical stroke"),
+    ('\u{21FA}', "leftwards arrow with double vertical stroke"),
+    ('\u{21FB}', "rightwards arrow with double vertical stroke"),
+    ('\u{21FC}', "left right arrow with double vertical stroke"),
+    ('\u{21FD}', "leftwards open-headed arrow"),
+    ('\u{21FE}', "rightwards open-headed arrow"),
+    ('\u{21FF}', "left right open-headed arrow"),
+    ('\u{2300}', "diameter sign"),
+    ('\u{2301}', "electric arrow"),
+    ('\u{2302}', "house"),
+    ('\u{2303}', "up arrowhead"),
+    ('\u{2304}', "down arrowhead"),
+    ('\u{2305}', "projective"),
+    ('\u{2306}', "perspective"),
+    ('\u{2307}', "wavy line"),
+    ('\u{2308}', "left ceiling"),
+    ('\u{2309}', "right ceiling"),
+    ('\u{230A}', "left floor"),
+    ('\u{230B}', "right floor"),
+    ('\u{230C}', "bottom right crop"),
+    ('\u{230D}', "bottom left crop"),
+    ('\u{230E}', "top right crop"),
+    ('\u{230F}', "top left crop"),
+    ('\u{2310}', "reversed not sign"),
+    ('\u{2311}', "square lozenge"),
+    ('\u{2312}', "arc"),
+    ('\u{2313}', "segment"),
+    ('\u{2314}', "sector"),
+    ('\u{2315}', "telephone recorder"),
+    ('\u{2316}', "position indicator"),
+    ('\u{2317}', "viewdata square"),
+    ('\u{2318}', "place of interest sign"),
+    ('\u{2319}', "turned not sign"),
+    ('\u{231A}', "watch"),
+    ('\u{231B}', "hourglass"),
+    ('\u{231C}', "top left corner"),
+    ('\u{231D}', "top right corner"),
+    ('\u{231E}', "bottom left corner"),
+    ('\u{231F}', "bottom right corner"),
+    ('\u{2320}', "top half integral"),
+    ('\u{2321}', "bottom half integral"),
+    ('\u{2322}', "frown"),
+    ('\u{2323}', "smile"),
+    ('\u{2324}', "up arrowhead between two horizontal bars"),
+    ('\u{2325}', "option key"),
+    ('\u{2326}', "erase to the right"),
+    ('\u{2327}', "x in a rectangle box"),
+    ('\u{2328}', "keyboard"),
+    ('\u{2329}', "left-pointing angle bracket"),
+    ('\u{232A}', "right-pointing angle bracket"),
+    ('\u{232B}', "erase to the left"),
+    ('\u{232C}', "benzene ring"),
+    ('\u{232D}', "cylindricity"),
+    ('\u{232E}', "all around-profile"),
+    ('\u{232F}', "symmetry"),
+    ('\u{2330}', "total runout"),
+    ('\u{2331}', "dimension origin"),
+    ('\u{2332}', "conical taper"),
+    ('\u{2333}', "slope"),
+    ('\u{2334}', "counterbore"),
+    ('\u{2335}', "countersink"),
+    ('\u{2336}', "apl functional symbol i-beam"),
+    ('\u{2337}', "apl functional symbol squish quad"),
+    ('\u{2338}', "apl functional symbol quad equal"),
+    ('\u{2339}', "apl functional symbol quad divide"),
+    ('\u{233A}', "apl functional symbol quad diamond"),
+    ('\u{233B}', "apl functional symbol quad jot"),
+    ('\u{233C}', "apl functional symbol quad circle"),
+    ('\u{233D}', "apl functional symbol circle stile"),
+    ('\u{233E}', "apl functional symbol circle jot"),
+    ('\u{233F}', "apl functional symbol slash bar"),
+    ('\u{2340}', "apl functional symbol backslash bar"),
+    ('\u{2341}', "apl functional symbol quad slash"),
+    ('\u{2342}', "apl functional symbol quad backslash"),
+    ('\u{2343}', "apl functional symbol quad less-than"),
+    ('\u{2344}', "apl functional symbol quad greater-than"),
+    ('\u{2345}', "apl functional symbol leftwards vane"),
+    ('\u{2346}', "apl functional symbol rightwards vane"),
+    ('\u{2347}', "apl functional symbol quad leftwards arrow"),
+    ('\u{2348}', "apl functional symbol quad rightwards arrow"),
+    ('\u{2349}', "apl functional symbol circle backslash"),
+    ('\u{234A}', "apl functional symbol down tack underbar"),
+    ('\u{234B}', "apl functional symbol delta stile"),
+    ('\u{234C}', "apl functional symbol quad down caret"),
+    ('\u{234D}', "apl functional symbol quad delta"),
+    ('\u{234E}', "apl functional symbol down tack jot"),
+    ('\u{234F}', "apl functional symbol upwards vane"),
+    ('\u{2350}', "apl functional symbol quad upwards arrow"),
+    ('\u{2351}', "apl functional symbol up tack overbar"),
+    ('\u{2352}', "apl functional symbol del stile"),
+    ('\u{2353}', "apl functional symbol quad up caret"),
+    ('\u{2354}', "apl functional symbol quad del"),
+    ('\u{2355}', "apl functional symbol up tack jot"),
+    ('\u{2356}', "apl functional symbol downwards vane"),
+    ('\u{2357}', "apl functional symbol quad downwards arrow"),
+    ('\u{2358}', "apl functional symbol quote underbar"),
+    ('\u{2359}', "apl functional symbol delta underbar"),
+    ('\u{235A}', "apl functional symbol diamond underbar"),
+    ('\u{235B}', "apl functional symbol jot underbar"),
+    ('\u{235C}', "apl functional symbol circle underbar"),
+    ('\u{235D}', "apl functional symbol up shoe jot"),
+    ('\u{235E}', "apl functional symbol quote quad"),
+    ('\u{235F}', "apl functional symbol circle star"),
+    ('\u{2360}', "apl functional symbol quad colon"),
+    ('\u{2361}', "apl functional symbol up tack diaeresis"),
+    ('\u{2362}', "apl functional symbol del diaeresis"),
+    ('\u{2363}', "apl functional symbol star diaeresis"),
+    ('\u{2364}', "apl functional symbol jot diaeresis"),
+    ('\u{2365}', "apl functional symbol circle diaeresis"),
+    ('\u{2366}', "apl functional symbol down shoe stile"),
+    ('\u{2367}', "apl functional symbol left shoe stile"),
+    ('\u{2368}', "apl functional symbol tilde diaeresis"),
+    ('\u{2369}', "apl functional symbol greater-than diaeresis"),
+    ('\u{236A}', "apl functional symbol comma bar"),
+    ('\u{236B}', "apl functional symbol del tilde"),
+    ('\u{236C}', "apl functional symbol zilde"),
+    ('\u{236D}', "apl functional symbol stile tilde"),
+    ('\u{236E}', "apl functional symbol semicolon underbar"),
+    ('\u{236F}', "apl functional symbol quad not equal"),
+    ('\u{2370}', "apl functional symbol quad question"),
+    ('\u{2371}', "apl functional symbol down caret tilde"),
+    ('\u{2372}', "apl functional symbol up caret tilde"),
+    ('\u{2373}', "apl functional symbol iota"),
+    ('\u{2374}', "apl functional symbol rho"),
+    ('\u{2375}', "apl functional symbol omega"),
+    ('\u{2376}', "apl functional symbol alpha underbar"),
+    ('\u{2377}', "apl functional symbol epsilon underbar"),
+    ('\u{2378}', "apl functional symbol iota underbar"),
+    ('\u{2379}', "apl functional symbol omega underbar"),
+    ('\u{237A}', "apl functional symbol alpha"),
+    ('\u{237B}', "not check mark"),
+    ('\u{237C}', "right angle with downwards zigzag arrow"),
+    ('\u{237D}', "shouldered open box"),
+    ('\u{237E}', "bell symbol"),
+    ('\u{237F}', "vertical line with middle dot"),
+    ('\u{2380}', "insertion symbol"),
+    ('\u{2381}', "continuous underline symbol"),
+    ('\u{2382}', "discontinuous underline symbol"),
+    ('\u{2383}', "emphasis symbol"),
+    ('\u{2384}', "composition symbol"),
+    ('\u{2385}', "white square with centre vertical line"),
+    ('\u{2386}', "enter symbol"),
+    ('\u{2387}', "alternative key symbol"),
+    ('\u{2388}', "helm symbol"),
+    ('\u{2389}', "circled horizontal bar with notch"),
+    ('\u{238A}', "circled triangle down"),
+    ('\u{238B}', "broken circle with northwest arrow"),
+    ('\u{238C}', "undo symbol"),
+    ('\u{238D}', "monostable symbol"),
+    ('\u{238E}', "hysteresis symbol"),
+    ('\u{238F}', "open-circuit-output h-type symbol"),
+    ('\u{2390}', "open-circuit-output l-type symbol"),
+    ('\u{2391}', "passive-pull-down-output symbol"),
+    ('\u{2392}', "passive-pull-up-output symbol"),
+    ('\u{2393}', "direct current symbol form two"),
+    ('\u{2394}', "software-function symbol"),
+    ('\u{2395}', "apl functional symbol quad"),
+    ('\u{2396}', "decimal separator key symbol"),
+    ('\u{2397}', "previous page"),
+    ('\u{2398}', "next page"),
+    ('\u{2399}', "print screen symbol"),
+    ('\u{239A}', "clear screen symbol"),
+    ('\u{239B}', "left parenthesis upper hook"),
+    ('\u{239C}', "left parenthesis extension"),
+    ('\u{239D}', "left parenthesis lower hook"),
+    ('\u{239E}', "right parenthesis upper hook"),
+    ('\u{239F}', "right parenthesis extension"),
+    ('\u{23A0}', "right parenthesis lower hook"),
+    ('\u{23A1}', "left square bracket upper corner"),
+    ('\u{23A2}', "left square bracket extension"),
+    ('\u{23A3}', "left square bracket lower corner"),
+    ('\u{23A4}', "right square bracket upper corner"),
+    ('\u{23A5}', "right square bracket extension"),
+    ('\u{23A6}', "right square bracket lower corner"),
+    ('\u{23A7}', "left curly bracket upper hook"),
+    ('\u{23A8}', "left curly bracket middle piece"),
+    ('\u{23A9}', "left curly bracket lower hook"),
+    ('\u{23AA}', "curly bracket extension"),
+    ('\u{23AB}', "right curly bracket upper hook"),
+    ('\u{23AC}', "right curly bracket middle piece"),
+    ('\u{23AD}', "right curly bracket lower hook"),
+    ('\u{23AE}', "integral extension"),
+    ('\u{23AF}', "horizontal line extension"),
+    (
+        '\u{23B0}',
+        "upper left or lower right curly bracket section",
+    ),
+    (
+        '\u{23B1}',
+        "upper right or lower left curly bracket section",
+    ),
+    ('\u{23B2}', "summation top"),
+    ('\u{23B3}', "summation bottom"),
+    ('\u{23B4}', "top square bracket"),
+    ('\u{23B5}', "bottom square bracket"),
+    ('\u{23B6}', "bottom square bracket over top square bracket"),
+    ('\u{23B7}', "radical symbol bottom"),
+    ('\u{23B8}', "left vertical box line"),
+    ('\u{23B9}', "right vertical box line"),
+    ('\u{23BA}', "horizontal scan line-1"),
+    ('\u{23BB}', "horizontal scan line-3"),
+    ('\u{23BC}', "horizontal scan line-7"),
+    ('\u{23BD}', "horizontal scan line-9"),
+    ('\u{23BE}', "dentistry symbol light vertical and top right"),
+    (
+        '\u{23BF}',
+        "dentistry symbol light vertical and bottom right",
+    ),
+    ('\u{23C0}', "dentistry symbol light vertical with circle"),
+    (
+        '\u{23C1}',
+        "dentistry symbol light down and horizontal with circle",
+    ),
+    (
+        '\u{23C2}',
+        "dentistry symbol light up and horizontal with circle",
+    ),
+    ('\u{23C3}', "dentistry symbol light vertical with triangle"),
+    (
+        '\u{23C4}',
+        "dentistry symbol light down and horizontal with triangle",
+    ),
+    (
+        '\u{23C5}',
+        "dentistry symbol light up and horizontal with triangle",
+    ),
+    ('\u{23C6}', "dentistry symbol light vertical and wave"),
+    (
+        '\u{23C7}',
+        "dentistry symbol light down and horizontal with wave",
+    ),
+    (
+        '\u{23C8}',
+        "dentistry symbol light up and horizontal with wave",
+    ),
+    ('\u{23C9}', "dentistry symbol light down and horizontal"),
+    ('\u{23CA}', "dentistry symbol light up and horizontal"),
+    ('\u{23CB}', "dentistry symbol light vertical and top left"),
+    (
+        '\u{23CC}',
+        "dentistry symbol light vertical and bottom left",
+    ),
+    ('\u{23CD}', "square foot"),
+    ('\u{23CE}', "return symbol"),
+    ('\u{23CF}', "eject symbol"),
+    ('\u{23D0}', "vertical line extension"),
+    ('\u{23D1}', "metrical breve"),
+    ('\u{23D2}', "metrical long over short"),
+    ('\u{23D3}', "metrical short over long"),
+    ('\u{23D4}', "metrical long over two shorts"),
+    ('\u{23D5}', "metrical two shorts over long"),
+    ('\u{23D6}', "metrical two shorts joined"),
+    ('\u{23D7}', "metrical triseme"),
+    ('\u{23D8}', "metrical tetraseme"),
+    ('\u{23D9}', "metrical pentaseme"),
+    ('\u{23DA}', "earth ground"),
+    ('\u{23DB}', "fuse"),
+    ('\u{23DC}', "top parenthesis"),
+    ('\u{23DD}', "bottom parenthesis"),
+    ('\u{23DE}', "top curly bracket"),
+    ('\u{23DF}', "bottom curly bracket"),
+    ('\u{23E0}', "top tortoise shell bracket"),
+    ('\u{23E1}', "bottom tortoise shell bracket"),
+    ('\u{23E2}', "white trapezium"),
+    ('\u{23E3}', "benzene ring with circle"),
+    ('\u{23E4}', "straightness"),
+    ('\u{23E5}', "flatness"),
+    ('\u{23E6}', "ac current"),
+    ('\u{23E7}', "electrical intersection"),
+    ('\u{23E8}', "decimal exponent symbol"),
+    ('\u{23E9}', "black right-pointing double triangle"),
+    ('\u{23EA}', "black left-pointing double triangle"),
+    ('\u{23EB}', "black up-pointing double triangle"),
+    ('\u{23EC}', "black down-pointing double triangle"),
+    (
+        '\u{23ED}',
+        "black right-pointing double triangle with vertical bar",
+    ),
+    (
+        '\u{23EE}',
+        "black left-pointing double triangle with vertical bar",
+    ),
+    (
+        '\u{23EF}',
+        "black right-pointing triangle with double vertical bar",
+    ),
+    ('\u{23F0}', "alarm clock"),
+    ('\u{23F1}', "stopwatch"),
+    ('\u{23F2}', "timer clock"),
+    ('\u{23F3}', "hourglass with flowing sand"),
+    ('\u{23F4}', "black medium left-pointing triangle"),
+    ('\u{23F5}', "black medium right-pointing triangle"),
+    ('\u{23F6}', "black medium up-pointing triangle"),
+    ('\u{23F7}', "black medium down-pointing triangle"),
+    ('\u{23F8}', "double vertical bar"),
+    ('\u{23F9}', "black square for stop"),
+    ('\u{23FA}', "black circle for record"),
+    ('\u{23FB}', "power symbol"),
+    ('\u{23FC}', "power on-off symbol"),
+    ('\u{23FD}', "power on symbol"),
+    ('\u{23FE}', "power sleep symbol"),
+    ('\u{23FF}', "observer eye symbol"),
+    ('\u{25A0}', "black square"),
+    ('\u{25A1}', "white square"),
+    ('\u{25A2}', "white square with rounded corners"),
+    ('\u{25A3}', "white square containing black small square"),
+    ('\u{25A4}', "square with horizontal fill"),
+    ('\u{25A5}', "square with vertical fill"),
+    ('\u{25A6}', "square with orthogonal crosshatch fill"),
+    ('\u{25A7}', "square with upper left to lower right fill"),
+    ('\u{25A8}', "square with upper right to lower left fill"),
+    ('\u{25A9}', "square with diagonal crosshatch fill"),
+    ('\u{25AA}', "black small square"),
+    ('\u{25AB}', "white small square"),
+    ('\u{25AC}', "black rectangle"),
+    ('\u{25AD}', "white rectangle"),
+    ('\u{25AE}', "black vertical rectangle"),
+    ('\u{25AF}', "white vertical rectangle"),
+    ('\u{25B0}', "black parallelogram"),
+    ('\u{25B1}', "white parallelogram"),
+    ('\u{25B2}', "black up-pointing triangle"),
+    ('\u{25B3}', "white up-pointing triangle"),
+    ('\u{25B4}', "black up-pointing small triangle"),
+    ('\u{25B5}', "white up-pointing small triangle"),
+    ('\u{25B6}', "black right-pointing triangle"),
+    ('\u{25B7}', "white right-pointing triangle"),
+    ('\u{25B8}', "black right-pointing small triangle"),
+    ('\u{25B9}', "white right-pointing small triangle"),
+    ('\u{25BA}', "black right-pointing pointer"),
+    ('\u{25BB}', "white right-pointing pointer"),
+    ('\u{25BC}', "black down-pointing triangle"),
+    ('\u{25BD}', "white down-pointing triangle"),
+    ('\u{25BE}', "black down-pointing small triangle"),
+    ('\u{25BF}', "white down-pointing small triangle"),
+    ('\u{25C0}', "black left-pointing triangle"),
+    ('\u{25C1}', "white left-pointing triangle"),
+    ('\u{25C2}', "black left-pointing small triangle"),
+    ('\u{25C3}', "white left-pointing small triangle"),
+    ('\u{25C4}', "black left-pointing pointer"),
+    ('\u{25C5}', "white left-pointing pointer"),
+    ('\u{25C6}', "black diamond"),
+    ('\u{25C7}', "white diamond"),
+    ('\u{25C8}', "white diamond containing black small diamond"),
+    ('\u{25C9}', "fisheye"),
+    ('\u{25CA}', "lozenge"),
+    ('\u{25CB}', "white circle"),
+    ('\u{25CC}', "dotted circle"),
+    ('\u{25CD}', "circle with vertical fill"),
+    ('\u{25CE}', "bullseye"),
+    ('\u{25CF}', "black circle"),
+    ('\u{25D0}', "circle with left half black"),
+    ('\u{25D1}', "circle with right half black"),
+    ('\u{25D2}', "circle with lower half black"),
+    ('\u{25D3}', "circle with upper half black"),
+    ('\u{25D4}', "circle with upper right quadrant black"),
+    ('\u{25D5}', "circle with all but upper left quadrant black"),
+    ('\u{25D6}', "left half black circle"),
+    ('\u{25D7}', "right half black circle"),
+    ('\u{25D8}', "inverse bullet"),
+    ('\u{25D9}', "inverse white circle"),
+    ('\u{25DA}', "upper half inverse white circle"),
+    ('\u{25DB}', "lower half inverse white circle"),
+    ('\u{25DC}', "upper left quadrant circular arc"),
+    ('\u{25DD}', "upper right quadrant circular arc"),
+    ('\u{25DE}', "lower right quadrant circular arc"),
+    ('\u{25DF}', "lower left quadrant circular arc"),
+    ('\u{25E0}', "upper half circle"),
+    ('\u{25E1}', "lower half circle"),
+    ('\u{25E2}', "black lower right triangle"),
+    ('\u{25E3}', "black lower left triangle"),
+    ('\u{25E4}', "black upper left triangle"),
+    ('\u{25E5}', "black upper right triangle"),
+    ('\u{25E6}', "white bullet"),
+    ('\u{25E7}', "square with left half black"),
+    ('\u{25E8}', "square with right half black"),
+    ('\u{25E9}', "square with upper left diagonal half black"),
+    ('\u{25EA}', "square with lower right diagonal half black"),
+    ('\u{25EB}', "white square with vertical bisecting line"),
+    ('\u{25EC}', "white up-pointing triangle with dot"),
+    ('\u{25ED}', "up-pointing triangle with left half black"),
+    ('\u{25EE}', "up-pointing triangle with right half black"),
+    ('\u{25EF}', "large circle"),
+    ('\u{25F0}', "white square with upper left quadrant"),
+    ('\u{25F1}', "white square with lower left quadrant"),
+    ('\u{25F2}', "white square with lower right quadrant"),
+    ('\u{25F3}', "white square with upper right quadrant"),
+    ('\u{25F4}', "white circle with upper left quadrant"),
+    ('\u{25F5}', "white circle with lower left quadrant"),
+    ('\u{25F6}', "white circle with lower right quadrant"),
+    ('\u{25F7}', "white circle with upper right quadrant"),
+    ('\u{25F8}', "upper left triangle"),
+    ('\u{25F9}', "upper right triangle"),
+    ('\u{25FA}', "lower left triangle"),
+    ('\u{25FB}', "white medium square"),
+    ('\u{25FC}', "black medium square"),
+    ('\u{25FD}', "white medium small square"),
+    ('\u{25FE}', "black medium small square"),
+    ('\u{25FF}', "lower right triangle"),
+    ('\u{2600}', "black sun with rays"),
+    ('\u{2601}', "cloud"),
+    ('\u{2602}', "umbrella"),
+    ('\u{2603}', "snowman"),
+    ('\u{2604}', "comet"),
+    ('\u{2605}', "black star"),
+    ('\u{2606}', "white star"),
+    ('\u{2607}', "lightning"),
+    ('\u{2608}', "thunderstorm"),
+    ('\u{2609}', "sun"),
+    ('\u{260A}', "ascending node"),
+    ('\u{260B}', "descending node"),
+    ('\u{260C}', "conjunction"),
+    ('\u{260D}', "opposition"),
+    ('\u{260E}', "black telephone"),
+    ('\u{260F}', "white telephone"),
+    ('\u{2610}', "ballot box"),
+    ('\u{2611}', "ballot box with check"),
+    ('\u{2612}', "ballot box with x"),
+    ('\u{2613}', "saltire"),
+    ('\u{2614}', "umbrella with rain drops"),
+    ('\u{2615}', "hot beverage"),
+    ('\u{2616}', "white shogi piece"),
+    ('\u{2617}', "black shogi piece"),
+    ('\u{2618}', "shamrock"),
+    ('\u{2619}', "reversed rotated floral heart bullet"),
+    ('\u{261A}', "black left pointing index"),
+    ('\u{261B}', "black right pointing index"),
+    ('\u{261C}', "white left pointing index"),
+    ('\u{261D}', "white up pointing index"),
+    ('\u{261E}', "white right pointing index"),
+    ('\u{261F}', "white down pointing index"),
+    ('\u{2620}', "skull and crossbones"),
+    ('\u{2621}', "caution sign"),
+    ('\u{2622}', "radioactive sign"),
+    ('\u{2623}', "biohazard sign"),
+    ('\u{2624}', "caduceus"),
+    ('\u{2625}', "ankh"),
+    ('\u{2626}', "orthodox cross"),
+    ('\u{2627}', "chi rho"),
+    ('\u{2628}', "cross of lorraine"),
+    ('\u{2629}', "cross of jerusalem"),
+    ('\u{262A}', "star and crescent"),
+    ('\u{262B}', "farsi symbol"),
+    ('\u{262C}', "adi shakti"),
+    ('\u{262D}', "hammer and sickle"),
+    ('\u{262E}', "peace symbol"),
+    ('\u{262F}', "yin yang"),
+    ('\u{2630}', "trigram for heaven"),
+    ('\u{2631}', "trigram for lake"),
+    ('\u{2632}', "trigram for fire"),
+    ('\u{2633}', "trigram for thunder"),
+    ('\u{2634}', "trigram for wind"),
+    ('\u{2635}', "trigram for water"),
+    ('\u{2636}', "trigram for mountain"),
+    ('\u{2637}', "trigram for earth"),
+    ('\u{2638}', "wheel of dharma"),
+    ('\u{2639}', "white frowning face"),
+    ('\u{263A}', "white smiling face"),
+    ('\u{263B}', "black smiling face"),
+    ('\u{263C}', "white sun with rays"),
+    ('\u{263D}', "first quarter moon"),
+    ('\u{263E}', "last quarter moon"),
+    ('\u{263F}', "mercury"),
+    ('\u{2640}', "female sign"),
+    ('\u{2641}', "earth"),
+    ('\u{2642}', "male sign"),
+    ('\u{2643}', "jupiter"),
+    ('\u{2644}', "saturn"),
+    ('\u{2645}', "uranus"),
+    ('\u{2646}', "neptune"),
+    ('\u{2647}', "pluto"),
+    ('\u{2648}', "aries"),
+    ('\u{2649}', "taurus"),
+    ('\u{264A}', "gemini"),
+    ('\u{264B}', "cancer"),
+    ('\u{264C}', "leo"),
+    ('\u{264D}', "virgo"),
+    ('\u{264E}', "libra"),
+    ('\u{264F}', "scorpius"),
+    ('\u{2650}', "sagittarius"),
+    ('\u{2651}', "capricorn"),
+    ('\u{2652}', "aquarius"),
+    ('\u{2653}', "pisces"),
+    ('\u{2654}', "white chess king"),
+    ('\u{2655}', "white chess queen"),
+    ('\u{2656}', "white chess rook"),
+    ('\u{2657}', "white chess bishop"),
+    ('\u{2658}', "white chess knight"),
+    ('\u{2659}', "white chess pawn"),
+    ('\u{265A}', "black chess king"),
+    ('\u{265B}', "black chess queen"),
+    ('\u{265C}', "black chess rook"),
+    ('\u{265D}', "black chess bishop"),
+    ('\u{265E}', "black chess knight"),
+    ('\u{265F}', "black chess pawn"),
+    ('\u{2660}', "black spade suit"),
+    ('\u{2661}', "white heart suit"),
+    ('\u{2662}', "white diamond suit"),
+    ('\u{2663}', "black club suit"),
+    ('\u{2664}', "white spade suit"),
+    ('\u{2665}', "black heart suit"),
+    ('\u{2666}', "black diamond suit"),
+    ('\u{2667}', "white club suit"),
+    ('\u{2668}', "hot springs"),
+    ('\u{2669}', "quarter note"),
+    ('\u{266A}', "eighth note"),
+    ('\u{266B}', "beamed eighth notes"),
+    ('\u{266C}', "beamed sixteenth notes"),
+    ('\u{266D}', "music flat sign"),
+    ('\u{266E}', "music natural sign"),
+    ('\u{266F}', "music sharp sign"),
+    ('\u{2670}', "west syriac cross"),
+    ('\u{2671}', "east syriac cross"),
+    ('\u{2672}', "universal recycling symbol"),
+    ('\u{2673}', "recycling symbol for type-1 plastics"),
+    ('\u{2674}', "recycling symbol for type-2 plastics"),
+    ('\u{2675}', "recycling symbol for type-3 plastics"),
+    ('\u{2676}', "recycling symbol for type-4 plastics"),
+    ('\u{2677}', "recycling symbol for type-5 plastics"),
+    ('\u{2678}', "recycling symbol for type-6 plastics"),
+    ('\u{2679}', "recycling symbol for type-7 plastics"),
+    ('\u{267A}', "recycling symbol for generic materials"),
+    ('\u{267B}', "black universal recycling symbol"),
+    ('\u{267C}', "recycled paper symbol"),
+    ('\u{267D}', "partially-recycled paper symbol"),
+    ('\u{267E}', "permanent paper sign"),
+    ('\u{267F}', "wheelchair symbol"),
+    ('\u{2680}', "die face-1"),
+    ('\u{2681}', "die face-2"),
+    ('\u{2682}', "die face-3"),
+    ('\u{2683}', "die face-4"),
+    ('\u{2684}', "die face-5"),
+    ('\u{2685}', "die face-6"),
+    ('\u{2686}', "white circle with dot right"),
+    ('\u{2687}', "white circle with two dots"),
+    ('\u{2688}', "black circle with white dot right"),
+    ('\u{2689}', "black circle with two white dots"),
+    ('\u{268A}', "monogram for yang"),
+    ('\u{268B}', "monogram for yin"),
+    ('\u{268C}', "digram for greater yang"),
+    ('\u{268D}', "digram for lesser yin"),
+    ('\u{268E}', "digram for lesser yang"),
+    ('\u{268F}', "digram for greater yin"),
+    ('\u{2690}', "white flag"),
+    ('\u{2691}', "black flag"),
+    ('\u{2692}', "hammer and pick"),
+    ('\u{2693}', "anchor"),
+    ('\u{2694}', "crossed swords"),
+    ('\u{2695}', "staff of aesculapius"),
+    ('\u{2696}', "scales"),
+    ('\u{2697}', "alembic"),
+    ('\u{2698}', "flower"),
+    ('\u{2699}', "gear"),
+    ('\u{269A}', "staff of hermes"),
+    ('\u{269B}', "atom symbol"),
+    ('\u{269C}', "fleur-de-lis"),
+    ('\u{269D}', "outlined white star"),
+    ('\u{269E}', "three lines converging right"),
+    ('\u{269F}', "three lines converging left"),
+    ('\u{26A0}', "warning sign"),
+    ('\u{26A1}', "high voltage sign"),
+    ('\u{26A2}', "doubled female sign"),
+    ('\u{26A3}', "doubled male sign"),
+    ('\u{26A4}', "interlocked female and male sign"),
+    ('\u{26A5}', "male and female sign"),
+    ('\u{26A6}', "male with stroke sign"),
+    ('\u{26A7}', "male with stroke and male and female sign"),
+    ('\u{26A8}', "vertical male with stroke sign"),
+    ('\u{26A9}', "horizontal male with stroke sign"),
+    ('\u{26AA}', "medium white circle"),
+    ('\u{26AB}', "medium black circle"),
+    ('\u{26AC}', "medium small white circle"),
+    ('\u{26AD}', "marriage symbol"),
+    ('\u{26AE}', "divorce symbol"),
+    ('\u{26AF}', "unmarried partnership symbol"),
+    ('\u{26B0}', "coffin"),
+    ('\u{26B1}', "funeral urn"),
+    ('\u{26B2}', "neuter"),
+    ('\u{26B3}', "ceres"),
+    ('\u{26B4}', "pallas"),
+    ('\u{26B5}', "juno"),
+    ('\u{26B6}', "vesta"),
+    ('\u{26B7}', "chiron"),
+    ('\u{26B8}', "black moon lilith"),
+    ('\u{26B9}', "sextile"),
+    ('\u{26BA}', "semisextile"),
+    ('\u{26BB}', "quincunx"),
+    ('\u{26BC}', "sesquiquadrate"),
+    ('\u{26BD}', "soccer ball"),
+    ('\u{26BE}', "baseball"),
+    ('\u{26BF}', "squared key"),
+    ('\u{26C0}', "white draughts man"),
+    ('\u{26C1}', "white draughts king"),
+    ('\u{26C2}', "black draughts man"),
+    ('\u{26C3}', "black draughts king"),
+    ('\u{26C4}', "snowman without snow"),
+    ('\u{26C5}', "sun behind cloud"),
+    ('\u{26C6}', "rain"),
+    ('\u{26C7}', "black snowman"),
+    ('\u{26C8}', "thunder cloud and rain"),
+    ('\u{26C9}', "turned white shogi piece"),
+    ('\u{26CA}', "turned black shogi piece"),
+    ('\u{26CB}', "white diamond in square"),
+    ('\u{26CC}', "crossing lanes"),
+    ('\u{26CD}', "disabled car"),
+    ('\u{26CE}', "ophiuchus"),
+    ('\u{26CF}', "pick"),
+    ('\u{26D0}', "car sliding"),
+    ('\u{26D1}', "helmet with white cross"),
+    ('\u{26D2}', "circled crossing lanes"),
+    ('\u{26D3}', "chains"),
+    ('\u{26D4}', "no entry"),
+    ('\u{26D5}', "alternate one-way left way traffic"),
+    ('\u{26D6}', "black two-way left way traffic"),
+    ('\u{26D7}', "white two-way left way traffic"),
+    ('\u{26D8}', "black left lane merge"),
+    ('\u{26D9}', "white left lane merge"),
+    ('\u{26DA}', "drive slow sign"),
+    ('\u{26DB}', "heavy white down-pointing triangle"),
+    ('\u{26DC}', "left closed entry"),
+    ('\u{26DD}', "squared saltire"),
+    (
+        '\u{26DE}',
+        "falling diagonal in white circle in black square",
+    ),
+    ('\u{26DF}', "black truck"),
+    ('\u{26E0}', "restricted left entry-1"),
+    ('\u{26E1}', "restricted left entry-2"),
+    ('\u{26E2}', "astronomical symbol for uranus"),
+    ('\u{26E3}', "heavy circle with stroke and two dots above"),
+    ('\u{26E4}', "pentagram"),
+    ('\u{26E5}', "right-handed interlaced pentagram"),
+    ('\u{26E6}', "left-handed interlaced pentagram"),
+    ('\u{26E7}', "inverted pentagram"),
+    ('\u{26E8}', "black cross on shield"),
+    ('\u{26E9}', "shinto shrine"),
+    ('\u{26EA}', "church"),
+    ('\u{26EB}', "castle"),
+    ('\u{26EC}', "historic site"),
+    ('\u{26ED}', "gear without hub"),
+    ('\u{26EE}', "gear with handles"),
+    ('\u{26EF}', "map symbol for lighthouse"),
+    ('\u{26F0}', "mountain"),
+    ('\u{26F1}', "umbrella on ground"),
+    ('\u{26F2}', "fountain"),
+    ('\u{26F3}', "flag in hole"),
+    ('\u{26F4}', "ferry"),
+    ('\u{26F5}', "sailboat"),
+    ('\u{26F6}', "square four corners"),
+    ('\u{26F7}', "skier"),
+    ('\u{26F8}', "ice skate"),
+    ('\u{26F9}', "person with ball"),
+    ('\u{26FA}', "tent"),
+    ('\u{26FB}', "japanese bank symbol"),
+    ('\u{26FC}', "headstone graveyard symbol"),
+    ('\u{26FD}', "fuel pump"),
+    ('\u{26FE}', "cup on black square"),
+    ('\u{26FF}', "white flag with horizontal middle black stripe"),
+    ('\u{2700}', "black safety scissors"),
+    ('\u{2701}', "upper blade scissors"),
+    ('\u{2702}', "black scissors"),
+    ('\u{2703}', "lower blade scissors"),
+    ('\u{2704}', "white scissors"),
+    ('\u{2705}', "white heavy check mark"),
+    ('\u{2706}', "telephone location sign"),
+    ('\u{2707}', "tape drive"),
+    ('\u{2708}', "airplane"),
+    ('\u{2709}', "envelope"),
+    ('\u{270A}', "raised fist"),
+    ('\u{270B}', "raised hand"),
+    ('\u{270C}', "victory hand"),
+    ('\u{270D}', "writing hand"),
+    ('\u{270E}', "lower right pencil"),
+    ('\u{270F}', "pencil"),
+    ('\u{2710}', "upper right pencil"),
+    ('\u{2711}', "white nib"),
+    ('\u{2712}', "black nib"),
+    ('\u{2713}', "check mark"),
+    ('\u{2714}', "heavy check mark"),
+    ('\u{2715}', "multiplication x"),
+    ('\u{2716}', "heavy multiplication x"),
+    ('\u{2717}', "ballot x"),
+    ('\u{2718}', "heavy ballot x"),
+    ('\u{2719}', "outlined greek cross"),
+    ('\u{271A}', "heavy greek cross"),
+    ('\u{271B}', "open centre cross"),
+    ('\u{271C}', "heavy open centre cross"),
+    ('\u{271D}', "latin cross"),
+    ('\u{271E}', "shadowed white latin cross"),
+    ('\u{271F}', "outlined latin cross"),
+    ('\u{2720}', "maltese cross"),
+    ('\u{2721}', "star of david"),
+    ('\u{2722}', "four teardrop-spoked asterisk"),
+    ('\u{2723}', "four balloon-spoked asterisk"),
+    ('\u{2724}', "heavy four balloon-spoked asterisk"),
+    ('\u{2725}', "four club-spoked asterisk"),
+    ('\u{2726}', "black four pointed star"),
+    ('\u{2727}', "white four pointed star"),
+    ('\u{2728}', "sparkles"),
+    ('\u{2729}', "stress outlined white star"),
+    ('\u{272A}', "circled white star"),
+    ('\u{272B}', "open centre black star"),
+    ('\u{272C}', "black centre white star"),
+    ('\u{272D}', "outlined black star"),
+    ('\u{272E}', "heavy outlined black star"),
+    ('\u{272F}', "pinwheel star"),
+    ('\u{2730}', "shadowed white star"),
+    ('\u{2731}', "heavy asterisk"),
+    ('\u{2732}', "open centre asterisk"),
+    ('\u{2733}', "eight spoked asterisk"),
+    ('\u{2734}', "eight pointed black star"),
+    ('\u{2735}', "eight pointed pinwheel star"),
+    ('\u{2736}', "six pointed black star"),
+    ('\u{2737}', "eight pointed rectilinear black star"),
+    ('\u{2738}', "heavy eight pointed rectilinear black star"),
+    ('\u{2739}', "twelve pointed black star"),
+    ('\u{273A}', "sixteen pointed asterisk"),
+    ('\u{273B}', "teardrop-spoked asterisk"),
+    ('\u{273C}', "open centre teardrop-spoked asterisk"),
+    ('\u{273D}', "heavy teardrop-spoked asterisk"),
+    ('\u{273E}', "six petalled black and white florette"),
+    ('\u{273F}', "black florette"),
+    ('\u{2740}', "white florette"),
+    ('\u{2741}', "eight petalled outlined black florette"),
+    ('\u{2742}', "circled open centre eight pointed star"),
+    ('\u{2743}', "heavy teardrop-spoked pinwheel asterisk"),
+    ('\u{2744}', "snowflake"),
+    ('\u{2745}', "tight trifoliate snowflake"),
+    ('\u{2746}', "heavy chevron snowflake"),
+    ('\u{2747}', "sparkle"),
+    ('\u{2748}', "heavy sparkle"),
+    ('\u{2749}', "balloon-spoked asterisk"),
+    ('\u{274A}', "eight teardrop-spoked propeller asterisk"),
+    ('\u{274B}', "heavy eight teardrop-spoked propeller asterisk"),
+    ('\u{274C}', "cross mark"),
+    ('\u{274D}', "shadowed white circle"),
+    ('\u{274E}', "negative squared cross mark"),
+    ('\u{274F}', "lower right drop-shadowed white square"),
+    ('\u{2750}', "upper right drop-shadowed white square"),
+    ('\u{2751}', "lower right shadowed white square"),
+    ('\u{2752}', "upper right shadowed white square"),
+    ('\u{2753}', "black question mark ornament"),
+    ('\u{2754}', "white question mark ornament"),
+    ('\u{2755}', "white exclamation mark ornament"),
+    ('\u{2756}', "black diamond minus white x"),
+    ('\u{2757}', "heavy exclamation mark symbol"),
+    ('\u{2758}', "light vertical bar"),
+    ('\u{2759}', "medium vertical bar"),
+    ('\u{275A}', "heavy vertical bar"),
+    (
+        '\u{275B}',
+        "heavy single turned comma quotation mark ornament",
+    ),
+    ('\u{275C}', "heavy single comma quotation mark ornament"),
+    (
+        '\u{275D}',
+        "heavy double turned comma quotation mark ornament",
+    ),
+    ('\u{275E}', "heavy double comma quotation mark ornament"),
+    ('\u{275F}', "heavy low single comma quotation mark ornament"),
+    ('\u{2760}', "heavy low double comma quotation mark ornament"),
+    ('\u{2761}', "curved stem paragraph sign ornament"),
+    ('\u{2762}', "heavy exclamation mark ornament"),
+    ('\u{2763}', "heavy heart exclamation mark ornament"),
+    ('\u{2764}', "heavy black heart"),
+    ('\u{2765}', "rotated heavy black heart bullet"),
+    ('\u{2766}', "floral heart"),
+    ('\u{2767}', "rotated floral heart bullet"),
+    ('\u{2768}', "medium left parenthesis ornament"),
+    ('\u{2769}', "medium right parenthesis ornament"),
+    ('\u{276A}', "medium flattened left parenthesis ornament"),
+    ('\u{276B}', "medium flattened right parenthesis ornament"),
+    ('\u{276C}', "medium left-pointing angle bracket ornament"),
+    ('\u{276D}', "medium right-pointing angle bracket ornament"),
+    (
+        '\u{276E}',
+        "heavy left-pointing angle quotation mark ornament",
+    ),
+    (
+        '\u{276F}',
+        "heavy right-pointing angle quotation mark ornament",
+    ),
+    ('\u{2770}', "heavy left-pointing angle bracket ornament"),
+    ('\u{2771}', "heavy right-pointing angle bracket ornament"),
+    ('\u{2772}', "light left tortoise shell bracket ornament"),
+    ('\u{2773}', "light right tortoise shell bracket ornament"),
+    ('\u{2774}', "medium left curly bracket ornament"),
+    ('\u{2775}', "medium right curly bracket ornament"),
+    ('\u{2794}', "heavy wide-headed rightwards arrow"),
+    ('\u{2795}', "heavy plus sign"),
+    ('\u{2796}', "heavy minus sign"),
+    ('\u{2797}', "heavy division sign"),
+    ('\u{2798}', "heavy south east arrow"),
+    ('\u{2799}', "heavy rightwards arrow"),
+    ('\u{279A}', "heavy north east arrow"),
+    ('\u{279B}', "drafting point rightwards arrow"),
+    ('\u{279C}', "heavy round-tipped rightwards arrow"),
+    ('\u{279D}', "triangle-headed rightwards arrow"),
+    ('\u{279E}', "heavy triangle-headed rightwards arrow"),
+    ('\u{279F}', "dashed triangle-headed rightwards arrow"),
+    ('\u{27A0}', "heavy dashed triangle-headed rightwards arrow"),
+    ('\u{27A1}', "black rightwards arrow"),
+    ('\u{27A2}', "three-d top-lighted rightwards arrowhead"),
+    ('\u{27A3}', "three-d bottom-lighted rightwards arrowhead"),
+    ('\u{27A4}', "black rightwards arrowhead"),
+    (
+        '\u{27A5}',
+        "heavy black curved downwards and rightwards arrow",
+    ),
+    (
+        '\u{27A6}',
+        "heavy black curved upwards and rightwards arrow",
+    ),
+    ('\u{27A7}', "squat black rightwards arrow"),
+    ('\u{27A8}', "heavy concave-pointed black rightwards arrow"),
+    ('\u{27A9}', "right-shaded white rightwards arrow"),
+    ('\u{27AA}', "left-shaded white rightwards arrow"),
+    ('\u{27AB}', "back-tilted shadowed white rightwards arrow"),
+    ('\u{27AC}', "front-tilted shadowed white rightwards arrow"),
+    (
+        '\u{27AD}',
+        "heavy lower right-shadowed white rightwards arrow",
+    ),
+    (
+        '\u{27AE}',
+        "heavy upper right-shadowed white rightwards arrow",
+    ),
+    (
+        '\u{27AF}',
+        "notched lower right-shadowed white rightwards arrow",
+    ),
+    ('\u{27B0}', "curly loop"),
+    (
+        '\u{27B1}',
+        "notched upper right-shadowed white rightwards arrow",
+    ),
+    ('\u{27B2}', "circled heavy white rightwards arrow"),
+    ('\u{27B3}', "white-feathered rightwards arrow"),
+    ('\u{27B4}', "black-feathered south east arrow"),
+    ('\u{27B5}', "black-feathered rightwards arrow"),
+    ('\u{27B6}', "black-feathered north east arrow"),
+    ('\u{27B7}', "heavy black-feathered south east arrow"),
+    ('\u{27B8}', "heavy black-feathered rightwards arrow"),
+    ('\u{27B9}', "heavy black-feathered north east arrow"),
+    ('\u{27BA}', "teardrop-barbed rightwards arrow"),
+    ('\u{27BB}', "heavy teardrop-shanked rightwards arrow"),
+    ('\u{27BC}', "wedge-tailed rightwards arrow"),
+    ('\u{27BD}', "heavy wedge-tailed rightwards arrow"),
+    ('\u{27BE}', "open-outlined rightwards arrow"),
+    ('\u{27BF}', "double curly loop"),
+    ('\u{2B00}', "north east white arrow"),
+    ('\u{2B01}', "north west white arrow"),
+    ('\u{2B02}', "south east white arrow"),
+    ('\u{2B03}', "south west white arrow"),
+    ('\u{2B04}', "left right white arrow"),
+    ('\u{2B05}', "leftwards black arrow"),
+    ('\u{2B06}', "upwards black arrow"),
+    ('\u{2B07}', "downwards black arrow"),
+    ('\u{2B08}', "north east black arrow"),
+    ('\u{2B09}', "north west black arrow"),
+    ('\u{2B0A}', "south east black arrow"),
+    ('\u{2B0B}', "south west black arrow"),
+    ('\u{2B0C}', "left right black arrow"),
+    ('\u{2B0D}', "up down black arrow"),
+    ('\u{2B0E}', "rightwards arrow with tip downwards"),
+    ('\u{2B0F}', "rightwards arrow with tip upwards"),
+    ('\u{2B10}', "leftwards arrow with tip downwards"),
+    ('\u{2B11}', "leftwards arrow with tip upwards"),
+    ('\u{2B12}', "square with top half black"),
+    ('\u{2B13}', "square with bottom half black"),
+    ('\u{2B14}', "square with upper right diagonal half black"),
+    ('\u{2B15}', "square with lower left diagonal half black"),
+    ('\u{2B16}', "diamond with left half black"),
+    ('\u{2B17}', "diamond with right half black"),
+    ('\u{2B18}', "diamond with top half black"),
+    ('\u{2B19}', "diamond with bottom half black"),
+    ('\u{2B1A}', "dotted square"),
+    ('\u{2B1B}', "black large square"),
+    ('\u{2B1C}', "white large square"),
+    ('\u{2B1D}', "black very small square"),
+    ('\u{2B1E}', "white very small square"),
+    ('\u{2B1F}', "black pentagon"),
+    ('\u{2B20}', "white pentagon"),
+    ('\u{2B21}', "white hexagon"),
+    ('\u{2B22}', "black hexagon"),
+    ('\u{2B23}', "horizontal black hexagon"),
+    ('\u{2B24}', "black large circle"),
+    ('\u{2B25}', "black medium diamond"),
+    ('\u{2B26}', "white medium diamond"),
+    ('\u{2B27}', "black medium lozenge"),
+    ('\u{2B28}', "white medium lozenge"),
+    ('\u{2B29}', "black small diamond"),
+    ('\u{2B2A}', "black small lozenge"),
+    ('\u{2B2B}', "white small lozenge"),
+    ('\u{2B2C}', "black horizontal ellipse"),
+    ('\u{2B2D}', "white horizontal ellipse"),
+    ('\u{2B2E}', "black vertical ellipse"),
+    ('\u{2B2F}', "white vertical ellipse"),
+    ('\u{2B30}', "left arrow with small circle"),
+    ('\u{2B31}', "three leftwards arrows"),
+    ('\u{2B32}', "left arrow with circled plus"),
+    ('\u{2B33}', "long leftwards squiggle arrow"),
+    (
+        '\u{2B34}',
+        "leftwards two-headed arrow with vertical stroke",
+    ),
+    (
+        '\u{2B35}',
+        "leftwards two-headed arrow with double vertical stroke",
+    ),
+    ('\u{2B36}', "leftwards two-headed arrow from bar"),
+    ('\u{2B37}', "leftwards two-headed triple dash arrow"),
+    ('\u{2B38}', "leftwards arrow with dotted stem"),
+    ('\u{2B39}', "leftwards arrow with tail with vertical stroke"),
+    (
+        '\u{2B3A}',
+        "leftwards arrow with tail with double vertical stroke",
+    ),
+    ('\u{2B3B}', "leftwards two-headed arrow with tail"),
+    (
+        '\u{2B3C}',
+        "leftwards two-headed arrow with tail with vertical stroke",
+    ),
+    (
+        '\u{2B3D}',
+        "leftwards two-headed arrow with tail with double vertical stroke",
+    ),
+    ('\u{2B3E}', "leftwards arrow through x"),
+    ('\u{2B3F}', "wave arrow pointing directly left"),
+    ('\u{2B40}', "equals sign above leftwards arrow"),
+    ('\u{2B41}', "reverse tilde operator above leftwards arrow"),
+    ('\u{2B42}', "leftwards arrow above reverse almost equal to"),
+    ('\u{2B43}', "rightwards arrow through greater-than"),
+    ('\u{2B44}', "rightwards arrow through superset"),
+    ('\u{2B45}', "leftwards quadruple arrow"),
+    ('\u{2B46}', "rightwards quadruple arrow"),
+    ('\u{2B47}', "reverse tilde operator above rightwards arrow"),
+    ('\u{2B48}', "rightwards arrow above reverse almost equal to"),
+    ('\u{2B49}', "tilde operator above leftwards arrow"),
+    ('\u{2B4A}', "leftwards arrow above almost equal to"),
+    ('\u{2B4B}', "leftwards arrow above reverse tilde operator"),
+    ('\u{2B4C}', "rightwards arrow above reverse tilde operator"),
+    ('\u{2B4D}', "downwards triangle-headed zigzag arrow"),
+    ('\u{2B4E}', "short slanted north arrow"),
+    ('\u{2B4F}', "short backslanted south arrow"),
+    ('\u{2B50}', "white medium star"),
+    ('\u{2B51}', "black small star"),
+    ('\u{2B52}', "white small star"),
+    ('\u{2B53}', "black right-pointing pentagon"),
+    ('\u{2B54}', "white right-pointing pentagon"),
+    ('\u{2B55}', "heavy large circle"),
+    ('\u{2B56}', "heavy oval with oval inside"),
+    ('\u{2B57}', "heavy circle with circle inside"),
+    ('\u{2B58}', "heavy circle"),
+    ('\u{2B59}', "heavy circled saltire"),
+    ('\u{2B5A}', "slanted north arrow with hooked head"),
+    ('\u{2B5B}', "backslanted south arrow with hooked tail"),
+    ('\u{2B5C}', "slanted north arrow with horizontal tail"),
+    ('\u{2B5D}', "backslanted south arrow with horizontal tail"),
+    ('\u{2B5E}', "bent arrow pointing downwards then north east"),
+    (
+        '\u{2B5F}',
+        "short bent arrow pointing downwards then north east",
+    ),
+    ('\u{2B60}', "leftwards triangle-headed arrow"),
+    ('\u{2B61}', "upwards triangle-headed arrow"),
+    ('\u{2B62}', "rightwards triangle-headed arrow"),
+    ('\u{2B63}', "downwards triangle-headed arrow"),
+    ('\u{2B64}', "left right triangle-headed arrow"),
+    ('\u{2B65}', "up down triangle-headed arrow"),
+    ('\u{2B66}', "north west triangle-headed arrow"),
+    ('\u{2B67}', "north east triangle-headed arrow"),
+    ('\u{2B68}', "south east triangle-headed arrow"),
+    ('\u{2B69}', "south west triangle-headed arrow"),
+    ('\u{2B6A}', "leftwards triangle-headed dashed arrow"),
+    ('\u{2B6B}', "upwards triangle-headed dashed arrow"),
+    ('\u{2B6C}', "rightwards triangle-headed dashed arrow"),
+    ('\u{2B6D}', "downwards triangle-headed dashed arrow"),
+    ('\u{2B6E}', "clockwise triangle-headed open circle arrow"),
+    (
+        '\u{2B6F}',
+        "anticlockwise triangle-headed open circle arrow",
+    ),
+    ('\u{2B70}', "leftwards triangle-headed arrow to bar"),
+    ('\u{2B71}', "upwards triangle-headed arrow to bar"),
+    ('\u{2B72}', "rightwards triangle-headed arrow to bar"),
+    ('\u{2B73}', "downwards triangle-headed arrow to bar"),
+    ('\u{2B76}', "north west triangle-headed arrow to bar"),
+    ('\u{2B77}', "north east triangle-headed arrow to bar"),
+    ('\u{2B78}', "south east triangle-headed arrow to bar"),
+    ('\u{2B79}', "south west triangle-headed arrow to bar"),
+    (
+        '\u{2B7A}',
+        "leftwards triangle-headed arrow with double horizontal stroke",
+    ),
+    (
+        '\u{2B7B}',
+        "upwards triangle-headed arrow with double horizontal stroke",
+    ),
+    (
+        '\u{2B7C}',
+        "rightwards triangle-headed arrow with double horizontal stroke",
+    ),
+    (
+        '\u{2B7D}',
+        "downwards triangle-headed arrow with double horizontal stroke",
+    ),
+    ('\u{2B7E}', "horizontal tab key"),
+    ('\u{2B7F}', "vertical tab key"),
+    (
+        '\u{2B80}',
+        "leftwards triangle-headed arrow over rightwards triangle-headed arrow",
+    ),
+    (
+        '\u{2B81}',
+        "upwards triangle-headed arrow leftwards of downwards triangle-headed arrow",
+    ),
+    (
+        '\u{2B82}',
+        "rightwards triangle-headed arrow over leftwards triangle-headed arrow",
+    ),
+    (
+        '\u{2B83}',
+        "downwards triangle-headed arrow leftwards of upwards triangle-headed arrow",
+    ),
+    ('\u{2B84}', "leftwards triangle-headed paired arrows"),
+    ('\u{2B85}', "upwards triangle-headed paired arrows"),
+    ('\u{2B86}', "rightwards triangle-headed paired arrows"),
+    ('\u{2B87}', "downwards triangle-headed paired arrows"),
+    ('\u{2B88}', "leftwards black circled white arrow"),
+    ('\u{2B89}', "upwards black circled white arrow"),
+    ('\u{2B8A}', "rightwards black circled white arrow"),
+    ('\u{2B8B}', "downwards black circled white arrow"),
+    (
+        '\u{2B8C}',
+        "anticlockwise triangle-headed right u-shaped arrow",
+    ),
+    (
+        '\u{2B8D}',
+        "anticlockwise triangle-headed bottom u-shaped arrow",
+    ),
+    (
+        '\u{2B8E}',
+        "anticlockwise triangle-headed left u-shaped arrow",
+    ),
+    (
+        '\u{2B8F}',
+        "anticlockwise triangle-headed top u-shaped arrow",
+    ),
+    ('\u{2B90}', "return left"),
+    ('\u{2B91}', "return right"),
+    ('\u{2B92}', "newline left"),
+    ('\u{2B93}', "newline right"),
+    ('\u{2B94}', "four corner arrows circling anticlockwise"),
+    ('\u{2B95}', "rightwards black arrow"),
+    ('\u{2B97}', "symbol for type a electronics"),
+    (
+        '\u{2B98}',
+        "three-d top-lighted leftwards equilateral arrowhead",
+    ),
+    (
+        '\u{2B99}',
+        "three-d right-lighted upwards equilateral arrowhead",
+    ),
+    (
+        '\u{2B9A}',
+        "three-d top-lighted rightwards equilateral arrowhead",
+    ),
+    (
+        '\u{2B9B}',
+        "three-d left-lighted downwards equilateral arrowhead",
+    ),
+    ('\u{2B9C}', "black leftwards equilateral arrowhead"),
+    ('\u{2B9D}', "black upwards equilateral arrowhead"),
+    ('\u{2B9E}', "black rightwards equilateral arrowhead"),
+    ('\u{2B9F}', "black downwards equilateral arrowhead"),
+    (
+        '\u{2BA0}',
+        "downwards triangle-headed arrow with long tip leftwards",
+    ),
+    (
+        '\u{2BA1}',
+        "downwards triangle-headed arrow with long tip rightwards",
+    ),
+    (
+        '\u{2BA2}',
+        "upwards triangle-headed arrow with long tip leftwards",
+    ),
+    (
+        '\u{2BA3}',
+        "upwards triangle-headed arrow with long tip rightwards",
+    ),
+    (
+        '\u{2BA4}',
+        "leftwards triangle-headed arrow with long tip upwards",
+    ),
+    (
+        '\u{2BA5}',
+        "rightwards triangle-headed arrow with long tip upwards",
+    ),
+    (
+        '\u{2BA6}',
+        "leftwards triangle-headed arrow with long tip downwards",
+    ),
+    (
+        '\u{2BA7}',
+        "rightwards triangle-headed arrow with long tip downwards",
+    ),
+    ('\u{2BA8}', "black curved downwards and leftwards arrow"),
+    ('\u{2BA9}', "black curved downwards and rightwards arrow"),
+    ('\u{2BAA}', "black curved upwards and leftwards arrow"),
+    ('\u{2BAB}', "black curved upwards and rightwards arrow"),
+    ('\u{2BAC}', "black curved leftwards and upwards arrow"),
+    ('\u{2BAD}', "black curved rightwards and upwards arrow"),
+    ('\u{2BAE}', "black curved leftwards and downwards arrow"),
+    ('\u{2BAF}', "black curved rightwards and downwards arrow"),
+    ('\u{2BB0}', "ribbon arrow down left"),
+    ('\u{2BB1}', "ribbon arrow down right"),
+    ('\u{2BB2}', "ribbon arrow up left"),
+    ('\u{2BB3}', "ribbon arrow up right"),
+    ('\u{2BB4}', "ribbon arrow left up"),
+    ('\u{2BB5}', "ribbon arrow right up"),
+    ('\u{2BB6}', "ribbon arrow left down"),
+    ('\u{2BB7}', "ribbon arrow right down"),
+    (
+        '\u{2BB8}',
+        "upwards white arrow from bar with horizontal bar",
+    ),
+    ('\u{2BB9}', "up arrowhead in a rectangle box"),
+    ('\u{2BBA}', "overlapping white squares"),
+    ('\u{2BBB}', "overlapping white and black squares"),
+    ('\u{2BBC}', "overlapping black squares"),
+    ('\u{2BBD}', "ballot box with light x"),
+    ('\u{2BBE}', "circled x"),
+    ('\u{2BBF}', "circled bold x"),
+    ('\u{2BC0}', "black square centred"),
+    ('\u{2BC1}', "black diamond centred"),
+    ('\u{2BC2}', "turned black pentagon"),
+    ('\u{2BC3}', "horizontal black octagon"),
+    ('\u{2BC4}', "black octagon"),
+    ('\u{2BC5}', "black medium up-pointing triangle centred"),
+    ('\u{2BC6}', "black medium down-pointing triangle centred"),
+    ('\u{2BC7}', "black medium left-pointing triangle centred"),
+    ('\u{2BC8}', "black medium right-pointing triangle centred"),
+    ('\u{2BC9}', "neptune form two"),
+    ('\u{2BCA}', "top half black circle"),
+    ('\u{2BCB}', "bottom half black circle"),
+    ('\u{2BCC}', "light four pointed black cusp"),
+    ('\u{2BCD}', "rotated light four pointed black cusp"),
+    ('\u{2BCE}', "white four pointed cusp"),
+    ('\u{2BCF}', "rotated white four pointed cusp"),
+    ('\u{2BD0}', "square position indicator"),
+    ('\u{2BD1}', "uncertainty sign"),
+    ('\u{2BD2}', "group mark"),
+    ('\u{2BD3}', "pluto form two"),
+    ('\u{2BD4}', "pluto form three"),
+    ('\u{2BD5}', "pluto form four"),
+    ('\u{2BD6}', "pluto form five"),
+    ('\u{2BD7}', "transpluto"),
+    ('\u{2BD8}', "proserpina"),
+    ('\u{2BD9}', "astraea"),
+    ('\u{2BDA}', "hygiea"),
+    ('\u{2BDB}', "pholus"),
+    ('\u{2BDC}', "nessus"),
+    ('\u{2BDD}', "white moon selena"),
+    ('\u{2BDE}', "black diamond on cross"),
+    ('\u{2BDF}', "true light moon arta"),
+    ('\u{2BE0}', "cupido"),
+    ('\u{2BE1}', "hades"),
+    ('\u{2BE2}', "zeus"),
+    ('\u{2BE3}', "kronos"),
+    ('\u{2BE4}', "apollon"),
+    ('\u{2BE5}', "admetos"),
+    ('\u{2BE6}', "vulcanus"),
+    ('\u{2BE7}', "poseidon"),
+    ('\u{2BE8}', "left half black star"),
+    ('\u{2BE9}', "right half black star"),
+    ('\u{2BEA}', "star with left half black"),
+    ('\u{2BEB}', "star with right half black"),
+    (
+        '\u{2BEC}',
+        "leftwards two-headed arrow with triangle arrowheads",
+    ),
+    (
+        '\u{2BED}',
+        "upwards two-headed arrow with triangle arrowheads",
+    ),
+    (
+        '\u{2BEE}',
+        "rightwards two-headed arrow with triangle arrowheads",
+    ),
+    (
+        '\u{2BEF}',
+        "downwards two-headed arrow with triangle arrowheads",
+    ),
+    ('\u{2BF0}', "eris form one"),
+    ('\u{2BF1}', "eris form two"),
+    ('\u{2BF2}', "sedna"),
+    ('\u{2BF3}', "russian astrological symbol vigintile"),
+    ('\u{2BF4}', "russian astrological symbol novile"),
+    ('\u{2BF5}', "russian astrological symbol quintile"),
+    ('\u{2BF6}', "russian astrological symbol binovile"),
+    ('\u{2BF7}', "russian astrological symbol sentagon"),
+    ('\u{2BF8}', "russian astrological symbol tredecile"),
+    ('\u{2BF9}', "equals sign with infinity below"),
+    ('\u{2BFA}', "united symbol"),
+    ('\u{2BFB}', "separated symbol"),
+    ('\u{2BFC}', "doubled symbol"),
+    ('\u{2BFD}', "passed symbol"),
+    ('\u{2BFE}', "reversed right angle"),
+    ('\u{2BFF}', "hellschreiber pause symbol"),
+    ('\u{1F1E6}', "regional indicator symbol letter a"),
+    ('\u{1F1E7}', "regional indicator symbol letter b"),
+    ('\u{1F1E8}', "regional indicator symbol letter c"),
+    ('\u{1F1E9}', "regional indicator symbol letter d"),
+    ('\u{1F1EA}', "regional indicator symbol letter e"),
+    ('\u{1F1EB}', "regional indicator symbol letter f"),
+    ('\u{1F1EC}', "regional indicator symbol letter g"),
+    ('\u{1F1ED}', "regional indicator symbol letter h"),
+    ('\u{1F1EE}', "regional indicator symbol letter i"),
+    ('\u{1F1EF}', "regional indicator symbol letter j"),
+    ('\u{1F1F0}', "regional indicator symbol letter k"),
+    ('\u{1F1F1}', "regional indicator symbol letter l"),
+    ('\u{1F1F2}', "regional indicator symbol letter m"),
+    ('\u{1F1F3}', "regional indicator symbol letter n"),
+    ('\u{1F1F4}', "regional indicator symbol letter o"),
+    ('\u{1F1F5}', "regional indicator symbol letter p"),
+    ('\u{1F1F6}', "regional indicator symbol letter q"),
+    ('\u{1F1F7}', "regional indicator symbol letter r"),
+    ('\u{1F1F8}', "regional indicator symbol letter s"),
+    ('\u{1F1F9}', "regional indicator symbol letter t"),
+    ('\u{1F1FA}', "regional indicator symbol letter u"),
+    ('\u{1F1FB}', "regional indicator symbol letter v"),
+    ('\u{1F1FC}', "regional indicator symbol letter w"),
+    ('\u{1F1FD}', "regional indicator symbol letter x"),
+    ('\u{1F1FE}', "regional indicator symbol letter y"),
+    ('\u{1F1FF}', "regional indicator symbol letter z"),
+    ('\u{1F300}', "cyclone"),
+    ('\u{1F301}', "foggy"),
+    ('\u{1F302}', "closed umbrella"),
+    ('\u{1F303}', "night with stars"),
+    ('\u{1F304}', "sunrise over mountains"),
+    ('\u{1F305}', "sunrise"),
+    ('\u{1F306}', "cityscape at dusk"),
+    ('\u{1F307}', "sunset over buildings"),
+    ('\u{1F308}', "rainbow"),
+    ('\u{1F309}', "bridge at night"),
+    ('\u{1F30A}', "water wave"),
+    ('\u{1F30B}', "volcano"),
+    ('\u{1F30C}', "milky way"),
+    ('\u{1F30D}', "earth globe europe-africa"),
+    ('\u{1F30E}', "earth globe americas"),
+    ('\u{1F30F}', "earth globe asia-australia"),
+    ('\u{1F310}', "globe with meridians"),
+    ('\u{1F311}', "new moon symbol"),
+    ('\u{1F312}', "waxing crescent moon symbol"),
+    ('\u{1F313}', "first quarter moon symbol"),
+    ('\u{1F314}', "waxing gibbous moon symbol"),
+    ('\u{1F315}', "full moon symbol"),
+    ('\u{1F316}', "waning gibbous moon symbol"),
+    ('\u{1F317}', "last quarter moon symbol"),
+    ('\u{1F318}', "waning crescent moon symbol"),
+    ('\u{1F319}', "crescent moon"),
+    ('\u{1F31A}', "new moon with face"),
+    ('\u{1F31B}', "first quarter moon with face"),
+    ('\u{1F31C}', "last quarter moon with face"),
+    ('\u{1F31D}', "full moon with face"),
+    ('\u{1F31E}', "sun with face"),
+    ('\u{1F31F}', "glowing star"),
+    ('\u{1F320}', "shooting star"),
+    ('\u{1F321}', "thermometer"),
+    ('\u{1F322}', "black droplet"),
+    ('\u{1F323}', "white sun"),
+    ('\u{1F324}', "white sun with small cloud"),
+    ('\u{1F325}', "white sun behind cloud"),
+    ('\u{1F326}', "white sun behind cloud with rain"),
+    ('\u{1F327}', "cloud with rain"),
+    ('\u{1F328}', "cloud with snow"),
+    ('\u{1F329}', "cloud with lightning"),
+    ('\u{1F32A}', "cloud with tornado"),
+    ('\u{1F32B}', "fog"),
+    ('\u{1F32C}', "wind blowing face"),
+    ('\u{1F32D}', "hot dog"),
+    ('\u{1F32E}', "taco"),
+    ('\u{1F32F}', "burrito"),
+    ('\u{1F330}', "chestnut"),
+    ('\u{1F331}', "seedling"),
+    ('\u{1F332}', "evergreen tree"),
+    ('\u{1F333}', "deciduous tree"),
+    ('\u{1F334}', "palm tree"),
+    ('\u{1F335}', "cactus"),
+    ('\u{1F336}', "hot pepper"),
+    ('\u{1F337}', "tulip"),
+    ('\u{1F338}', "cherry blossom"),
+    ('\u{1F339}', "rose"),
+    ('\u{1F33A}', "hibiscus"),
+    ('\u{1F33B}', "sunflower"),
+    ('\u{1F33C}', "blossom"),
+    ('\u{1F33D}', "ear of maize"),
+    ('\u{1F33E}', "ear of rice"),
+    ('\u{1F33F}', "herb"),
+    ('\u{1F340}', "four leaf clover"),
+    ('\u{1F341}', "maple leaf"),
+    ('\u{1F342}', "fallen leaf"),
+    ('\u{1F343}', "leaf fluttering in wind"),
+    ('\u{1F344}', "mushroom"),
+    ('\u{1F345}', "tomato"),
+    ('\u{1F346}', "aubergine"),
+    ('\u{1F347}', "grapes"),
+    ('\u{1F348}', "melon"),
+    ('\u{1F349}', "watermelon"),
+    ('\u{1F34A}', "tangerine"),
+    ('\u{1F34B}', "lemon"),
+    ('\u{1F34C}', "banana"),
+    ('\u{1F34D}', "pineapple"),
+    ('\u{1F34E}', "red apple"),
+    ('\u{1F34F}', "green apple"),
+    ('\u{1F350}', "pear"),
+    ('\u{1F351}', "peach"),
+    ('\u{1F352}', "cherries"),
+    ('\u{1F353}', "strawberry"),
+    ('\u{1F354}', "hamburger"),
+    ('\u{1F355}', "slice of pizza"),
+    ('\u{1F356}', "meat on bone"),
+    ('\u{1F357}', "poultry leg"),
+    ('\u{1F358}', "rice cracker"),
+    ('\u{1F359}', "rice ball"),
+    ('\u{1F35A}', "cooked rice"),
+    ('\u{1F35B}', "curry and rice"),
+    ('\u{1F35C}', "steaming bowl"),
+    ('\u{1F35D}', "spaghetti"),
+    ('\u{1F35E}', "bread"),
+    ('\u{1F35F}', "french fries"),
+    ('\u{1F360}', "roasted sweet potato"),
+    ('\u{1F361}', "dango"),
+    ('\u{1F362}', "oden"),
+    ('\u{1F363}', "sushi"),
+    ('\u{1F364}', "fried shrimp"),
+    ('\u{1F365}', "fish cake with swirl design"),
+    ('\u{1F366}', "soft ice cream"),
+    ('\u{1F367}', "shaved ice"),
+    ('\u{1F368}', "ice cream"),
+    ('\u{1F369}', "doughnut"),
+    ('\u{1F36A}', "cookie"),
+    ('\u{1F36B}', "chocolate bar"),
+    ('\u{1F36C}', "candy"),
+    ('\u{1F36D}', "lollipop"),
+    ('\u{1F36E}', "custard"),
+    ('\u{1F36F}', "honey pot"),
+    ('\u{1F370}', "shortcake"),
+    ('\u{1F371}', "bento box"),
+    ('\u{1F372}', "pot of food"),
+    ('\u{1F373}', "cooking"),
+    ('\u{1F374}', "fork and knife"),
+    ('\u{1F375}', "teacup without handle"),
+    ('\u{1F376}', "sake bottle and cup"),
+    ('\u{1F377}', "wine glass"),
+    ('\u{1F378}', "cocktail glass"),
+    ('\u{1F379}', "tropical drink"),
+    ('\u{1F37A}', "beer mug"),
+    ('\u{1F37B}', "clinking beer mugs"),
+    ('\u{1F37C}', "baby bottle"),
+    ('\u{1F37D}', "fork and knife with plate"),
+    ('\u{1F37E}', "bottle with popping cork"),
+    ('\u{1F37F}', "popcorn"),
+    ('\u{1F380}', "ribbon"),
+    ('\u{1F381}', "wrapped present"),
+    ('\u{1F382}', "birthday cake"),
+    ('\u{1F383}', "jack-o-lantern"),
+    ('\u{1F384}', "christmas tree"),
+    ('\u{1F385}', "father christmas"),
+    ('\u{1F386}', "fireworks"),
+    ('\u{1F387}', "firework sparkler"),
+    ('\u{1F388}', "balloon"),
+    ('\u{1F389}', "party popper"),
+    ('\u{1F38A}', "confetti ball"),
+    ('\u{1F38B}', "tanabata tree"),
+    ('\u{1F38C}', "crossed flags"),
+    ('\u{1F38D}', "pine decoration"),
+    ('\u{1F38E}', "japanese dolls"),
+    ('\u{1F38F}', "carp streamer"),
+    ('\u{1F390}', "wind chime"),
+    ('\u{1F391}', "moon viewing ceremony"),
+    ('\u{1F392}', "school satchel"),
+    ('\u{1F393}', "graduation cap"),
+    ('\u{1F394}', "heart with tip on the left"),
+    ('\u{1F395}', "bouquet of flowers"),
+    ('\u{1F396}', "military medal"),
+    ('\u{1F397}', "reminder ribbon"),
+    ('\u{1F398}', "musical keyboard with jacks"),
+    ('\u{1F399}', "studio microphone"),
+    ('\u{1F39A}', "level slider"),
+    ('\u{1F39B}', "control knobs"),
+    ('\u{1F39C}', "beamed ascending musical notes"),
+    ('\u{1F39D}', "beamed descending musical notes"),
+    ('\u{1F39E}', "film frames"),
+    ('\u{1F39F}', "admission tickets"),
+    ('\u{1F3A0}', "carousel horse"),
+    ('\u{1F3A1}', "ferris wheel"),
+    ('\u{1F3A2}', "roller coaster"),
+    ('\u{1F3A3}', "fishing pole and fish"),
+    ('\u{1F3A4}', "microphone"),
+    ('\u{1F3A5}', "movie camera"),
+    ('\u{1F3A6}', "cinema"),
+    ('\u{1F3A7}', "headphone"),
+    ('\u{1F3A8}', "artist palette"),
+    ('\u{1F3A9}', "top hat"),
+    ('\u{1F3AA}', "circus tent"),
+    ('\u{1F3AB}', "ticket"),
+    ('\u{1F3AC}', "clapper board"),
+    ('\u{1F3AD}', "performing arts"),
+    ('\u{1F3AE}', "video game"),
+    ('\u{1F3AF}', "direct hit"),
+    ('\u{1F3B0}', "slot machine"),
+    ('\u{1F3B1}', "billiards"),
+    ('\u{1F3B2}', "game die"),
+    ('\u{1F3B3}', "bowling"),
+    ('\u{1F3B4}', "flower playing cards"),
+    ('\u{1F3B5}', "musical note"),
+    ('\u{1F3B6}', "multiple musical notes"),
+    ('\u{1F3B7}', "saxophone"),
+    ('\u{1F3B8}', "guitar"),
+    ('\u{1F3B9}', "musical keyboard"),
+    ('\u{1F3BA}', "trumpet"),
+    ('\u{1F3BB}', "violin"),
+    ('\u{1F3BC}', "musical score"),
+    ('\u{1F3BD}', "running shirt with sash"),
+    ('\u{1F3BE}', "tennis racquet and ball"),
+    ('\u{1F3BF}', "ski and ski boot"),
+    ('\u{1F3C0}', "basketball and hoop"),
+    ('\u{1F3C1}', "chequered flag"),
+    ('\u{1F3C2}', "snowboarder"),
+    ('\u{1F3C3}', "runner"),
+    ('\u{1F3C4}', "surfer"),
+    ('\u{1F3C5}', "sports medal"),
+    ('\u{1F3C6}', "trophy"),
+    ('\u{1F3C7}', "horse racing"),
+    ('\u{1F3C8}', "american football"),
+    ('\u{1F3C9}', "rugby football"),
+    ('\u{1F3CA}', "swimmer"),
+    ('\u{1F3CB}', "weight lifter"),
+    ('\u{1F3CC}', "golfer"),
+    ('\u{1F3CD}', "racing motorcycle"),
+    ('\u{1F3CE}', "racing car"),
+    ('\u{1F3CF}', "cricket bat and ball"),
+    ('\u{1F3D0}', "volleyball"),
+    ('\u{1F3D1}', "field hockey stick and ball"),
+    ('\u{1F3D2}', "ice hockey stick and puck"),
+    ('\u{1F3D3}', "table tennis paddle and ball"),
+    ('\u{1F3D4}', "snow capped mountain"),
+    ('\u{1F3D5}', "camping"),
+    ('\u{1F3D6}', "beach with umbrella"),
+    ('\u{1F3D7}', "building construction"),
+    ('\u{1F3D8}', "house buildings"),
+    ('\u{1F3D9}', "cityscape"),
+    ('\u{1F3DA}', "derelict house building"),
+    ('\u{1F3DB}', "classical building"),
+    ('\u{1F3DC}', "desert"),
+    ('\u{1F3DD}', "desert island"),
+    ('\u{1F3DE}', "national park"),
+    ('\u{1F3DF}', "stadium"),
+    ('\u{1F3E0}', "house building"),
+    ('\u{1F3E1}', "house with garden"),
+    ('\u{1F3E2}', "office building"),
+    ('\u{1F3E3}', "japanese post office"),
+    ('\u{1F3E4}', "european post office"),
+    ('\u{1F3E5}', "hospital"),
+    ('\u{1F3E6}', "bank"),
+    ('\u{1F3E7}', "automated teller machine"),
+    ('\u{1F3E8}', "hotel"),
+    ('\u{1F3E9}', "love hotel"),
+    ('\u{1F3EA}', "convenience store"),
+    ('\u{1F3EB}', "school"),
+    ('\u{1F3EC}', "department store"),
+    ('\u{1F3ED}', "factory"),
+    ('\u{1F3EE}', "izakaya lantern"),
+    ('\u{1F3EF}', "japanese castle"),
+    ('\u{1F3F0}', "european castle"),
+    ('\u{1F3F1}', "white pennant"),
+    ('\u{1F3F2}', "black pennant"),
+    ('\u{1F3F3}', "waving white flag"),
+    ('\u{1F3F4}', "waving black flag"),
+    ('\u{1F3F5}', "rosette"),
+    ('\u{1F3F6}', "black rosette"),
+    ('\u{1F3F7}', "label"),
+    ('\u{1F3F8}', "badminton racquet and shuttlecock"),
+    ('\u{1F3F9}', "bow and arrow"),
+    ('\u{1F3FA}', "amphora"),
+    ('\u{1F3FB}', "emoji modifier fitzpatrick type-1-2"),
+    ('\u{1F3FC}', "emoji modifier fitzpatrick type-3"),
+    ('\u{1F3FD}', "emoji modifier fitzpatrick type-4"),
+    ('\u{1F3FE}', "emoji modifier fitzpatrick type-5"),
+    ('\u{1F3FF}', "emoji modifier fitzpatrick type-6"),
+    ('\u{1F400}', "rat"),
+    ('\u{1F401}', "mouse"),
+    ('\u{1F402}', "ox"),
+    ('\u{1F403}', "water buffalo"),
+    ('\u{1F404}', "cow"),
+    ('\u{1F405}', "tiger"),
+    ('\u{1F406}', "leopard"),
+    ('\u{1F407}', "rabbit"),
+    ('\u{1F408}', "cat"),
+    ('\u{1F409}', "dragon"),
+    ('\u{1F40A}', "crocodile"),
+    ('\u{1F40B}', "whale"),
+    ('\u{1F40C}', "snail"),
+    ('\u{1F40D}', "snake"),
+    ('\u{1F40E}', "horse"),
+    ('\u{1F40F}', "ram"),
+    ('\u{1F410}', "goat"),
+    ('\u{1F411}', "sheep"),
+    ('\u{1F412}', "monkey"),
+    ('\u{1F413}', "rooster"),
+    ('\u{1F414}', "chicken"),
+    ('\u{1F415}', "dog"),
+    ('\u{1F416}', "pig"),
+    ('\u{1F417}', "boar"),
+    ('\u{1F418}', "elephant"),
+    ('\u{1F419}', "octopus"),
+    ('\u{1F41A}', "spiral shell"),
+    ('\u{1F41B}', "bug"),
+    ('\u{1F41C}', "ant"),
+    ('\u{1F41D}', "honeybee"),
+    ('\u{1F41E}', "lady beetle"),
+    ('\u{1F41F}', "fish"),
+    ('\u{1F420}', "tropical fish"),
+    ('\u{1F421}', "blowfish"),
+    ('\u{1F422}', "turtle"),
+    ('\u{1F423}', "hatching chick"),
+    ('\u{1F424}', "baby chick"),
+    ('\u{1F425}', "front-facing baby chick"),
+    ('\u{1F426}', "bird"),
+    ('\u{1F427}', "penguin"),
+    ('\u{1F428}', "koala"),
+    ('\u{1F429}', "poodle"),
+    ('\u{1F42A}', "dromedary camel"),
+    ('\u{1F42B}', "bactrian camel"),
+    ('\u{1F42C}', "dolphin"),
+    ('\u{1F42D}', "mouse face"),
+    ('\u{1F42E}', "cow face"),
+    ('\u{1F42F}', "tiger face"),
+    ('\u{1F430}', "rabbit face"),
+    ('\u{1F431}', "cat face"),
+    ('\u{1F432}', "dragon face"),
+    ('\u{1F433}', "spouting whale"),
+    ('\u{1F434}', "horse face"),
+    ('\u{1F435}', "monkey face"),
+    ('\u{1F436}', "dog face"),
+    ('\u{1F437}', "pig face"),
+    ('\u{1F438}', "frog face"),
+    ('\u{1F439}', "hamster face"),
+    ('\u{1F43A}', "wolf face"),
+    ('\u{1F43B}', "bear face"),
+    ('\u{1F43C}', "panda face"),
+    ('\u{1F43D}', "pig nose"),
+    ('\u{1F43E}', "paw prints"),
+    ('\u{1F43F}', "chipmunk"),
+    ('\u{1F440}', "eyes"),
+    ('\u{1F441}', "eye"),
+    ('\u{1F442}', "ear"),
+    ('\u{1F443}', "nose"),
+    ('\u{1F444}', "mouth"),
+    ('\u{1F445}', "tongue"),
+    ('\u{1F446}', "white up pointing backhand index"),
+    ('\u{1F447}', "white down pointing backhand index"),
+    ('\u{1F448}', "white left pointing backhand index"),
+    ('\u{1F449}', "white right pointing backhand index"),
+    ('\u{1F44A}', "fisted hand sign"),
+    ('\u{1F44B}', "waving hand sign"),
+    ('\u{1F44C}', "ok hand sign"),
+    ('\u{1F44D}', "thumbs up sign"),
+    ('\u{1F44E}', "thumbs down sign"),
+    ('\u{1F44F}', "clapping hands sign"),
+    ('\u{1F450}', "open hands sign"),
+    ('\u{1F451}', "crown"),
+    ('\u{1F452}', "womans hat"),
+    ('\u{1F453}', "eyeglasses"),
+    ('\u{1F454}', "necktie"),
+    ('\u{1F455}', "t-shirt"),
+    ('\u{1F456}', "jeans"),
+    ('\u{1F457}', "dress"),
+    ('\u{1F458}', "kimono"),
+    ('\u{1F459}', "bikini"),
+    ('\u{1F45A}', "womans clothes"),
+    ('\u{1F45B}', "purse"),
+    ('\u{1F45C}', "handbag"),
+    ('\u{1F45D}', "pouch"),
+    ('\u{1F45E}', "mans shoe"),
+    ('\u{1F45F}', "athletic shoe"),
+    ('\u{1F460}', "high-heeled shoe"),
+    ('\u{1F461}', "womans sandal"),
+    ('\u{1F462}', "womans boots"),
+    ('\u{1F463}', "footprints"),
+    ('\u{1F464}', "bust in silhouette"),
+    ('\u{1F465}', "busts in silhouette"),
+    ('\u{1F466}', "boy"),
+    ('\u{1F467}', "girl"),
+    ('\u{1F468}', "man"),
+    ('\u{1F469}', "woman"),
+    ('\u{1F46A}', "family"),
+    ('\u{1F46B}', "man and woman holding hands"),
+    ('\u{1F46C}', "two men holding hands"),
+    ('\u{1F46D}', "two women holding hands"),
+    ('\u{1F46E}', "police officer"),
+    ('\u{1F46F}', "woman with bunny ears"),
+    ('\u{1F470}', "bride with veil"),
+    ('\u{1F471}', "person with blond hair"),
+    ('\u{1F472}', "man with gua pi mao"),
+    ('\u{1F473}', "man with turban"),
+    ('\u{1F474}', "older man"),
+    ('\u{1F475}', "older woman"),
+    ('\u{1F476}', "baby"),
+    ('\u{1F477}', "construction worker"),
+    ('\u{1F478}', "princess"),
+    ('\u{1F479}', "japanese ogre"),
+    ('\u{1F47A}', "japanese goblin"),
+    ('\u{1F47B}', "ghost"),
+    ('\u{1F47C}', "baby angel"),
+    ('\u{1F47D}', "extraterrestrial alien"),
+    ('\u{1F47E}', "alien monster"),
+    ('\u{1F47F}', "imp"),
+    ('\u{1F480}', "skull"),
+    ('\u{1F481}', "information desk person"),
+    ('\u{1F482}', "guardsman"),
+    ('\u{1F483}', "dancer"),
+    ('\u{1F484}', "lipstick"),
+    ('\u{1F485}', "nail polish"),
+    ('\u{1F486}', "face massage"),
+    ('\u{1F487}', "haircut"),
+    ('\u{1F488}', "barber pole"),
+    ('\u{1F489}', "syringe"),
+    ('\u{1F48A}', "pill"),
+    ('\u{1F48B}', "kiss mark"),
+    ('\u{1F48C}', "love letter"),
+    ('\u{1F48D}', "ring"),
+    ('\u{1F48E}', "gem stone"),
+    ('\u{1F48F}', "kiss"),
+    ('\u{1F490}', "bouquet"),
+    ('\u{1F491}', "couple with heart"),
+    ('\u{1F492}', "wedding"),
+    ('\u{1F493}', "beating heart"),
+    ('\u{1F494}', "broken heart"),
+    ('\u{1F495}', "two hearts"),
+    ('\u{1F496}', "sparkling heart"),
+    ('\u{1F497}', "growing heart"),
+    ('\u{1F498}', "heart with arrow"),
+    ('\u{1F499}', "blue heart"),
+    ('\u{1F49A}', "green heart"),
+    ('\u{1F49B}', "yellow heart"),
+    ('\u{1F49C}', "purple heart"),
+    ('\u{1F49D}', "heart with ribbon"),
+    ('\u{1F49E}', "revolving hearts"),
+    ('\u{1F49F}', "heart decoration"),
+    ('\u{1F4A0}', "diamond shape with a dot inside"),
+    ('\u{1F4A1}', "electric light bulb"),
+    ('\u{1F4A2}', "anger symbol"),
+    ('\u{1F4A3}', "bomb"),
+    ('\u{1F4A4}', "sleeping symbol"),
+    ('\u{1F4A5}', "collision symbol"),
+    ('\u{1F4A6}', "splashing sweat symbol"),
+    ('\u{1F4A7}', "droplet"),
+    ('\u{1F4A8}', "dash symbol"),
+    ('\u{1F4A9}', "pile of poo"),
+    ('\u{1F4AA}', "flexed biceps"),
+    ('\u{1F4AB}', "dizzy symbol"),
+    ('\u{1F4AC}', "speech balloon"),
+    ('\u{1F4AD}', "thought balloon"),
+    ('\u{1F4AE}', "white flower"),
+    ('\u{1F4AF}', "hundred points symbol"),
+    ('\u{1F4B0}', "money bag"),
+    ('\u{1F4B1}', "currency exchange"),
+    ('\u{1F4B2}', "heavy dollar sign"),
+    ('\u{1F4B3}', "credit card"),
+    ('\u{1F4B4}', "banknote with yen sign"),
+    ('\u{1F4B5}', "banknote with dollar sign"),
+    ('\u{1F4B6}', "banknote with euro sign"),
+    ('\u{1F4B7}', "banknote with pound sign"),
+    ('\u{1F4B8}', "money with wings"),
+    ('\u{1F4B9}', "chart with upwards trend and yen sign"),
+    ('\u{1F4BA}', "seat"),
+    ('\u{1F4BB}', "personal computer"),
+    ('\u{1F4BC}', "briefcase"),
+    ('\u{1F4BD}', "minidisc"),
+    ('\u{1F4BE}', "floppy disk"),
+    ('\u{1F4BF}', "optical disc"),
+    ('\u{1F4C0}', "dvd"),
+    ('\u{1F4C1}', "file folder"),
+    ('\u{1F4C2}', "open file folder"),
+    ('\u{1F4C3}', "page with curl"),
+    ('\u{1F4C4}', "page facing up"),
+    ('\u{1F4C5}', "calendar"),
+    ('\u{1F4C6}', "tear-off calendar"),
+    ('\u{1F4C7}', "card index"),
+    ('\u{1F4C8}', "chart with upwards trend"),
+    ('\u{1F4C9}', "chart with downwards trend"),
+    ('\u{1F4CA}', "bar chart"),
+    ('\u{1F4CB}', "clipboard"),
+    ('\u{1F4CC}', "pushpin"),
+    ('\u{1F4CD}', "round pushpin"),
+    ('\u{1F4CE}', "paperclip"),
+    ('\u{1F4CF}', "straight ruler"),
+    ('\u{1F4D0}', "triangular ruler"),
+    ('\u{1F4D1}', "bookmark tabs"),
+    ('\u{1F4D2}', "ledger"),
+    ('\u{1F4D3}', "notebook"),
+    ('\u{1F4D4}', "notebook with decorative cover"),
+    ('\u{1F4D5}', "closed book"),
+    ('\u{1F4D6}', "open book"),
+    ('\u{1F4D7}', "green book"),
+    ('\u{1F4D8}', "blue book"),
+    ('\u{1F4D9}', "orange book"),
+    ('\u{1F4DA}', "books"),
+    ('\u{1F4DB}', "name badge"),
+    ('\u{1F4DC}', "scroll"),
+    ('\u{1F4DD}', "memo"),
+    ('\u{1F4DE}', "telephone receiver"),
+    ('\u{1F4DF}', "pager"),
+    ('\u{1F4E0}', "fax machine"),
+    ('\u{1F4E1}', "satellite antenna"),
+    ('\u{1F4E2}', "public address loudspeaker"),
+    ('\u{1F4E3}', "cheering megaphone"),
+    ('\u{1F4E4}', "outbox tray"),
+    ('\u{1F4E5}', "inbox tray"),
+    ('\u{1F4E6}', "package"),
+    ('\u{1F4E7}', "e-mail symbol"),
+    ('\u{1F4E8}', "incoming envelope"),
+    ('\u{1F4E9}', "envelope with downwards arrow above"),
+    ('\u{1F4EA}', "closed mailbox with lowered flag"),
+    ('\u{1F4EB}', "closed mailbox with raised flag"),
+    ('\u{1F4EC}', "open mailbox with raised flag"),
+    ('\u{1F4ED}', "open mailbox with lowered flag"),
+    ('\u{1F4EE}', "postbox"),
+    ('\u{1F4EF}', "postal horn"),
+    ('\u{1F4F0}', "newspaper"),
+    ('\u{1F4F1}', "mobile phone"),
+    ('\u{1F4F2}', "mobile phone with rightwards arrow at left"),
+    ('\u{1F4F3}', "vibration mode"),
+    ('\u{1F4F4}', "mobile phone off"),
+    ('\u{1F4F5}', "no mobile phones"),
+    ('\u{1F4F6}', "antenna with bars"),
+    ('\u{1F4F7}', "camera"),
+    ('\u{1F4F8}', "camera with flash"),
+    ('\u{1F4F9}', "video camera"),
+    ('\u{1F4FA}', "television"),
+    ('\u{1F4FB}', "radio"),
+    ('\u{1F4FC}', "videocassette"),
+    ('\u{1F4FD}', "film projector"),
+    ('\u{1F4FE}', "portable stereo"),
+    ('\u{1F4FF}', "prayer beads"),
+    ('\u{1F500}', "twisted rightwards arrows"),
+    (
+        '\u{1F501}',
+        "clockwise rightwards and leftwards open circle arrows",
+    ),
+    (
+        '\u{1F502}',
+        "clockwise rightwards and leftwards open circle arrows with circled one overlay",
+    ),
+    (
+        '\u{1F503}',
+        "clockwise downwards and upwards open circle arrows",
+    ),
+    (
+        '\u{1F504}',
+        "anticlockwise downwards and upwards open circle arrows",
+    ),
+    ('\u{1F505}', "low brightness symbol"),
+    ('\u{1F506}', "high brightness symbol"),
+    ('\u{1F507}', "speaker with cancellation stroke"),
+    ('\u{1F508}', "speaker"),
+    ('\u{1F509}', "speaker with one sound wave"),
+    ('\u{1F50A}', "speaker with three sound waves"),
+    ('\u{1F50B}', "battery"),
+    ('\u{1F50C}', "electric plug"),
+    ('\u{1F50D}', "left-pointing magnifying glass"),
+    ('\u{1F50E}', "right-pointing magnifying glass"),
+    ('\u{1F50F}', "lock with ink pen"),
+    ('\u{1F510}', "closed lock with key"),
+    ('\u{1F511}', "key"),
+    ('\u{1F512}', "lock"),
+    ('\u{1F513}', "open lock"),
+    ('\u{1F514}', "bell"),
+    ('\u{1F515}', "bell with cancellation stroke"),
+    ('\u{1F516}', "bookmark"),
+    ('\u{1F517}', "link symbol"),
+    ('\u{1F518}', "radio button"),
+    ('\u{1F519}', "back with leftwards arrow above"),
+    ('\u{1F51A}', "end with leftwards arrow above"),
+    (
+        '\u{1F51B}',
+        "on with exclamation mark with left right arrow above",
+    ),
+    ('\u{1F51C}', "soon with rightwards arrow above"),
+    ('\u{1F51D}', "top with upwards arrow above"),
+    ('\u{1F51E}', "no one under eighteen symbol"),
+    ('\u{1F51F}', "keycap ten"),
+    ('\u{1F520}', "input symbol for latin capital letters"),
+    ('\u{1F521}', "input symbol for latin small letters"),
+    ('\u{1F522}', "input symbol for numbers"),
+    ('\u{1F523}', "input symbol for symbols"),
+    ('\u{1F524}', "input symbol for latin letters"),
+    ('\u{1F525}', "fire"),
+    ('\u{1F526}', "electric torch"),
+    ('\u{1F527}', "wrench"),
+    ('\u{1F528}', "hammer"),
+    ('\u{1F529}', "nut and bolt"),
+    ('\u{1F52A}', "hocho"),
+    ('\u{1F52B}', "pistol"),
+    ('\u{1F52C}', "microscope"),
+    ('\u{1F52D}', "telescope"),
+    ('\u{1F52E}', "crystal ball"),
+    ('\u{1F52F}', "six pointed star with middle dot"),
+    ('\u{1F530}', "japanese symbol for beginner"),
+    ('\u{1F531}', "trident emblem"),
+    ('\u{1F532}', "black square button"),
+    ('\u{1F533}', "white square button"),
+    ('\u{1F534}', "large red circle"),
+    ('\u{1F535}', "large blue circle"),
+    ('\u{1F536}', "large orange diamond"),
+    ('\u{1F537}', "large blue diamond"),
+    ('\u{1F538}', "small orange diamond"),
+    ('\u{1F539}', "small blue diamond"),
+    ('\u{1F53A}', "up-pointing red triangle"),
+    ('\u{1F53B}', "down-pointing red triangle"),
+    ('\u{1F53C}', "up-pointing small red triangle"),
+    ('\u{1F53D}', "down-pointing small red triangle"),
+    ('\u{1F53E}', "lower right shadowed white circle"),
+    ('\u{1F53F}', "upper right shadowed white circle"),
+    ('\u{1F540}', "circled cross pommee"),
+    ('\u{1F541}', "cross pommee with half-circle below"),
+    ('\u{1F542}', "cross pommee"),
+    ('\u{1F543}', "notched left semicircle with three dots"),
+    ('\u{1F544}', "notched right semicircle with three dots"),
+    ('\u{1F545}', "symbol for marks chapter"),
+    ('\u{1F546}', "white latin cross"),
+    ('\u{1F547}', "heavy latin cross"),
+    ('\u{1F548}', "celtic cross"),
+    ('\u{1F549}', "om symbol"),
+    ('\u{1F54A}', "dove of peace"),
+    ('\u{1F54B}', "kaaba"),
+    ('\u{1F54C}', "mosque"),
+    ('\u{1F54D}', "synagogue"),
+    ('\u{1F54E}', "menorah with nine branches"),
+    ('\u{1F54F}', "bowl of hygieia"),
+    ('\u{1F550}', "clock face one oclock"),
+    ('\u{1F551}', "clock face two oclock"),
+    ('\u{1F552}', "clock face three oclock"),
+    ('\u{1F553}', "clock face four oclock"),
+    ('\u{1F554}', "clock face five oclock"),
+    ('\u{1F555}', "clock face six oclock"),
+    ('\u{1F556}', "clock face seven oclock"),
+    ('\u{1F557}', "clock face eight oclock"),
+    ('\u{1F558}', "clock face nine oclock"),
+    ('\u{1F559}', "clock face ten oclock"),
+    ('\u{1F55A}', "clock face eleven oclock"),
+    ('\u{1F55B}', "clock face twelve oclock"),
+    ('\u{1F55C}', "clock face one-thirty"),
+    ('\u{1F55D}', "clock face two-thirty"),
+    ('\u{1F55E}', "clock face three-thirty"),
+    ('\u{1F55F}', "clock face four-thirty"),
+    ('\u{1F560}', "clock face five-thirty"),
+    ('\u{1F561}', "clock face six-thirty"),
+    ('\u{1F562}', "clock face seven-thirty"),
+    ('\u{1F563}', "clock face eight-thirty"),
+    ('\u{1F564}', "clock face nine-thirty"),
+    ('\u{1F565}', "clock face ten-thirty"),
+    ('\u{1F566}', "clock face eleven-thirty"),
+    ('\u{1F567}', "clock face twelve-thirty"),
+    ('\u{1F568}', "right speaker"),
+    ('\u{1F569}', "right speaker with one sound wave"),
+    ('\u{1F56A}', "right speaker with three sound waves"),
+    ('\u{1F56B}', "bullhorn"),
+    ('\u{1F56C}', "bullhorn with sound waves"),
+    ('\u{1F56D}', "ringing bell"),
+    ('\u{1F56E}', "book"),
+    ('\u{1F56F}', "candle"),
+    ('\u{1F570}', "mantelpiece clock"),
+    ('\u{1F571}', "black skull and crossbones"),
+    ('\u{1F572}', "no piracy"),
+    ('\u{1F573}', "hole"),
+    ('\u{1F574}', "man in business suit levitating"),
+    ('\u{1F575}', "sleuth or spy"),
+    ('\u{1F576}', "dark sunglasses"),
+    ('\u{1F577}', "spider"),
+    ('\u{1F578}', "spider web"),
+    ('\u{1F579}', "joystick"),
+    ('\u{1F57A}', "man dancing"),
+    ('\u{1F57B}', "left hand telephone receiver"),
+    ('\u{1F57C}', "telephone receiver with page"),
+    ('\u{1F57D}', "right hand telephone receiver"),
+    ('\u{1F57E}', "white touchtone telephone"),
+    ('\u{1F57F}', "black touchtone telephone"),
+    ('\u{1F580}', "telephone on top of modem"),
+    ('\u{1F581}', "clamshell mobile phone"),
+    ('\u{1F582}', "back of envelope"),
+    ('\u{1F583}', "stamped envelope"),
+    ('\u{1F584}', "envelope with lightning"),
+    ('\u{1F585}', "flying envelope"),
+    ('\u{1F586}', "pen over stamped envelope"),
+    ('\u{1F587}', "linked paperclips"),
+    ('\u{1F588}', "black pushpin"),
+    ('\u{1F589}', "lower left pencil"),
+    ('\u{1F58A}', "lower left ballpoint pen"),
+    ('\u{1F58B}', "lower left fountain pen"),
+    ('\u{1F58C}', "lower left paintbrush"),
+    ('\u{1F58D}', "lower left crayon"),
+    ('\u{1F58E}', "left writing hand"),
+    ('\u{1F58F}', "turned ok hand sign"),
+    ('\u{1F590}', "raised hand with fingers splayed"),
+    ('\u{1F591}', "reversed raised hand with fingers splayed"),
+    ('\u{1F592}', "reversed thumbs up sign"),
+    ('\u{1F593}', "reversed thumbs down sign"),
+    ('\u{1F594}', "reversed victory hand"),
+    ('\u{1F595}', "reversed hand with middle finger extended"),
+    (
+        '\u{1F596}',
+        "raised hand with part between middle and ring fingers",
+    ),
+    ('\u{1F597}', "white down pointing left hand index"),
+    ('\u{1F598}', "sideways white left pointing index"),
+    ('\u{1F599}', "sideways white right pointing index"),
+    ('\u{1F59A}', "sideways black left pointing index"),
+    ('\u{1F59B}', "sideways black right pointing index"),
+    ('\u{1F59C}', "black left pointing backhand index"),
+    ('\u{1F59D}', "black right pointing backhand index"),
+    ('\u{1F59E}', "sideways white up pointing index"),
+    ('\u{1F59F}', "sideways white down pointing index"),
+    ('\u{1F5A0}', "sideways black up pointing index"),
+    ('\u{1F5A1}', "sideways black down pointing index"),
+    ('\u{1F5A2}', "black up pointing backhand index"),
+    ('\u{1F5A3}', "black down pointing backhand index"),
+    ('\u{1F5A4}', "black heart"),
+    ('\u{1F5A5}', "desktop computer"),
+    ('\u{1F5A6}', "keyboard and mouse"),
+    ('\u{1F5A7}', "three networked computers"),
+    ('\u{1F5A8}', "printer"),
+    ('\u{1F5A9}', "pocket calculator"),
+    ('\u{1F5AA}', "black hard shell floppy disk"),
+    ('\u{1F5AB}', "white hard shell floppy disk"),
+    ('\u{1F5AC}', "soft shell floppy disk"),
+    ('\u{1F5AD}', "tape cartridge"),
+    ('\u{1F5AE}', "wired keyboard"),
+    ('\u{1F5AF}', "one button mouse"),
+    ('\u{1F5B0}', "two button mouse"),
+    ('\u{1F5B1}', "three button mouse"),
+    ('\u{1F5B2}', "trackball"),
+    ('\u{1F5B3}', "old personal computer"),
+    ('\u{1F5B4}', "hard disk"),
+    ('\u{1F5B5}', "screen"),
+    ('\u{1F5B6}', "printer icon"),
+    ('\u{1F5B7}', "fax icon"),
+    ('\u{1F5B8}', "optical disc icon"),
+    ('\u{1F5B9}', "document with text"),
+    ('\u{1F5BA}', "document with text and picture"),
+    ('\u{1F5BB}', "document with picture"),
+    ('\u{1F5BC}', "frame with picture"),
+    ('\u{1F5BD}', "frame with tiles"),
+    ('\u{1F5BE}', "frame with an x"),
+    ('\u{1F5BF}', "black folder"),
+    ('\u{1F5C0}', "folder"),
+    ('\u{1F5C1}', "open folder"),
+    ('\u{1F5C2}', "card index dividers"),
+    ('\u{1F5C3}', "card file box"),
+    ('\u{1F5C4}', "file cabinet"),
+    ('\u{1F5C5}', "empty note"),
+    ('\u{1F5C6}', "empty note page"),
+    ('\u{1F5C7}', "empty note pad"),
+    ('\u{1F5C8}', "note"),
+    ('\u{1F5C9}', "note page"),
+    ('\u{1F5CA}', "note pad"),
+    ('\u{1F5CB}', "empty document"),
+    ('\u{1F5CC}', "empty page"),
+    ('\u{1F5CD}', "empty pages"),
+    ('\u{1F5CE}', "document"),
+    ('\u{1F5CF}', "page"),
+    ('\u{1F5D0}', "pages"),
+    ('\u{1F5D1}', "wastebasket"),
+    ('\u{1F5D2}', "spiral note pad"),
+    ('\u{1F5D3}', "spiral calendar pad"),
+    ('\u{1F5D4}', "desktop window"),
+    ('\u{1F5D5}', "minimize"),
+    ('\u{1F5D6}', "maximize"),
+    ('\u{1F5D7}', "overlap"),
+    ('\u{1F5D8}', "clockwise right and left semicircle arrows"),
+    ('\u{1F5D9}', "cancellation x"),
+    ('\u{1F5DA}', "increase font size symbol"),
+    ('\u{1F5DB}', "decrease font size symbol"),
+    ('\u{1F5DC}', "compression"),
+    ('\u{1F5DD}', "old key"),
+    ('\u{1F5DE}', "rolled-up newspaper"),
+    ('\u{1F5DF}', "page with circled text"),
+    ('\u{1F5E0}', "stock chart"),
+    ('\u{1F5E1}', "dagger knife"),
+    ('\u{1F5E2}', "lips"),
+    ('\u{1F5E3}', "speaking head in silhouette"),
+    ('\u{1F5E4}', "three rays above"),
+    ('\u{1F5E5}', "three rays below"),
+    ('\u{1F5E6}', "three rays left"),
+    ('\u{1F5E7}', "three rays right"),
+    ('\u{1F5E8}', "left speech bubble"),
+    ('\u{1F5E9}', "right speech bubble"),
+    ('\u{1F5EA}', "two speech bubbles"),
+    ('\u{1F5EB}', "three speech bubbles"),
+    ('\u{1F5EC}', "left thought bubble"),
+    ('\u{1F5ED}', "right thought bubble"),
+    ('\u{1F5EE}', "left anger bubble"),
+    ('\u{1F5EF}', "right anger bubble"),
+    ('\u{1F5F0}', "mood bubble"),
+    ('\u{1F5F1}', "lightning mood bubble"),
+    ('\u{1F5F2}', "lightning mood"),
+    ('\u{1F5F3}', "ballot box with ballot"),
+    ('\u{1F5F4}', "ballot script x"),
+    ('\u{1F5F5}', "ballot box with script x"),
+    ('\u{1F5F6}', "ballot bold script x"),
+    ('\u{1F5F7}', "ballot box with bold script x"),
+    ('\u{1F5F8}', "light check mark"),
+    ('\u{1F5F9}', "ballot box with bold check"),
+    ('\u{1F5FA}', "world map"),
+    ('\u{1F5FB}', "mount fuji"),
+    ('\u{1F5FC}', "tokyo tower"),
+    ('\u{1F5FD}', "statue of liberty"),
+    ('\u{1F5FE}', "silhouette of japan"),
+    ('\u{1F5FF}', "moyai"),
+    ('\u{1F600}', "grinning face"),
+    ('\u{1F601}', "grinning face with smiling eyes"),
+    ('\u{1F602}', "face with tears of joy"),
+    ('\u{1F603}', "smiling face with open mouth"),
+    ('\u{1F604}', "smiling face with open mouth and smiling eyes"),
+    ('\u{1F605}', "smiling face with open mouth and cold sweat"),
+    (
+        '\u{1F606}',
+        "smiling face with open mouth and tightly-closed eyes",
+    ),
+    ('\u{1F607}', "smiling face with halo"),
+    ('\u{1F608}', "smiling face with horns"),
+    ('\u{1F609}', "winking face"),
+    ('\u{1F60A}', "smiling face with smiling eyes"),
+    ('\u{1F60B}', "face savouring delicious food"),
+    ('\u{1F60C}', "relieved face"),
+    ('\u{1F60D}', "smiling face with heart-shaped eyes"),
+    ('\u{1F60E}', "smiling face with sunglasses"),
+    ('\u{1F60F}', "smirking face"),
+    ('\u{1F610}', "neutral face"),
+    ('\u{1F611}', "expressionless face"),
+    ('\u{1F612}', "unamused face"),
+    ('\u{1F613}', "face with cold sweat"),
+    ('\u{1F614}', "pensive face"),
+    ('\u{1F615}', "confused face"),
+    ('\u{1F616}', "confounded face"),
+    ('\u{1F617}', "kissing face"),
+    ('\u{1F618}', "face throwing a kiss"),
+    ('\u{1F619}', "kissing face with smiling eyes"),
+    ('\u{1F61A}', "kissing face with closed eyes"),
+    ('\u{1F61B}', "face with stuck-out tongue"),
+    ('\u{1F61C}', "face with stuck-out tongue and winking eye"),
+    (
+        '\u{1F61D}',
+        "face with stuck-out tongue and tightly-closed eyes",
+    ),
+    ('\u{1F61E}', "disappointed face"),
+    ('\u{1F61F}', "worried face"),
+    ('\u{1F620}', "angry face"),
+    ('\u{1F621}', "pouting face"),
+    ('\u{1F622}', "crying face"),
+    ('\u{1F623}', "persevering face"),
+    ('\u{1F624}', "face with look of triumph"),
+    ('\u{1F625}', "disappointed but relieved face"),
+    ('\u{1F626}', "frowning face with open mouth"),
+    ('\u{1F627}', "anguished face"),
+    ('\u{1F628}', "fearful face"),
+    ('\u{1F629}', "weary face"),
+    ('\u{1F62A}', "sleepy face"),
+    ('\u{1F62B}', "tired face"),
+    ('\u{1F62C}', "grimacing face"),
+    ('\u{1F62D}', "loudly crying face"),
+    ('\u{1F62E}', "face with open mouth"),
+    ('\u{1F62F}', "hushed face"),
+    ('\u{1F630}', "face with open mouth and cold sweat"),
+    ('\u{1F631}', "face screaming in fear"),
+    ('\u{1F632}', "astonished face"),
+    ('\u{1F633}', "flushed face"),
+    ('\u{1F634}', "sleeping face"),
+    ('\u{1F635}', "dizzy face"),
+    ('\u{1F636}', "face without mouth"),
+    ('\u{1F637}', "face with medical mask"),
+    ('\u{1F638}', "grinning cat face with smiling eyes"),
+    ('\u{1F639}', "cat face with tears of joy"),
+    ('\u{1F63A}', "smiling cat face with open mouth"),
+    ('\u{1F63B}', "smiling cat face with heart-shaped eyes"),
+    ('\u{1F63C}', "cat face with wry smile"),
+    ('\u{1F63D}', "kissing cat face with closed eyes"),
+    ('\u{1F63E}', "pouting cat face"),
+    ('\u{1F63F}', "crying cat face"),
+    ('\u{1F640}', "weary cat face"),
+    ('\u{1F641}', "slightly frowning face"),
+    ('\u{1F642}', "slightly smiling face"),
+    ('\u{1F643}', "upside-down face"),
+    ('\u{1F644}', "face with rolling eyes"),
+    ('\u{1F645}', "face with no good gesture"),
+    ('\u{1F646}', "face with ok gesture"),
+    ('\u{1F647}', "person bowing deeply"),
+    ('\u{1F648}', "see-no-evil monkey"),
+    ('\u{1F649}', "hear-no-evil monkey"),
+    ('\u{1F64A}', "speak-no-evil monkey"),
+    ('\u{1F64B}', "happy person raising one hand"),
+    ('\u{1F64C}', "person raising both hands in celebration"),
+    ('\u{1F64D}', "person frowning"),
+    ('\u{1F64E}', "person with pouting face"),
+    ('\u{1F64F}', "person with folded hands"),
+    ('\u{1F680}', "rocket"),
+    ('\u{1F681}', "helicopter"),
+    ('\u{1F682}', "steam locomotive"),
+    ('\u{1F683}', "railway car"),
+    ('\u{1F684}', "high-speed train"),
+    ('\u{1F685}', "high-speed train with bullet nose"),
+    ('\u{1F686}', "train"),
+    ('\u{1F687}', "metro"),
+    ('\u{1F688}', "light rail"),
+    ('\u{1F689}', "station"),
+    ('\u{1F68A}', "tram"),
+    ('\u{1F68B}', "tram car"),
+    ('\u{1F68C}', "bus"),
+    ('\u{1F68D}', "oncoming bus"),
+    ('\u{1F68E}', "trolleybus"),
+    ('\u{1F68F}', "bus stop"),
+    ('\u{1F690}', "minibus"),
+    ('\u{1F691}', "ambulance"),
+    ('\u{1F692}', "fire engine"),
+    ('\u{1F693}', "police car"),
+    ('\u{1F694}', "oncoming police car"),
+    ('\u{1F695}', "taxi"),
+    ('\u{1F696}', "oncoming taxi"),
+    ('\u{1F697}', "automobile"),
+    ('\u{1F698}', "oncoming automobile"),
+    ('\u{1F699}', "recreational vehicle"),
+    ('\u{1F69A}', "delivery truck"),
+    ('\u{1F69B}', "articulated lorry"),
+    ('\u{1F69C}', "tractor"),
+    ('\u{1F69D}', "monorail"),
+    ('\u{1F69E}', "mountain railway"),
+    ('\u{1F69F}', "suspension railway"),
+    ('\u{1F6A0}', "mountain cableway"),
+    ('\u{1F6A1}', "aerial tramway"),
+    ('\u{1F6A2}', "ship"),
+    ('\u{1F6A3}', "rowboat"),
+    ('\u{1F6A4}', "speedboat"),
+    ('\u{1F6A5}', "horizontal traffic light"),
+    ('\u{1F6A6}', "vertical traffic light"),
+    ('\u{1F6A7}', "construction sign"),
+    ('\u{1F6A8}', "police cars revolving light"),
+    ('\u{1F6A9}', "triangular flag on post"),
+    ('\u{1F6AA}', "door"),
+    ('\u{1F6AB}', "no entry sign"),
+    ('\u{1F6AC}', "smoking symbol"),
+    ('\u{1F6AD}', "no smoking symbol"),
+    ('\u{1F6AE}', "put litter in its place symbol"),
+    ('\u{1F6AF}', "do not litter symbol"),
+    ('\u{1F6B0}', "potable water symbol"),
+    ('\u{1F6B1}', "non-potable water symbol"),
+    ('\u{1F6B2}', "bicycle"),
+    ('\u{1F6B3}', "no bicycles"),
+    ('\u{1F6B4}', "bicyclist"),
+    ('\u{1F6B5}', "mountain bicyclist"),
+    ('\u{1F6B6}', "pedestrian"),
+    ('\u{1F6B7}', "no pedestrians"),
+    ('\u{1F6B8}', "children crossing"),
+    ('\u{1F6B9}', "mens symbol"),
+    ('\u{1F6BA}', "womens symbol"),
+    ('\u{1F6BB}', "restroom"),
+    ('\u{1F6BC}', "baby symbol"),
+    ('\u{1F6BD}', "toilet"),
+    ('\u{1F6BE}', "water closet"),
+    ('\u{1F6BF}', "shower"),
+    ('\u{1F6C0}', "bath"),
+    ('\u{1F6C1}', "bathtub"),
+    ('\u{1F6C2}', "passport control"),
+    ('\u{1F6C3}', "customs"),
+    ('\u{1F6C4}', "baggage claim"),
+    ('\u{1F6C5}', "left luggage"),
+    ('\u{1F6C6}', "triangle with rounded corners"),
+    ('\u{1F6C7}', "prohibited sign"),
+    ('\u{1F6C8}', "circled information source"),
+    ('\u{1F6C9}', "boys symbol"),
+    ('\u{1F6CA}', "girls symbol"),
+    ('\u{1F6CB}', "couch and lamp"),
+    ('\u{1F6CC}', "sleeping accommodation"),
+    ('\u{1F6CD}', "shopping bags"),
+    ('\u{1F6CE}', "bellhop bell"),
+    ('\u{1F6CF}', "bed"),
+    ('\u{1F6D0}', "place of worship"),
+    ('\u{1F6D1}', "octagonal sign"),
+    ('\u{1F6D2}', "shopping trolley"),
+    ('\u{1F6D3}', "stupa"),
+    ('\u{1F6D4}', "pagoda"),
+    ('\u{1F6D5}', "hindu temple"),
+    ('\u{1F6D6}', "hut"),
+    ('\u{1F6D7}', "elevator"),
+    ('\u{1F6DD}', "playground slide"),
+    ('\u{1F6DE}', "wheel"),
+    ('\u{1F6DF}', "ring buoy"),
+    ('\u{1F6E0}', "hammer and wrench"),
+    ('\u{1F6E1}', "shield"),
+    ('\u{1F6E2}', "oil drum"),
+    ('\u{1F6E3}', "motorway"),
+    ('\u{1F6E4}', "railway track"),
+    ('\u{1F6E5}', "motor boat"),
+    ('\u{1F6E6}', "up-pointing military airplane"),
+    ('\u{1F6E7}', "up-pointing airplane"),
+    ('\u{1F6E8}', "up-pointing small airplane"),
+    ('\u{1F6E9}', "small airplane"),
+    ('\u{1F6EA}', "northeast-pointing airplane"),
+    ('\u{1F6EB}', "airplane departure"),
+    ('\u{1F6EC}', "airplane arriving"),
+    ('\u{1F6F0}', "satellite"),
+    ('\u{1F6F1}', "oncoming fire engine"),
+    ('\u{1F6F2}', "diesel locomotive"),
+    ('\u{1F6F3}', "passenger ship"),
+    ('\u{1F6F4}', "scooter"),
+    ('\u{1F6F5}', "motor scooter"),
+    ('\u{1F6F6}', "canoe"),
+    ('\u{1F6F7}', "sled"),
+    ('\u{1F6F8}', "flying saucer"),
+    ('\u{1F6F9}', "skateboard"),
+    ('\u{1F6FA}', "auto rickshaw"),
+    ('\u{1F6FB}', "pickup truck"),
+    ('\u{1F6FC}', "roller skate"),
+    ('\u{1F900}', "circled cross formee with four dots"),
+    ('\u{1F901}', "circled cross formee with two dots"),
+    ('\u{1F902}', "circled cross formee"),
+    ('\u{1F903}', "left half circle with four dots"),
+    ('\u{1F904}', "left half circle with three dots"),
+    ('\u{1F905}', "left half circle with two dots"),
+    ('\u{1F906}', "left half circle with dot"),
+    ('\u{1F907}', "left half circle"),
+    ('\u{1F908}', "downward facing hook"),
+    ('\u{1F909}', "downward facing notched hook"),
+    ('\u{1F90A}', "downward facing hook with dot"),
+    ('\u{1F90B}', "downward facing notched hook with dot"),
+    ('\u{1F90C}', "pinched fingers"),
+    ('\u{1F90D}', "white heart"),
+    ('\u{1F90E}', "brown heart"),
+    ('\u{1F90F}', "pinching hand"),
+    ('\u{1F910}', "zipper-mouth face"),
+    ('\u{1F911}', "money-mouth face"),
+    ('\u{1F912}', "face with thermometer"),
+    ('\u{1F913}', "nerd face"),
+    ('\u{1F914}', "thinking face"),
+    ('\u{1F915}', "face with head-bandage"),
+    ('\u{1F916}', "robot face"),
+    ('\u{1F917}', "hugging face"),
+    ('\u{1F918}', "sign of the horns"),
+    ('\u{1F919}', "call me hand"),
+    ('\u{1F91A}', "raised back of hand"),
+    ('\u{1F91B}', "left-facing fist"),
+    ('\u{1F91C}', "right-facing fist"),
+    ('\u{1F91D}', "handshake"),
+    ('\u{1F91E}', "hand with index and middle fingers crossed"),
+    ('\u{1F91F}', "i love you hand sign"),
+    ('\u{1F920}', "face with cowboy hat"),
+    ('\u{1F921}', "clown face"),
+    ('\u{1F922}', "nauseated face"),
+    ('\u{1F923}', "rolling on the floor laughing"),
+    ('\u{1F924}', "drooling face"),
+    ('\u{1F925}', "lying face"),
+    ('\u{1F926}', "face palm"),
+    ('\u{1F927}', "sneezing face"),
+    ('\u{1F928}', "face with one eyebrow raised"),
+    ('\u{1F929}', "grinning face with star eyes"),
+    (
+        '\u{1F92A}',
+        "grinning face with one large and one small eye",
+    ),
+    ('\u{1F92B}', "face with finger covering closed lips"),
+    ('\u{1F92C}', "serious face with symbols covering mouth"),
+    (
+        '\u{1F92D}',
+        "smiling face with smiling eyes and hand covering mouth",
+    ),
+    ('\u{1F92E}', "face with open mouth vomiting"),
+    ('\u{1F92F}', "shocked face with exploding head"),
+    ('\u{1F930}', "pregnant woman"),
+    ('\u{1F931}', "breast-feeding"),
+    ('\u{1F932}', "palms up together"),
+    ('\u{1F933}', "selfie"),
+    ('\u{1F934}', "prince"),
+    ('\u{1F935}', "man in tuxedo"),
+    ('\u{1F936}', "mother christmas"),
+    ('\u{1F937}', "shrug"),
+    ('\u{1F938}', "person doing cartwheel"),
+    ('\u{1F939}', "juggling"),
+    ('\u{1F93A}', "fencer"),
+    ('\u{1F93B}', "modern pentathlon"),
+    ('\u{1F93C}', "wrestlers"),
+    ('\u{1F93D}', "water polo"),
+    ('\u{1F93E}', "handball"),
+    ('\u{1F93F}', "diving mask"),
+    ('\u{1F940}', "wilted flower"),
+    ('\u{1F941}', "drum with drumsticks"),
+    ('\u{1F942}', "clinking glasses"),
+    ('\u{1F943}', "tumbler glass"),
+    ('\u{1F944}', "spoon"),
+    ('\u{1F945}', "goal net"),
+    ('\u{1F946}', "rifle"),
+    ('\u{1F947}', "first place medal"),
+    ('\u{1F948}', "second place medal"),
+    ('\u{1F949}', "third place medal"),
+    ('\u{1F94A}', "boxing glove"),
+    ('\u{1F94B}', "martial arts uniform"),
+    ('\u{1F94C}', "curling stone"),
+    ('\u{1F94D}', "lacrosse stick and ball"),
+    ('\u{1F94E}', "softball"),
+    ('\u{1F94F}', "flying disc"),
+    ('\u{1F950}', "croissant"),
+    ('\u{1F951}', "avocado"),
+    ('\u{1F952}', "cucumber"),
+    ('\u{1F953}', "bacon"),
+    ('\u{1F954}', "potato"),
+    ('\u{1F955}', "carrot"),
+    ('\u{1F956}', "baguette bread"),
+    ('\u{1F957}', "green salad"),
+    ('\u{1F958}', "shallow pan of food"),
+    ('\u{1F959}', "stuffed flatbread"),
+    ('\u{1F95A}', "egg"),
+    ('\u{1F95B}', "glass of milk"),
+    ('\u{1F95C}', "peanuts"),
+    ('\u{1F95D}', "kiwifruit"),
+    ('\u{1F95E}', "pancakes"),
+    ('\u{1F95F}', "dumpling"),
+    ('\u{1F960}', "fortune cookie"),
+    ('\u{1F961}', "takeout box"),
+    ('\u{1F962}', "chopsticks"),
+    ('\u{1F963}', "bowl with spoon"),
+    ('\u{1F964}', "cup with straw"),
+    ('\u{1F965}', "coconut"),
+    ('\u{1F966}', "broccoli"),
+    ('\u{1F967}', "pie"),
+    ('\u{1F968}', "pretzel"),
+    ('\u{1F969}', "cut of meat"),
+    ('\u{1F96A}', "sandwich"),
+    ('\u{1F96B}', "canned food"),
+    ('\u{1F96C}', "leafy green"),
+    ('\u{1F96D}', "mango"),
+    ('\u{1F96E}', "moon cake"),
+    ('\u{1F96F}', "bagel"),
+    (
+        '\u{1F970}',
+        "smiling face with smiling eyes and three hearts",
+    ),
+    ('\u{1F971}', "yawning face"),
+    ('\u{1F972}', "smiling face with tear"),
+    ('\u{1F973}', "face with party horn and party hat"),
+    ('\u{1F974}', "face with uneven eyes and wavy mouth"),
+    ('\u{1F975}', "overheated face"),
+    ('\u{1F976}', "freezing face"),
+    ('\u{1F977}', "ninja"),
+    ('\u{1F978}', "disguised face"),
+    ('\u{1F979}', "face holding back tears"),
+    ('\u{1F97A}', "face with pleading eyes"),
+    ('\u{1F97B}', "sari"),
+    ('\u{1F97C}', "lab coat"),
+    ('\u{1F97D}', "goggles"),
+    ('\u{1F97E}', "hiking boot"),
+    ('\u{1F97F}', "flat shoe"),
+    ('\u{1F980}', "crab"),
+    ('\u{1F981}', "lion face"),
+    ('\u{1F982}', "scorpion"),
+    ('\u{1F983}', "turkey"),
+    ('\u{1F984}', "unicorn face"),
+    ('\u{1F985}', "eagle"),
+    ('\u{1F986}', "duck"),
+    ('\u{1F987}', "bat"),
+    ('\u{1F988}', "shark"),
+    ('\u{1F989}', "owl"),
+    ('\u{1F98A}', "fox face"),
+    ('\u{1F98B}', "butterfly"),
+    ('\u{1F98C}', "deer"),
+    ('\u{1F98D}', "gorilla"),
+    ('\u{1F98E}', "lizard"),
+    ('\u{1F98F}', "rhinoceros"),
+    ('\u{1F990}', "shrimp"),
+    ('\u{1F991}', "squid"),
+    ('\u{1F992}', "giraffe face"),
+    ('\u{1F993}', "zebra face"),
+    ('\u{1F994}', "hedgehog"),
+    ('\u{1F995}', "sauropod"),
+    ('\u{1F996}', "t-rex"),
+    ('\u{1F997}', "cricket"),
+    ('\u{1F998}', "kangaroo"),
+    ('\u{1F999}', "llama"),
+    ('\u{1F99A}', "peacock"),
+    ('\u{1F99B}', "hippopotamus"),
+    ('\u{1F99C}', "parrot"),
+    ('\u{1F99D}', "raccoon"),
+    ('\u{1F99E}', "lobster"),
+    ('\u{1F99F}', "mosquito"),
+    ('\u{1F9A0}', "microbe"),
+    ('\u{1F9A1}', "badger"),
+    ('\u{1F9A2}', "swan"),
+    ('\u{1F9A3}', "mammoth"),
+    ('\u{1F9A4}', "dodo"),
+    ('\u{1F9A5}', "sloth"),
+    ('\u{1F9A6}', "otter"),
+    ('\u{1F9A7}', "orangutan"),
+    ('\u{1F9A8}', "skunk"),
+    ('\u{1F9A9}', "flamingo"),
+    ('\u{1F9AA}', "oyster"),
+    ('\u{1F9AB}', "beaver"),
+    ('\u{1F9AC}', "bison"),
+    ('\u{1F9AD}', "seal"),
+    ('\u{1F9AE}', "guide dog"),
+    ('\u{1F9AF}', "probing cane"),
+    ('\u{1F9B0}', "emoji component red hair"),
+    ('\u{1F9B1}', "emoji component curly hair"),
+    ('\u{1F9B2}', "emoji component bald"),
+    ('\u{1F9B3}', "emoji component white hair"),
+    ('\u{1F9B4}', "bone"),
+    ('\u{1F9B5}', "leg"),
+    ('\u{1F9B6}', "foot"),
+    ('\u{1F9B7}', "tooth"),
+    ('\u{1F9B8}', "superhero"),
+    ('\u{1F9B9}', "supervillain"),
+    ('\u{1F9BA}', "safety vest"),
+    ('\u{1F9BB}', "ear with hearing aid"),
+    ('\u{1F9BC}', "motorized wheelchair"),
+    ('\u{1F9BD}', "manual wheelchair"),
+    ('\u{1F9BE}', "mechanical arm"),
+    ('\u{1F9BF}', "mechanical leg"),
+    ('\u{1F9C0}', "cheese wedge"),
+    ('\u{1F9C1}', "cupcake"),
+    ('\u{1F9C2}', "salt shaker"),
+    ('\u{1F9C3}', "beverage box"),
+    ('\u{1F9C4}', "garlic"),
+    ('\u{1F9C5}', "onion"),
+    ('\u{1F9C6}', "falafel"),
+    ('\u{1F9C7}', "waffle"),
+    ('\u{1F9C8}', "butter"),
+    ('\u{1F9C9}', "mate drink"),
+    ('\u{1F9CA}', "ice cube"),
+    ('\u{1F9CB}', "bubble tea"),
+    ('\u{1F9CC}', "troll"),
+    ('\u{1F9CD}', "standing person"),
+    ('\u{1F9CE}', "kneeling person"),
+    ('\u{1F9CF}', "deaf person"),
+    ('\u{1F9D0}', "face with monocle"),
+    ('\u{1F9D1}', "adult"),
+    ('\u{1F9D2}', "child"),
+    ('\u{1F9D3}', "older adult"),
+    ('\u{1F9D4}', "bearded person"),
+    ('\u{1F9D5}', "person with headscarf"),
+    ('\u{1F9D6}', "person in steamy room"),
+    ('\u{1F9D7}', "person climbing"),
+    ('\u{1F9D8}', "person in lotus position"),
+    ('\u{1F9D9}', "mage"),
+    ('\u{1F9DA}', "fairy"),
+    ('\u{1F9DB}', "vampire"),
+    ('\u{1F9DC}', "merperson"),
+    ('\u{1F9DD}', "elf"),
+    ('\u{1F9DE}', "genie"),
+    ('\u{1F9DF}', "zombie"),
+    ('\u{1F9E0}', "brain"),
+    ('\u{1F9E1}', "orange heart"),
+    ('\u{1F9E2}', "billed cap"),
+    ('\u{1F9E3}', "scarf"),
+    ('\u{1F9E4}', "gloves"),
+    ('\u{1F9E5}', "coat"),
+    ('\u{1F9E6}', "socks"),
+    ('\u{1F9E7}', "red gift envelope"),
+    ('\u{1F9E8}', "firecracker"),
+    ('\u{1F9E9}', "jigsaw puzzle piece"),
+    ('\u{1F9EA}', "test tube"),
+    ('\u{1F9EB}', "petri dish"),
+    ('\u{1F9EC}', "dna double helix"),
+    ('\u{1F9ED}', "compass"),
+    ('\u{1F9EE}', "abacus"),
+    ('\u{1F9EF}', "fire extinguisher"),
+    ('\u{1F9F0}', "toolbox"),
+    ('\u{1F9F1}', "brick"),
+    ('\u{1F9F2}', "magnet"),
+    ('\u{1F9F3}', "luggage"),
+    ('\u{1F9F4}', "lotion bottle"),
+    ('\u{1F9F5}', "spool of thread"),
+    ('\u{1F9F6}', "ball of yarn"),
+    ('\u{1F9F7}', "safety pin"),
+    ('\u{1F9F8}', "teddy bear"),
+    ('\u{1F9F9}', "broom"),
+    ('\u{1F9FA}', "basket"),
+    ('\u{1F9FB}', "roll of paper"),
+    ('\u{1F9FC}', "bar of soap"),
+    ('\u{1F9FD}', "sponge"),
+    ('\u{1F9FE}', "receipt"),
+    ('\u{1F9FF}', "nazar amulet"),
+    ('\u{1FA70}', "ballet shoes"),
+    ('\u{1FA71}', "one-piece swimsuit"),
+    ('\u{1FA72}', "briefs"),
+    ('\u{1FA73}', "shorts"),
+    ('\u{1FA74}', "thong sandal"),
+    ('\u{1FA78}', "drop of blood"),
+    ('\u{1FA79}', "adhesive bandage"),
+    ('\u{1FA7A}', "stethoscope"),
+    ('\u{1FA7B}', "x-ray"),
+    ('\u{1FA7C}', "crutch"),
+    ('\u{1FA80}', "yo-yo"),
+    ('\u{1FA81}', "kite"),
+    ('\u{1FA82}', "parachute"),
+    ('\u{1FA83}', "boomerang"),
+    ('\u{1FA84}', "magic wand"),
+    ('\u{1FA85}', "pinata"),
+    ('\u{1FA86}', "nesting dolls"),
+    ('\u{1FA90}', "ringed planet"),
+    ('\u{1FA91}', "chair"),
+    ('\u{1FA92}', "razor"),
+    ('\u{1FA93}', "axe"),
+    ('\u{1FA94}', "diya lamp"),
+    ('\u{1FA95}', "banjo"),
+    ('\u{1FA96}', "military helmet"),
+    ('\u{1FA97}', "accordion"),
+    ('\u{1FA98}', "long drum"),
+    ('\u{1FA99}', "coin"),
+    ('\u{1FA9A}', "carpentry saw"),
+    ('\u{1FA9B}', "screwdriver"),
+    ('\u{1FA9C}', "ladder"),
+    ('\u{1FA9D}', "hook"),
+    ('\u{1FA9E}', "mirror"),
+    ('\u{1FA9F}', "window"),
+    ('\u{1FAA0}', "plunger"),
+    ('\u{1FAA1}', "sewing needle"),
+    ('\u{1FAA2}', "knot"),
+    ('\u{1FAA3}', "bucket"),
+    ('\u{1FAA4}', "mouse trap"),
+    ('\u{1FAA5}', "toothbrush"),
+    ('\u{1FAA6}', "headstone"),
+    ('\u{1FAA7}', "placard"),
+    ('\u{1FAA8}', "rock"),
+    ('\u{1FAA9}', "mirror ball"),
+    ('\u{1FAAA}', "identification card"),
+    ('\u{1FAAB}', "low battery"),
+    ('\u{1FAAC}', "hamsa"),
+    ('\u{1FAB0}', "fly"),
+    ('\u{1FAB1}', "worm"),
+    ('\u{1FAB2}', "beetle"),
+    ('\u{1FAB3}', "cockroach"),
+    ('\u{1FAB4}', "potted plant"),
+    ('\u{1FAB5}', "wood"),
+    ('\u{1FAB6}', "feather"),
+    ('\u{1FAB7}', "lotus"),
+    ('\u{1FAB8}', "coral"),
+    ('\u{1FAB9}', "empty nest"),
+    ('\u{1FABA}', "nest with eggs"),
+    ('\u{1FAC0}', "anatomical heart"),
+    ('\u{1FAC1}', "lungs"),
+    ('\u{1FAC2}', "people hugging"),
+    ('\u{1FAC3}', "pregnant man"),
+    ('\u{1FAC4}', "pregnant person"),
+    ('\u{1FAC5}', "person with crown"),
+    ('\u{1FAD0}', "blueberries"),
+    ('\u{1FAD1}', "bell pepper"),
+    ('\u{1FAD2}', "olive"),
+    ('\u{1FAD3}', "flatbread"),
+    ('\u{1FAD4}', "tamale"),
+    ('\u{1FAD5}', "fondue"),
+    ('\u{1FAD6}', "teapot"),
+    ('\u{1FAD7}', "pouring liquid"),
+    ('\u{1FAD8}', "beans"),
+    ('\u{1FAD9}', "jar"),
+    ('\u{1FAE0}', "melting face"),
+    ('\u{1FAE1}', "saluting face"),
+    ('\u{1FAE2}', "face with open eyes and hand over mouth"),
+    ('\u{1FAE3}', "face with peeking eye"),
+    ('\u{1FAE4}', "face with diagonal mouth"),
+    ('\u{1FAE5}', "dotted line face"),
+    ('\u{1FAE6}', "biting lip"),
+    ('\u{1FAE7}', "bubbles"),
+    ('\u{1FAF0}', "hand with index finger and thumb crossed"),
+    ('\u{1FAF1}', "rightwards hand"),
+    ('\u{1FAF2}', "leftwards hand"),
+    ('\u{1FAF3}', "palm down hand"),
+    ('\u{1FAF4}', "palm up hand"),
+    ('\u{1FAF5}', "index pointing at the viewer"),
+    ('\u{1FAF6}', "heart hands"),
+];
@@ -0,0 +1,299 @@
+/// What to do with characters that don't have a mapping in the alphabet
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Unmatched {
+    /// Silently drop the character
+    #[default]
+    Skip,
+    /// Pass the character through verbatim
+    Keep,
+    /// Replace the character with a placeholder
+    Replace(String),
+    /// Fail with [`SalphError::Unmatched`](crate::SalphError::Unmatched)
+    Error,
+}
+
+/// Unicode normalization form applied to the input before matching. Alphabet keys
+/// are stored in composed form (NFC), so decomposed forms only affect the
+/// [`Spelling::source`](crate::Spelling::source) that is reported. Compatibility
+/// forms additionally map characters like "ﬁ" or "Ａ" to their plain equivalents,
+/// in which case positions refer to the normalized input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Normalization {
+    /// Match the input as is
+    None,
+    /// Canonical composition
+    #[default]
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// How digits are spelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DigitStyle {
+    /// Spell every digit using the code words of the alphabet
+    #[default]
+    Alphabet,
+    /// Spell digits using the ICAO radiotelephony pronunciation ("tree", "fife",
+    /// "niner"). Numbers consisting of whole hundreds or thousands are spelled as a
+    /// group (e.g. "2500" as "two tousand fife hundred"), unless they have more than
+    /// twelve digits.
+    Icao,
+    /// Spell consecutive digits as a number (e.g. "23" as "twenty-three"). Numbers
+    /// are spelled in the language of the alphabet if supported and in English
+    /// otherwise. Numbers with leading zeros or more than twelve digits are spelled
+    /// digit by digit.
+    /// ```
+    /// use salph::{Alphabet, DigitStyle, SpellingAlphabet, SpellingOptions};
+    ///
+    /// let nato = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().digit_style(DigitStyle::Number);
+    /// let spellings = nato.str_to_spellings_with("23 1234567890123", &options).unwrap();
+    /// assert_eq!(spellings[0].spelling, "twenty-three");
+    /// assert_eq!(spellings.len(), 14);
+    /// assert_eq!(spellings[13].spelling, "three");
+    /// ```
+    Number,
+}
+
+/// Options that control how a string is spelled by
+/// [`SpellingAlphabet::str_to_spellings_with`](crate::SpellingAlphabet::str_to_spellings_with).
+/// Options are constructed using [`SpellingOptions::new`] and the builder methods,
+/// so new options can be added without breaking existing code.
+/// ```
+/// use salph::{SpellingOptions, Unmatched};
+///
+/// let options = SpellingOptions::new()
+///     .unmatched(Unmatched::Keep)
+///     .spell_spaces(true)
+///     .announce_case(true)
+///     .capital_word("uppercase");
+/// assert_eq!(options.unmatched, Unmatched::Keep);
+/// assert_eq!(options.capital_word.as_deref(), Some("uppercase"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SpellingOptions {
+    /// Policy for characters without a mapping
+    pub unmatched: Unmatched,
+    /// Spell whitespace using the alphabet's space word instead of treating it
+    /// as an unmatched character
+    pub spell_spaces: bool,
+    /// Announce uppercase letters by prefixing their code word with the
+    /// alphabet's capital word
+    pub announce_case: bool,
+    /// Word to announce uppercase letters with, overriding the alphabet's capital word
+    pub capital_word: Option<String>,
+    /// Spell the separators of email addresses and URLs (like "@", "." and "/") as
+    /// English words if the alphabet doesn't have code words for them
+    pub spell_addresses: bool,
+    /// Announce the class of every character by prefixing its code word with the
+    /// alphabet's capital, lowercase, digit or symbol word (e.g. "lowercase Echo" or
+    /// "digit three"), so case and symbols can't be missed
+    pub announce_class: bool,
+    /// Fold diacritics (e.g. "é" to "e") of characters without a mapping and retry
+    /// the lookup. Spellings found this way are flagged with
+    /// [`Spelling::folded`](crate::Spelling::folded).
+    pub fold_diacritics: bool,
+    /// Spell emoji and other symbols without a mapping by their Unicode name (e.g.
+    /// "😀" as "grinning face"). Spellings found this way have the kind
+    /// [`SpellingKind::Symbol`](crate::SpellingKind::Symbol).
+    #[cfg(feature = "unicode-names")]
+    pub name_symbols: bool,
+    /// Unicode normalization form applied to the input before matching
+    pub normalization: Normalization,
+    /// How digits are spelled
+    pub digit_style: DigitStyle,
+    /// Which variant to use for code words with alternates (e.g. "Alpha|Alfa"),
+    /// starting at 0. Code words with fewer variants use the first one.
+    pub variant: usize,
+}
+
+impl SpellingOptions {
+    /// Create options with the default settings: the input is normalized to NFC,
+    /// unmatched characters are skipped and no spaces or case are spelled
+    pub const fn new() -> SpellingOptions {
+        SpellingOptions {
+            unmatched: Unmatched::Skip,
+            spell_spaces: false,
+            announce_case: false,
+            capital_word: None,
+            spell_addresses: false,
+            announce_class: false,
+            fold_diacritics: false,
+            #[cfg(feature = "unicode-names")]
+            name_symbols: false,
+            normalization: Normalization::Nfc,
+            digit_style: DigitStyle::Alphabet,
+            variant: 0,
+        }
+    }
+
+    /// Set the policy for characters without a mapping
+    pub fn unmatched(mut self, unmatched: Unmatched) -> SpellingOptions {
+        self.unmatched = unmatched;
+        self
+    }
+
+    /// Spell whitespace using the alphabet's space word
+    pub fn spell_spaces(mut self, spell_spaces: bool) -> SpellingOptions {
+        self.spell_spaces = spell_spaces;
+        self
+    }
+
+    /// Announce uppercase letters
+    pub fn announce_case(mut self, announce_case: bool) -> SpellingOptions {
+        self.announce_case = announce_case;
+        self
+    }
+
+    /// Set the word to announce uppercase letters with
+    pub fn capital_word(mut self, word: &str) -> SpellingOptions {
+        self.capital_word = Some(word.to_string());
+        self
+    }
+
+    /// Spell the separators of email addresses and URLs as words. Addresses are
+    /// recognized by their shape, so other punctuation is left alone. Code words of
+    /// the alphabet take precedence.
+    /// ```
+    /// use salph::{Normalization, SpellingAlphabet, SpellingOptions};
+    ///
+    /// let options = SpellingOptions::new().spell_addresses(true);
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// # use salph::Alphabet;
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// let words = spelling_alphabet
+    ///     .str_to_spellings_with("Mail jo@x.de!", &options)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|x| x.spelling.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     words,
+    ///     ["Martha", "Anton", "Ida", "Ludwig", "Julius", "Otto", "at", "Xanthippe", "Punkt", "Dora", "Emil"]
+    /// );
+    /// # }
+    ///
+    /// // Addresses are found after normalization, which can split characters (e.g.
+    /// // "Ⅷ" into "VIII")
+    /// let spelling_alphabet = SpellingAlphabet::parse("j Juliet\nx X-ray\ny Yankee").unwrap();
+    /// let options = options.normalization(Normalization::Nfkc);
+    /// let words = spelling_alphabet
+    ///     .str_to_spellings_with("ⅧⅧ j@x.y", &options)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|x| x.spelling.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Juliet", "at", "X-ray", "dot", "Yankee"]);
+    /// ```
+    pub fn spell_addresses(mut self, spell_addresses: bool) -> SpellingOptions {
+        self.spell_addresses = spell_addresses;
+        self
+    }
+
+    /// Announce the class of every character, like when reading out a password.
+    /// Characters without a mapping that are kept are announced as symbols.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions, Unmatched};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new()
+    ///     .announce_class(true)
+    ///     .unmatched(Unmatched::Keep);
+    /// let words = spelling_alphabet
+    ///     .str_to_spellings_with("Se3-!", &options)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|x| x.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     words,
+    ///     ["capital Sierra", "lowercase Echo", "digit three", "symbol dash", "symbol !"]
+    /// );
+    /// ```
+    pub fn announce_class(mut self, announce_class: bool) -> SpellingOptions {
+        self.announce_class = announce_class;
+        self
+    }
+
+    /// Fold diacritics of characters without a mapping
+    pub fn fold_diacritics(mut self, fold_diacritics: bool) -> SpellingOptions {
+        self.fold_diacritics = fold_diacritics;
+        self
+    }
+
+    /// Spell emoji and other symbols without a mapping by their Unicode name, see
+    /// [`names`](crate::names)
+    #[cfg(feature = "unicode-names")]
+    pub fn name_symbols(mut self, name_symbols: bool) -> SpellingOptions {
+        self.name_symbols = name_symbols;
+        self
+    }
+
+    /// Set the Unicode normalization form applied to the input
+    pub fn normalization(mut self, normalization: Normalization) -> SpellingOptions {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Set how digits are spelled
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions, DigitStyle};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().digit_style(DigitStyle::Icao);
+    /// let words = spelling_alphabet
+    ///     .str_to_spellings_with("FL390 QNH 1000", &options)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|x| x.spelling.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     words,
+    ///     ["Foxtrot", "Lima", "tree", "niner", "zero", "Quebec", "November", "Hotel", "one tousand"]
+    /// );
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// let options = SpellingOptions::new().digit_style(DigitStyle::Number);
+    /// let words = spelling_alphabet
+    ///     .str_to_spellings_with("A23", &options)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|x| x.spelling.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(words, ["Anton", "dreiundzwanzig"]);
+    /// # }
+    /// ```
+    pub fn digit_style(mut self, digit_style: DigitStyle) -> SpellingOptions {
+        self.digit_style = digit_style;
+        self
+    }
+
+    /// Set which variant to use for code words with alternates
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().variant(1);
+    /// let spellings = spelling_alphabet.str_to_spellings_with("ab", &options).unwrap();
+    /// assert_eq!(spellings[0].spelling, "Alfa");
+    /// assert_eq!(spellings[0].alternates, ["Alpha"]);
+    /// assert_eq!(spellings[1].spelling, "Bravo");
+    /// ```
+    pub fn variant(mut self, variant: usize) -> SpellingOptions {
+        self.variant = variant;
+        self
+    }
+}
+
+impl Default for SpellingOptions {
+    fn default() -> Self {
+        SpellingOptions::new()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Normalization, SalphError, Spelling, SpellingAlphabet, SpellingOptions};
+use rayon::prelude::*;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Inputs are split into chunks of at least this many bytes
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl SpellingAlphabet {
+    /// Map a String to a vector of `Spelling`s like
+    /// [`SpellingAlphabet::str_to_spellings`], spelling parts of the input on
+    /// multiple threads. This pays off for inputs of hundreds of kilobytes or
+    /// more, like whole books. Enabled through the `parallel` feature.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let input = "the quick brown fox jumps over the lazy dog\n".repeat(10_000);
+    /// let spellings = spelling_alphabet.str_to_spellings_par(&input);
+    /// assert_eq!(spellings, spelling_alphabet.str_to_spellings(&input));
+    /// ```
+    pub fn str_to_spellings_par(&self, s: &str) -> Vec<Spelling> {
+        self.str_to_spellings_par_with(s, &SpellingOptions::new())
+            .expect("skipping unmatched characters can't fail")
+    }
+
+    /// Map a String to a vector of `Spelling`s using the given
+    /// [`SpellingOptions`], spelling parts of the input on multiple threads. The
+    /// input is only split at whitespace, so the result is the same as that of
+    /// [`SpellingAlphabet::str_to_spellings_with`], including the positions and
+    /// the first unmatched character that is reported.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SalphError, SpellingOptions, Unmatched};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let input = format!("{}€ €", "a ".repeat(100_000));
+    /// let options = SpellingOptions::new().spell_spaces(true).unmatched(Unmatched::Error);
+    /// let error = spelling_alphabet.str_to_spellings_par_with(&input, &options).unwrap_err();
+    /// assert!(matches!(error, SalphError::Unmatched { position: 200_000, .. }));
+    /// ```
+    pub fn str_to_spellings_par_with(
+        &self,
+        s: &str,
+        options: &SpellingOptions,
+    ) -> Result<Vec<Spelling>, SalphError> {
+        let spelled = chunks(s, options.normalization)
+            .into_par_iter()
+            .map(|(offset, chunk)| {
+                let mut spellings = self
+                    .str_to_spellings_with(chunk, options)
+                    .map_err(|e| offset_error(e, offset))?;
+                for spelling in &mut spellings {
+                    spelling.position += offset;
+                }
+                Ok::<_, SalphError>(spellings)
+            })
+            .collect::<Vec<_>>();
+
+        // Errors are checked in order, so the first unmatched character is reported
+        let mut spellings = Vec::new();
+        for chunk in spelled {
+            spellings.extend(chunk?);
+        }
+        Ok(spellings)
+    }
+}
+
+// Move the position of an unmatched character from its chunk to the input
+fn offset_error(e: SalphError, offset: usize) -> SalphError {
+    match e {
+        SalphError::Unmatched { position, grapheme } => SalphError::Unmatched {
+            position: position + offset,
+            grapheme,
+        },
+        e => e,
+    }
+}
+
+// Split a string into chunks that end in whitespace, so no match or number is
+// split. Every chunk comes with its position in the input, counted in normalized
+// grapheme clusters.
+fn chunks(s: &str, normalization: Normalization) -> Vec<(usize, &str)> {
+    let mut chunks = Vec::new();
+    let (mut start, mut offset, mut position) = (0, 0, 0);
+    for (i, grapheme) in s.grapheme_indices(true) {
+        // Compatibility normalization can split a grapheme into multiple graphemes
+        position += match normalization {
+            Normalization::Nfkc => grapheme.nfkc().collect::<String>().graphemes(true).count(),
+            Normalization::Nfkd => grapheme.nfkd().collect::<String>().graphemes(true).count(),
+            _ => 1,
+        };
+        let end = i + grapheme.len();
+        if end - start >= CHUNK_SIZE && grapheme.chars().all(char::is_whitespace) {
+            chunks.push((offset, &s[start..end]));
+            (start, offset) = (end, position);
+        }
+    }
+    if start < s.len() {
+        chunks.push((offset, &s[start..]));
+    }
+    chunks
+}
@@ -0,0 +1,128 @@
+//! Phone numbers, grouped the way they're written in a region and spelled with
+//! the ICAO radiotelephony digits ("tree", "fife", "niner"), digit by digit.
+//!
+//! A pattern lists the sizes of the groups (e.g. `[3, 3, 4]` for "555 123 4567").
+//! Patterns are applied from the end of the national number. The country code of
+//! an international number (starting with `+`) forms a group of its own, as do
+//! digits in front of the pattern.
+//! ```
+//! use salph::phone;
+//!
+//! assert_eq!(phone::group("+1 (555) 123-4567", &[3, 3, 4]), ["+1", "555", "123", "4567"]);
+//! assert_eq!(phone::spell("+1", &[3])[0], ["plus", "one"]);
+//! ```
+use crate::digits::icao_digit;
+
+/// The pattern used for regions without a known convention
+pub const DEFAULT_PATTERN: &[usize] = &[3, 3, 4];
+
+// How national numbers are grouped, by ISO 3166 region code
+const PATTERNS: &[(&str, &[usize])] = &[
+    ("BE", &[4, 2, 2, 2]),
+    ("CA", &[3, 3, 4]),
+    ("CH", &[3, 3, 2, 2]),
+    ("CZ", &[3, 3, 3]),
+    ("DK", &[2, 2, 2, 2]),
+    ("ES", &[3, 3, 3]),
+    ("FR", &[2, 2, 2, 2, 2]),
+    ("GB", &[5, 6]),
+    ("IT", &[3, 3, 4]),
+    ("NL", &[3, 3, 4]),
+    ("NO", &[2, 2, 2, 2]),
+    ("PT", &[3, 3, 3]),
+    ("RO", &[4, 3, 3]),
+    ("SE", &[3, 3, 2, 2]),
+    ("TR", &[4, 3, 2, 2]),
+    ("US", &[3, 3, 4]),
+];
+
+// Country calling codes with one or two digits. Calling codes are prefix-free, so
+// the codes of all other countries have three digits.
+const SHORT_COUNTRY_CODES: &[&str] = &[
+    "1", "7", "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45",
+    "46", "47", "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63",
+    "64", "65", "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+];
+
+/// The pattern phone numbers are written with in a region (e.g. "FR"), or
+/// [`DEFAULT_PATTERN`] if the convention isn't known
+/// ```
+/// use salph::phone;
+///
+/// assert_eq!(phone::pattern("FR"), [2, 2, 2, 2, 2]);
+/// assert_eq!(phone::pattern("XX"), phone::DEFAULT_PATTERN);
+/// ```
+pub fn pattern(region: &str) -> &'static [usize] {
+    PATTERNS
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(region))
+        .map_or(DEFAULT_PATTERN, |(_, pattern)| pattern)
+}
+
+/// Split the digits of a phone number into groups, following `pattern` from the
+/// end. Characters other than digits are dropped, except for a leading `+`, which
+/// is kept in a group with the country code that follows it.
+/// ```
+/// use salph::phone;
+///
+/// assert_eq!(phone::group("01 23 45 67 89", &[2, 2, 2, 2, 2]), ["01", "23", "45", "67", "89"]);
+/// assert_eq!(phone::group("5551234", &[3, 3, 4]), ["555", "1234"]);
+/// assert_eq!(phone::group("+31201234567", &[3, 3, 4]), ["+31", "20", "123", "4567"]);
+/// assert_eq!(phone::group("+352 621 123 456", &[3, 3, 3]), ["+352", "621", "123", "456"]);
+/// ```
+pub fn group(number: &str, pattern: &[usize]) -> Vec<String> {
+    let plus = number.trim_start().starts_with('+');
+    let mut digits = number
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    let country_code = plus.then(|| {
+        let short = SHORT_COUNTRY_CODES
+            .iter()
+            .find(|code| digits.starts_with(*code));
+        let len = short.map_or(3, |code| code.len()).min(digits.len());
+        let national = digits.split_off(len);
+        format!("+{}", std::mem::replace(&mut digits, national))
+    });
+
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    for size in pattern.iter().rev().filter(|size| **size > 0) {
+        if end == 0 {
+            break;
+        }
+        let start = end.saturating_sub(*size);
+        groups.push(digits[start..end].to_string());
+        end = start;
+    }
+    if end > 0 {
+        groups.push(digits[..end].to_string());
+    }
+    groups.extend(country_code);
+    groups.reverse();
+    groups
+}
+
+/// Group a phone number like [`group`] and spell every group digit by digit. A
+/// leading `+` is spelled as "plus".
+/// ```
+/// use salph::phone;
+///
+/// let groups = phone::spell("0900 359", &[4, 3]);
+/// assert_eq!(groups[0], ["zero", "niner", "zero", "zero"]);
+/// assert_eq!(groups[1], ["tree", "fife", "niner"]);
+/// ```
+pub fn spell(number: &str, pattern: &[usize]) -> Vec<Vec<&'static str>> {
+    group(number, pattern)
+        .iter()
+        .map(|group| {
+            group
+                .bytes()
+                .map(|b| match b {
+                    b'+' => "plus",
+                    digit => icao_digit(digit),
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+use crate::{SalphError, SpellingAlphabet, SpellingKind, SpellingOptions};
+
+impl SpellingAlphabet {
+    /// Spell a string as a phrase like "A as in Alpha, B as in Bravo", the way
+    /// spellings are read out on the phone. The connector words come from the
+    /// alphabet, see [`SpellingAlphabet::connector_word`]. Characters without a
+    /// mapping are skipped.
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(spelling_alphabet.as_in("ab1"), "A as in Alpha, B as in Bravo, 1 as in one");
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::de).unwrap();
+    /// assert_eq!(spelling_alphabet.as_in("ja"), "J wie Julius, A wie Anton");
+    /// # }
+    /// ```
+    pub fn as_in(&self, s: &str) -> String {
+        self.as_in_with(s, &SpellingOptions::new())
+            .expect("skipping unmatched characters can't fail")
+    }
+
+    /// Spell a string as a phrase like [`SpellingAlphabet::as_in`], using the given
+    /// options. Spelled whitespace and characters without a mapping that are kept
+    /// or replaced are included as is.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet, SpellingOptions};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// let options = SpellingOptions::new().spell_spaces(true);
+    /// let phrase = spelling_alphabet.as_in_with("a b", &options).unwrap();
+    /// assert_eq!(phrase, "A as in Alpha, space, B as in Bravo");
+    /// ```
+    pub fn as_in_with(&self, s: &str, options: &SpellingOptions) -> Result<String, SalphError> {
+        let connector = self.connector_word();
+        let parts = self
+            .str_to_spellings_with(s, options)?
+            .into_iter()
+            .map(|spelling| match spelling.kind {
+                SpellingKind::Letter | SpellingKind::Digit | SpellingKind::Punctuation => {
+                    format!(
+                        "{} {} {}",
+                        spelling.source.to_uppercase(),
+                        connector,
+                        spelling
+                    )
+                }
+                SpellingKind::Whitespace | SpellingKind::Symbol | SpellingKind::Unknown => {
+                    spelling.to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(parts.join(", "))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{SalphError, SpellingAlphabet, SpellingOptions};
+
+/// Radio procedure words that wrap a spelling in a transmission, as returned by
+/// [`SpellingAlphabet::prowords`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Prowords {
+    /// Announces the spelling (e.g. "I spell")
+    pub intro: String,
+    /// Separates the spelled words (e.g. "break")
+    pub separator: String,
+    /// Ends the transmission (e.g. "over")
+    pub end: String,
+}
+
+impl SpellingAlphabet {
+    /// The procedure words to transmit a spelling with, taken from the `prowords`
+    /// directive (e.g. `# prowords: ich buchstabiere | Trennung | kommen`). Words
+    /// the alphabet doesn't define fall back to "I spell", "break" and "over".
+    /// ```
+    /// # #[cfg(feature = "all-alphabets")] {
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let prowords = SpellingAlphabet::load(Alphabet::nato).unwrap().prowords();
+    /// assert_eq!(prowords.intro, "I spell");
+    ///
+    /// let prowords = SpellingAlphabet::load(Alphabet::de).unwrap().prowords();
+    /// assert_eq!(prowords.intro, "ich buchstabiere");
+    /// assert_eq!(prowords.end, "kommen");
+    /// # }
+    /// ```
+    pub fn prowords(&self) -> Prowords {
+        let mut words = self
+            .directives
+            .get("prowords")
+            .map_or("", |w| w)
+            .split('|')
+            .map(str::trim);
+        let mut word = |default: &str| {
+            let word = words.next().unwrap_or_default();
+            (if word.is_empty() { default } else { word }).to_string()
+        };
+        Prowords {
+            intro: word("I spell"),
+            separator: word("break"),
+            end: word("over"),
+        }
+    }
+
+    /// Spell a string as a transmission that can be read out over the radio: the
+    /// code words of every word of the string, wrapped in the alphabet's
+    /// [procedure words](SpellingAlphabet::prowords). Characters without a mapping
+    /// are skipped.
+    /// ```
+    /// use salph::{SpellingAlphabet, Alphabet};
+    ///
+    /// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+    /// assert_eq!(
+    ///     spelling_alphabet.transmission("hi all"),
+    ///     "I spell, Hotel India, break, Alpha Lima Lima, over"
+    /// );
+    /// ```
+    pub fn transmission(&self, s: &str) -> String {
+        self.transmission_with(s, &SpellingOptions::new())
+            .expect("skipping unmatched characters can't fail")
+    }
+
+    /// Spell a string as a transmission like [`SpellingAlphabet::transmission`],
+    /// using the given options. Whitespace separates the words, even when spaces
+    /// are spelled.
+    pub fn transmission_with(
+        &self,
+        s: &str,
+        options: &SpellingOptions,
+    ) -> Result<String, SalphError> {
+        let prowords = self.prowords();
+        let mut parts = vec![prowords.intro.clone()];
+        for word in s.split_whitespace() {
+            let spellings = self.str_to_spellings_with(word, options)?;
+            if spellings.is_empty() {
+                continue;
+            }
+            if parts.len() > 1 {
+                parts.push(prowords.separator.clone());
+            }
+            let words = spellings.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            parts.push(words.join(" "));
+        }
+        parts.push(prowords.end);
+        Ok(parts.join(", "))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Alphabet, AlphabetMetadata, SalphError, SpellingAlphabet};
+use indexmap::IndexMap;
+use std::{
+    str::FromStr,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+// Alphabets registered at runtime, in registration order
+static REGISTRY: LazyLock<RwLock<IndexMap<String, Arc<SpellingAlphabet>>>> =
+    LazyLock::new(Default::default);
+
+/// Register a custom alphabet under `name`, so it can be loaded by name like an
+/// embedded alphabet (e.g. through [`SpellingAlphabet::from_str`]) and is part of
+/// [`SpellingAlphabet::list`]. The alphabet can also be loaded by the aliases in its
+/// metadata. Registering a name again replaces the previous alphabet. Names and
+/// aliases of embedded alphabets can't be registered.
+/// ```
+/// use salph::{AlphabetBuilder, SpellingAlphabet, SalphError};
+/// use std::str::FromStr;
+///
+/// let alphabet = AlphabetBuilder::new().add("a", "Apple").build().unwrap();
+/// salph::register("mycorp", alphabet).unwrap();
+///
+/// let spelling_alphabet = SpellingAlphabet::from_str("mycorp").unwrap();
+/// assert_eq!(spelling_alphabet.str_to_spellings("a")[0].spelling, "Apple");
+/// assert!(SpellingAlphabet::list().iter().any(|(name, _)| name == "mycorp"));
+///
+/// let alphabet = AlphabetBuilder::new().add("a", "Apple").build().unwrap();
+/// assert!(matches!(salph::register("nato", alphabet), Err(SalphError::Reserved(_))));
+///
+/// let alphabet = SpellingAlphabet::parse("# aliases: acme\nA Acme").unwrap();
+/// salph::register("acmecorp", alphabet).unwrap();
+/// assert!(SpellingAlphabet::from_str("acme").is_ok());
+/// ```
+pub fn register(name: &str, alphabet: SpellingAlphabet) -> Result<(), SalphError> {
+    if Alphabet::from_str(name).is_ok() {
+        return Err(SalphError::Reserved(name.to_string()));
+    }
+    // A poisoned lock only means another thread panicked while holding it, the
+    // map itself is still consistent
+    let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    registry.insert(name.to_string(), Arc::new(alphabet));
+    Ok(())
+}
+
+// Look up a registered alphabet by its name or one of its aliases
+pub(crate) fn get(name: &str) -> Option<Arc<SpellingAlphabet>> {
+    let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+    registry
+        .get(name)
+        .or_else(|| {
+            registry.values().find(|alphabet| {
+                alphabet
+                    .metadata()
+                    .aliases
+                    .iter()
+                    .any(|alias| alias == name)
+            })
+        })
+        .cloned()
+}
+
+// Names and metadata of all registered alphabets
+pub(crate) fn list() -> Vec<(String, AlphabetMetadata)> {
+    let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+    registry
+        .iter()
+        .map(|(name, alphabet)| (name.clone(), alphabet.metadata()))
+        .collect()
+}
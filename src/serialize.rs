@@ -0,0 +1,67 @@
+//! Serde support, enabled through the `serde` feature.
+//!
+//! A [`SpellingAlphabet`] is serialized as an object containing its entries in
+//! their original order:
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use salph::{SpellingAlphabet, AlphabetBuilder};
+//!
+//! let spelling_alphabet = AlphabetBuilder::new().add("a", "Amsterdam").build().unwrap();
+//! let json = serde_json::to_string(&spelling_alphabet).unwrap();
+//! assert_eq!(json, r#"{"words":{"a":"Amsterdam"}}"#);
+//!
+//! let spelling_alphabet: SpellingAlphabet = serde_json::from_str(&json).unwrap();
+//! assert_eq!(spelling_alphabet.str_to_spellings("a")[0].spelling, "Amsterdam");
+//!
+//! // Alphabets are serialized using their names
+//! let json = serde_json::to_string(&salph::Alphabet::fr_BE).unwrap();
+//! assert_eq!(json, r#""fr_BE""#);
+//! # }
+//! ```
+use crate::{AlphabetBuilder, SalphError, SpellingAlphabet, WordMap};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// Serializable representation of a `SpellingAlphabet`. Indexes are rebuilt
+// when deserializing.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SerializedAlphabet {
+    words: IndexMap<String, String>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    directives: IndexMap<String, String>,
+}
+
+impl From<SpellingAlphabet> for SerializedAlphabet {
+    fn from(alphabet: SpellingAlphabet) -> SerializedAlphabet {
+        SerializedAlphabet {
+            words: to_owned_map(alphabet.words),
+            directives: to_owned_map(alphabet.directives),
+        }
+    }
+}
+
+impl TryFrom<SerializedAlphabet> for SpellingAlphabet {
+    type Error = SalphError;
+
+    fn try_from(serialized: SerializedAlphabet) -> Result<SpellingAlphabet, SalphError> {
+        let mut builder = serialized
+            .words
+            .iter()
+            .fold(AlphabetBuilder::new(), |builder, (key, word)| {
+                builder.add(key, word)
+            });
+        builder.directives = serialized
+            .directives
+            .into_iter()
+            .map(|(name, value)| (Cow::Owned(name), Cow::Owned(value)))
+            .collect();
+        builder.build()
+    }
+}
+
+fn to_owned_map(map: WordMap) -> IndexMap<String, String> {
+    map.into_iter()
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect()
+}
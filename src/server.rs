@@ -0,0 +1,258 @@
+//! A small HTTP server exposing salph as a REST API with JSON bodies, enabled
+//! through the `server` feature. The CLI runs it with `salph serve`.
+//!
+//! Endpoints:
+//! - `GET /alphabets`: the available alphabets and their metadata
+//! - `POST /spell`: spell `{"text": "abc", "alphabet": "nato"}`, returning
+//!   `{"spellings": [...]}`
+//! - `POST /decode`: decode `{"text": "Alpha Bravo", "alphabet": "nato"}`,
+//!   returning `{"text": "ab"}`
+//! - `GET /openapi.json`: an OpenAPI document describing the endpoints above
+//!
+//! The alphabet defaults to `nato`. Errors are returned as `{"error": "..."}` with
+//! a 4xx status code.
+use crate::{
+    registry, Alphabet, AlphabetMetadata, SalphError, Spelling, SpellingAlphabet, SpellingKind,
+    SpellingOptions,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{self, Read},
+    str::FromStr,
+    sync::Arc,
+    thread,
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+use utoipa::{OpenApi, ToSchema};
+
+// Requests with larger bodies are rejected
+const MAX_BODY_LEN: u64 = 1 << 20;
+
+/// An available alphabet, as returned by `GET /alphabets`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AlphabetInfo {
+    /// Name to use in requests (e.g. "nato")
+    pub alphabet: String,
+    #[serde(flatten)]
+    pub metadata: AlphabetMetadata,
+}
+
+/// Body of `POST /spell`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SpellRequest {
+    /// The text to spell
+    pub text: String,
+    /// Name of the alphabet to use
+    #[serde(default = "default_alphabet")]
+    pub alphabet: String,
+    /// Announce uppercase letters (e.g. "capital Alpha")
+    #[serde(default)]
+    pub announce_case: bool,
+    /// Spell spaces instead of skipping them
+    #[serde(default)]
+    pub spell_spaces: bool,
+}
+
+/// Response of `POST /spell`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SpellResponse {
+    pub spellings: Vec<Spelling>,
+}
+
+/// Body of `POST /decode`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DecodeRequest {
+    /// The code words to decode
+    pub text: String,
+    /// Name of the alphabet to use
+    #[serde(default = "default_alphabet")]
+    pub alphabet: String,
+}
+
+/// Response of `POST /decode`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DecodeResponse {
+    pub text: String,
+}
+
+/// Response of failed requests
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+fn default_alphabet() -> String {
+    "nato".to_string()
+}
+
+/// Serve the API on `address` (e.g. "127.0.0.1:8080"). Requests are handled by a
+/// thread per available CPU. Only returns if the server can't be started.
+pub fn serve(address: &str) -> io::Result<()> {
+    let server = Arc::new(Server::http(address).map_err(io::Error::other)?);
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+    let handles = (0..workers)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(request);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        // Workers only stop when the server does
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+// Respond to a single request. Failing to respond means the client went away,
+// which isn't an error for the server.
+fn handle(mut request: Request) {
+    let (status, body) = match route(&mut request) {
+        Ok(body) => (200, body),
+        Err((status, error)) => (status, to_json(&ErrorResponse { error })),
+    };
+    let header = Header::from_bytes("Content-Type", "application/json")
+        .expect("the content type header is valid");
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+// The OpenAPI document, derived from the request and response types
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "salph",
+        description = "Spell sentences in different spelling alphabets"
+    ),
+    paths(alphabets, spell, decode),
+    components(schemas(
+        AlphabetInfo,
+        AlphabetMetadata,
+        SpellRequest,
+        SpellResponse,
+        Spelling,
+        SpellingKind,
+        DecodeRequest,
+        DecodeResponse,
+        ErrorResponse
+    ))
+)]
+struct ApiDoc;
+
+/// The OpenAPI document describing the API, as JSON
+/// ```
+/// let openapi: serde_json::Value = serde_json::from_str(&salph::server::openapi()).unwrap();
+/// assert!(openapi["paths"]["/spell"]["post"].is_object());
+/// assert!(openapi["components"]["schemas"]["Spelling"].is_object());
+/// ```
+pub fn openapi() -> String {
+    ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("the OpenAPI document always serializes")
+}
+
+// Dispatch a request to its endpoint, returning the JSON body or a status code
+// and an error message
+fn route(request: &mut Request) -> Result<String, (u16, String)> {
+    let path = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    match (request.method(), path.as_str()) {
+        (Method::Get, "/alphabets") => Ok(to_json(&alphabets())),
+        (Method::Post, "/spell") => Ok(to_json(&spell(read_body(request)?)?)),
+        (Method::Post, "/decode") => Ok(to_json(&decode(read_body(request)?)?)),
+        (Method::Get, "/openapi.json") => Ok(openapi()),
+        (_, "/alphabets" | "/spell" | "/decode" | "/openapi.json") => {
+            Err((405, format!("Method {} not allowed", request.method())))
+        }
+        _ => Err((404, format!("Unknown endpoint {}", path))),
+    }
+}
+
+/// List the available alphabets
+#[utoipa::path(
+    get,
+    path = "/alphabets",
+    tag = "salph",
+    responses((status = 200, description = "The available alphabets", body = [AlphabetInfo]))
+)]
+fn alphabets() -> Vec<AlphabetInfo> {
+    SpellingAlphabet::list()
+        .into_iter()
+        .map(|(alphabet, metadata)| AlphabetInfo { alphabet, metadata })
+        .collect()
+}
+
+/// Spell a text. Characters without a mapping are skipped.
+#[utoipa::path(
+    post,
+    path = "/spell",
+    tag = "salph",
+    request_body = SpellRequest,
+    responses(
+        (status = 200, description = "The spellings of the text", body = SpellResponse),
+        (status = 400, description = "Invalid body or unknown alphabet", body = ErrorResponse)
+    )
+)]
+fn spell(body: SpellRequest) -> Result<SpellResponse, (u16, String)> {
+    let options = SpellingOptions::new()
+        .announce_case(body.announce_case)
+        .spell_spaces(body.spell_spaces);
+    let alphabet = load(&body.alphabet)?;
+    let spellings = alphabet
+        .spellings_with(&body.text, &options)
+        .flatten()
+        .collect();
+    Ok(SpellResponse { spellings })
+}
+
+/// Decode code words back to the text they spell
+#[utoipa::path(
+    post,
+    path = "/decode",
+    tag = "salph",
+    request_body = DecodeRequest,
+    responses(
+        (status = 200, description = "The decoded text", body = DecodeResponse),
+        (status = 400, description = "Invalid body or unknown alphabet", body = ErrorResponse)
+    )
+)]
+fn decode(body: DecodeRequest) -> Result<DecodeResponse, (u16, String)> {
+    let text = load(&body.alphabet)?.spellings_to_str(&body.text);
+    Ok(DecodeResponse { text })
+}
+
+// Parse the JSON body of a request
+fn read_body<T: for<'de> Deserialize<'de>>(request: &mut Request) -> Result<T, (u16, String)> {
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY_LEN + 1)
+        .read_to_string(&mut body)
+        .map_err(|e| (400, format!("Unable to read body: {}", e)))?;
+    if body.len() as u64 > MAX_BODY_LEN {
+        return Err((413, "Body too large".to_string()));
+    }
+    serde_json::from_str(&body).map_err(|e| (400, format!("Invalid body: {}", e)))
+}
+
+// Load an alphabet by name, sharing embedded alphabets between requests
+fn load(name: &str) -> Result<Arc<SpellingAlphabet>, (u16, String)> {
+    let alphabet = match Alphabet::from_str(name) {
+        Ok(alphabet) => Some(SpellingAlphabet::cached(alphabet)),
+        Err(_) => registry::get(name),
+    };
+    alphabet.ok_or_else(|| (400, SalphError::NotFound(name.to_string()).to_string()))
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("responses always serialize")
+}
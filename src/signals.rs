@@ -0,0 +1,285 @@
+//! Maritime signal flags of the International Code of Signals and flag semaphore
+//! positions for the letters A to Z.
+//! ```
+//! use salph::signals::{self, Direction};
+//!
+//! let flag = signals::flag('o').unwrap();
+//! assert_eq!(flag.name, "Oscar");
+//! assert_eq!(flag.meaning, Some("Man overboard"));
+//!
+//! assert_eq!(signals::semaphore('a'), Some((Direction::S, Direction::SW)));
+//! ```
+
+use std::fmt;
+
+/// A signal flag of the International Code of Signals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalFlag {
+    /// Name of the flag (e.g. "Alfa")
+    pub name: &'static str,
+    /// What the flag looks like
+    pub description: &'static str,
+    /// Meaning of the flag when it's flown on its own
+    pub meaning: Option<&'static str>,
+}
+
+/// Direction of a semaphore flag, as seen by the observer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+use Direction::*;
+
+// Flags and semaphore positions for the letters A to Z
+const SIGNALS: [(SignalFlag, (Direction, Direction)); 26] = [
+    (
+        signal_flag(
+            "Alfa",
+            "white and blue swallowtail",
+            "I have a diver down; keep well clear at slow speed",
+        ),
+        (S, SW),
+    ),
+    (
+        signal_flag(
+            "Bravo",
+            "red swallowtail",
+            "I am taking in, discharging or carrying dangerous goods",
+        ),
+        (S, W),
+    ),
+    (
+        signal_flag(
+            "Charlie",
+            "blue, white, red, white and blue horizontal stripes",
+            "Affirmative",
+        ),
+        (S, NW),
+    ),
+    (
+        signal_flag(
+            "Delta",
+            "yellow, blue and yellow horizontal stripes",
+            "Keep clear of me; I am maneuvering with difficulty",
+        ),
+        (N, S),
+    ),
+    (
+        signal_flag(
+            "Echo",
+            "blue over red",
+            "I am altering my course to starboard",
+        ),
+        (NE, S),
+    ),
+    (
+        signal_flag(
+            "Foxtrot",
+            "white with a red diamond",
+            "I am disabled; communicate with me",
+        ),
+        (E, S),
+    ),
+    (
+        signal_flag(
+            "Golf",
+            "yellow and blue vertical stripes",
+            "I require a pilot",
+        ),
+        (SE, S),
+    ),
+    (
+        signal_flag(
+            "Hotel",
+            "white and red vertical halves",
+            "I have a pilot on board",
+        ),
+        (SW, W),
+    ),
+    (
+        signal_flag(
+            "India",
+            "yellow with a black disc",
+            "I am altering my course to port",
+        ),
+        (SW, NW),
+    ),
+    (
+        signal_flag(
+            "Juliett",
+            "blue, white and blue horizontal stripes",
+            "I am on fire and have dangerous cargo on board; keep well clear of me",
+        ),
+        (N, E),
+    ),
+    (
+        signal_flag(
+            "Kilo",
+            "yellow and blue vertical halves",
+            "I wish to communicate with you",
+        ),
+        (N, SW),
+    ),
+    (
+        signal_flag(
+            "Lima",
+            "yellow and black quarters",
+            "You should stop your vessel instantly",
+        ),
+        (NE, SW),
+    ),
+    (
+        signal_flag(
+            "Mike",
+            "blue with a white saltire",
+            "My vessel is stopped and making no way through the water",
+        ),
+        (E, SW),
+    ),
+    (
+        signal_flag("November", "blue and white checks", "Negative"),
+        (SE, SW),
+    ),
+    (
+        signal_flag("Oscar", "red and yellow diagonal halves", "Man overboard"),
+        (W, NW),
+    ),
+    (
+        signal_flag(
+            "Papa",
+            "blue with a white square",
+            "All persons should report on board as the vessel is about to proceed to sea",
+        ),
+        (N, W),
+    ),
+    (
+        signal_flag(
+            "Quebec",
+            "yellow",
+            "My vessel is healthy and I request free pratique",
+        ),
+        (NE, W),
+    ),
+    (
+        SignalFlag {
+            name: "Romeo",
+            description: "red with a yellow cross",
+            meaning: None,
+        },
+        (E, W),
+    ),
+    (
+        signal_flag(
+            "Sierra",
+            "white with a blue square",
+            "I am operating astern propulsion",
+        ),
+        (SE, W),
+    ),
+    (
+        signal_flag(
+            "Tango",
+            "red, white and blue vertical stripes",
+            "Keep clear of me; I am engaged in pair trawling",
+        ),
+        (N, NW),
+    ),
+    (
+        signal_flag(
+            "Uniform",
+            "red and white quarters",
+            "You are running into danger",
+        ),
+        (NE, NW),
+    ),
+    (
+        signal_flag("Victor", "white with a red saltire", "I require assistance"),
+        (N, SE),
+    ),
+    (
+        signal_flag(
+            "Whiskey",
+            "blue border around a white square with a red center",
+            "I require medical assistance",
+        ),
+        (NE, E),
+    ),
+    (
+        signal_flag(
+            "X-ray",
+            "white with a blue cross",
+            "Stop carrying out your intentions and watch for my signals",
+        ),
+        (NE, SE),
+    ),
+    (
+        signal_flag(
+            "Yankee",
+            "yellow and red diagonal stripes",
+            "I am dragging my anchor",
+        ),
+        (E, NW),
+    ),
+    (
+        signal_flag(
+            "Zulu",
+            "black, yellow, blue and red triangles",
+            "I require a tug",
+        ),
+        (E, SE),
+    ),
+];
+
+const fn signal_flag(
+    name: &'static str,
+    description: &'static str,
+    meaning: &'static str,
+) -> SignalFlag {
+    SignalFlag {
+        name,
+        description,
+        meaning: Some(meaning),
+    }
+}
+
+// Index of an ASCII letter in the alphabet
+fn index(c: char) -> Option<usize> {
+    c.is_ascii_alphabetic()
+        .then(|| usize::from(c.to_ascii_lowercase() as u8 - b'a'))
+}
+
+/// The signal flag for a letter. Letters are matched case insensitively.
+/// ```
+/// use salph::signals;
+///
+/// assert_eq!(signals::flag('Q').unwrap().description, "yellow");
+/// assert_eq!(signals::flag('1'), None);
+/// ```
+pub fn flag(c: char) -> Option<SignalFlag> {
+    index(c).map(|i| SIGNALS[i].0)
+}
+
+/// The positions of the two semaphore flags for a letter, as seen by the observer
+/// and ordered clockwise starting at north. Letters are matched case insensitively.
+/// ```
+/// use salph::signals::{self, Direction};
+///
+/// assert_eq!(signals::semaphore('J'), Some((Direction::N, Direction::E)));
+/// ```
+pub fn semaphore(c: char) -> Option<(Direction, Direction)> {
+    index(c).map(|i| SIGNALS[i].1)
+}
@@ -0,0 +1,57 @@
+use crate::{SalphError, Spelling, SpellingAlphabet, SpellingOptions};
+use std::{rc::Rc, sync::Arc};
+
+/// Something that spells strings, like a [`SpellingAlphabet`]. Code that spells
+/// through this trait can be given other implementations, like a
+/// [`FallbackSpeller`](crate::FallbackSpeller) or one backed by a remote service.
+/// ```
+/// use salph::{SpellingAlphabet, Alphabet, Speller};
+///
+/// fn words(speller: &dyn Speller, s: &str) -> Vec<String> {
+///     speller.spell(s).iter().map(|s| s.to_string()).collect()
+/// }
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+/// assert_eq!(words(&spelling_alphabet, "hi"), ["Hotel", "India"]);
+/// ```
+pub trait Speller {
+    /// Spell a string using the given [`SpellingOptions`]
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError>;
+
+    /// Spell a string. Characters without a mapping are skipped.
+    fn spell(&self, s: &str) -> Vec<Spelling> {
+        // The default options skip unmatched characters, which never produces errors
+        self.spell_with(s, &SpellingOptions::new())
+            .unwrap_or_default()
+    }
+}
+
+impl Speller for SpellingAlphabet {
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        self.str_to_spellings_with(s, options)
+    }
+}
+
+impl<T: Speller + ?Sized> Speller for &T {
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        (**self).spell_with(s, options)
+    }
+}
+
+impl<T: Speller + ?Sized> Speller for Box<T> {
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        (**self).spell_with(s, options)
+    }
+}
+
+impl<T: Speller + ?Sized> Speller for Rc<T> {
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        (**self).spell_with(s, options)
+    }
+}
+
+impl<T: Speller + ?Sized> Speller for Arc<T> {
+    fn spell_with(&self, s: &str, options: &SpellingOptions) -> Result<Vec<Spelling>, SalphError> {
+        (**self).spell_with(s, options)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{SalphError, SpellingAlphabet, SpellingIter, SpellingKind, SpellingOptions, Unmatched};
+use std::time::Duration;
+
+// How long it takes to read out a word, at about 100 words per minute. Code words
+// are read out slower than regular speech so they can be written down.
+const WORD_DURATION: Duration = Duration::from_millis(600);
+
+/// What spelling a string amounts to, as returned by [`stats`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct SpellStats {
+    /// Number of letters that are spelled
+    pub letters: usize,
+    /// Number of digits that are spelled
+    pub digits: usize,
+    /// Number of punctuation characters and symbols that are spelled
+    pub punctuation: usize,
+    /// Number of characters without a code word, not counting whitespace
+    pub unmatched: usize,
+    /// Number of words that are read out, including words like "capital"
+    pub words: usize,
+    /// Estimate of how long reading out the words takes
+    pub duration: Duration,
+}
+
+/// Count the characters a string consists of and estimate how long reading out its
+/// spelling takes.
+/// ```
+/// use salph::{SpellingAlphabet, Alphabet};
+/// use std::time::Duration;
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+/// let stats = salph::stats("KL 1234!", &spelling_alphabet);
+/// assert_eq!((stats.letters, stats.digits, stats.punctuation), (2, 4, 0));
+/// assert_eq!(stats.unmatched, 1);
+/// assert_eq!(stats.words, 6);
+/// assert_eq!(stats.duration, Duration::from_millis(3600));
+/// ```
+pub fn stats(s: &str, alphabet: &SpellingAlphabet) -> SpellStats {
+    stats_with(s, alphabet, &SpellingOptions::new())
+        .expect("skipping unmatched characters can't fail")
+}
+
+/// Count the characters of a string like [`stats`], spelling it with the given
+/// options. Kept and replaced characters count as unmatched, but their words are
+/// read out as well.
+/// ```
+/// use salph::{SpellingAlphabet, Alphabet, SpellingOptions};
+///
+/// let spelling_alphabet = SpellingAlphabet::load(Alphabet::nato).unwrap();
+/// let options = SpellingOptions::new().announce_case(true);
+/// let stats = salph::stats_with("Ab", &spelling_alphabet, &options).unwrap();
+/// assert_eq!(stats.words, 3);
+/// ```
+pub fn stats_with(
+    s: &str,
+    alphabet: &SpellingAlphabet,
+    options: &SpellingOptions,
+) -> Result<SpellStats, SalphError> {
+    let mut stats = SpellStats::default();
+    // Characters that are skipped are counted without being read out
+    let skip = options.unmatched == Unmatched::Skip;
+    let counting = options.clone().unmatched(match &options.unmatched {
+        Unmatched::Skip => Unmatched::Keep,
+        unmatched => unmatched.clone(),
+    });
+    for spelling in SpellingIter::new(alphabet, s, &counting) {
+        let spelling = spelling?;
+        let whitespace = spelling.source.chars().all(char::is_whitespace);
+        match spelling.kind {
+            SpellingKind::Letter => stats.letters += 1,
+            SpellingKind::Digit => stats.digits += 1,
+            SpellingKind::Punctuation | SpellingKind::Symbol => stats.punctuation += 1,
+            SpellingKind::Whitespace => (),
+            SpellingKind::Unknown if whitespace => (),
+            SpellingKind::Unknown => stats.unmatched += 1,
+        }
+        if spelling.kind == SpellingKind::Unknown && (skip || whitespace) {
+            continue;
+        }
+        stats.words += spelling.to_string().split_whitespace().count();
+    }
+    stats.duration = WORD_DURATION * stats.words as u32;
+    Ok(stats)
+}
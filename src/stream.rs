@@ -0,0 +1,49 @@
+//! Spelling of asynchronous input, enabled through the `tokio` feature. Lines are
+//! read from an [`AsyncBufRead`] and spelled as they come in, without blocking the
+//! runtime.
+use crate::{Spelling, SpellingAlphabet, SpellingOptions};
+use std::{io, sync::Arc};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+use tokio_stream::{wrappers::LinesStream, Stream, StreamExt};
+
+/// Spell every line read from `reader`, yielding the spellings of a line as soon as
+/// it has been read. Line endings aren't spelled. The alphabet can be shared with
+/// other tasks by passing an `Arc`, like the one returned by
+/// [`SpellingAlphabet::cached`].
+///
+/// Reading errors are passed on. If a character can't be spelled and the unmatched
+/// policy is [`Unmatched::Error`](crate::Unmatched::Error), the error is yielded as
+/// an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) wrapping
+/// the [`SalphError`](crate::SalphError).
+/// ```
+/// use salph::{stream, SpellingAlphabet, Alphabet, SpellingOptions};
+/// use tokio_stream::StreamExt;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let spelling_alphabet = SpellingAlphabet::cached(Alphabet::nato);
+/// let input = "ab\nc\n".as_bytes();
+/// let mut lines = stream::spell_lines(input, spelling_alphabet, SpellingOptions::new());
+///
+/// let words = |spellings: Vec<salph::Spelling>| {
+///     spellings.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+/// };
+/// assert_eq!(words(lines.next().await.unwrap().unwrap()), ["Alpha", "Bravo"]);
+/// assert_eq!(words(lines.next().await.unwrap().unwrap()), ["Charlie"]);
+/// assert!(lines.next().await.is_none());
+/// # });
+/// ```
+pub fn spell_lines<R>(
+    reader: R,
+    alphabet: impl Into<Arc<SpellingAlphabet>>,
+    options: SpellingOptions,
+) -> impl Stream<Item = io::Result<Vec<Spelling>>>
+where
+    R: AsyncBufRead,
+{
+    let alphabet = alphabet.into();
+    LinesStream::new(reader.lines()).map(move |line| {
+        alphabet
+            .str_to_spellings_with(&line?, &options)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })
+}
@@ -0,0 +1,66 @@
+//! Tap code, which encodes letters by their row and column in a 5×5 Polybius
+//! square. "K" shares its position with "C".
+//!
+//! ```text
+//!   1 2 3 4 5
+//! 1 A B C D E
+//! 2 F G H I J
+//! 3 L M N O P
+//! 4 Q R S T U
+//! 5 V W X Y Z
+//! ```
+//!
+//! Both numbers are written as taps (`.`). The taps of a letter are separated by a
+//! space, letters by two spaces and words by `" / "`. Characters that aren't
+//! letters are skipped.
+//! ```
+//! use salph::tap;
+//!
+//! assert_eq!(tap::encode("hi"), ".. ...  .. ....");
+//! assert_eq!(tap::encode("ok go"), "... ....  . ... / .. ..  ... ....");
+//! ```
+
+// The letters of the square, row by row
+const SQUARE: &[u8; 25] = b"abcdefghijlmnopqrstuvwxyz";
+
+/// The row and column (both starting at 1) of a letter in the square. Letters are
+/// matched case insensitively.
+/// ```
+/// use salph::tap;
+///
+/// assert_eq!(tap::coordinates('W'), Some((5, 2)));
+/// assert_eq!(tap::coordinates('k'), tap::coordinates('c'));
+/// assert_eq!(tap::coordinates('1'), None);
+/// ```
+pub fn coordinates(c: char) -> Option<(u8, u8)> {
+    if !c.is_ascii_alphabetic() {
+        return None;
+    }
+    let c = match c.to_ascii_lowercase() {
+        'k' => b'c',
+        c => c as u8,
+    };
+    let index = SQUARE.iter().position(|letter| *letter == c)? as u8;
+    Some((index / 5 + 1, index % 5 + 1))
+}
+
+/// Encode a string as tap code. Words are split on whitespace.
+pub fn encode(s: &str) -> String {
+    s.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(coordinates)
+                .map(|(row, column)| {
+                    format!(
+                        "{} {}",
+                        ".".repeat(usize::from(row)),
+                        ".".repeat(usize::from(column))
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
@@ -0,0 +1,96 @@
+//! Chinese telegraph code, which encodes Chinese characters as four digit numbers
+//! from the Standard Telegraph Codebook.
+//!
+//! Codes are separated by a space and words by `" / "`. Characters without a code
+//! are skipped. Simplified characters share the code of their traditional form.
+//! The table holds a selection of common characters.
+//! ```
+//! use salph::telegraph;
+//!
+//! assert_eq!(telegraph::encode("中文信息"), "0022 2429 0207 1873");
+//! assert_eq!(telegraph::decode("0022 2429"), "中文");
+//! ```
+
+// Codes and the characters they stand for. The first character is the one from
+// the codebook, the others are simplified forms that share its code.
+const CODES: &[(u16, &str)] = &[
+    (1, "一"),
+    (6, "上"),
+    (22, "中"),
+    (79, "京"),
+    (86, "人"),
+    (207, "信"),
+    (554, "北"),
+    (948, "國国"),
+    (1129, "大"),
+    (1873, "息"),
+    (2429, "文"),
+    (2639, "東东"),
+    (3029, "毛"),
+    (3189, "海"),
+    (3419, "澤泽"),
+];
+
+/// The telegraph code for a character
+/// ```
+/// use salph::telegraph;
+///
+/// assert_eq!(telegraph::encode_char('中'), Some(22));
+/// assert_eq!(telegraph::encode_char('东'), telegraph::encode_char('東'));
+/// assert_eq!(telegraph::encode_char('a'), None);
+/// ```
+pub fn encode_char(c: char) -> Option<u16> {
+    CODES
+        .iter()
+        .find(|(_, characters)| characters.contains(c))
+        .map(|(code, _)| *code)
+}
+
+/// The character for a telegraph code, in its codebook (traditional) form
+/// ```
+/// use salph::telegraph;
+///
+/// assert_eq!(telegraph::decode_code(2639), Some('東'));
+/// assert_eq!(telegraph::decode_code(9999), None);
+/// ```
+pub fn decode_code(code: u16) -> Option<char> {
+    CODES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .and_then(|(_, characters)| characters.chars().next())
+}
+
+/// Encode a string as telegraph codes. Words are split on whitespace.
+pub fn encode(s: &str) -> String {
+    s.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(encode_char)
+                .map(|code| format!("{:04}", code))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Decode telegraph codes separated by whitespace, with words separated by `"/"`.
+/// Codes that aren't in the table are skipped.
+/// ```
+/// use salph::telegraph;
+///
+/// assert_eq!(telegraph::decode("0554 0079 / 0006 3189"), "北京 上海");
+/// ```
+pub fn decode(s: &str) -> String {
+    s.split('/')
+        .map(|word| {
+            word.split_whitespace()
+                .filter_map(|code| code.parse().ok())
+                .filter_map(decode_code)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,88 @@
+use crate::fold::fold_case;
+use unicode_normalization::UnicodeNormalization;
+
+/// Trie over the (lowercase, composed) keys of an alphabet, used to find the
+/// longest key that is a prefix of the input without allocating ngrams.
+#[derive(Debug, Clone)]
+pub(crate) struct Trie {
+    // Node 0 is the root
+    nodes: Vec<TrieNode>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    // Alphabets are small, so a linear scan over the children is fast enough
+    children: Vec<(char, usize)>,
+    // Index of the entry in the alphabet if a key ends at this node
+    value: Option<usize>,
+}
+
+impl Trie {
+    /// Build a trie from keys, where the value of each key is its index
+    pub(crate) fn new<'k>(keys: impl Iterator<Item = &'k str>) -> Trie {
+        let mut trie = Trie {
+            nodes: vec![TrieNode::default()],
+        };
+        for (index, key) in keys.enumerate() {
+            let mut node = 0;
+            for c in key.chars() {
+                node = match trie.child(node, c) {
+                    Some(child) => child,
+                    None => {
+                        trie.nodes.push(TrieNode::default());
+                        let child = trie.nodes.len() - 1;
+                        trie.nodes[node].children.push((c, child));
+                        child
+                    }
+                };
+            }
+            trie.nodes[node].value = Some(index);
+        }
+        trie
+    }
+
+    fn child(&self, node: usize, c: char) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .iter()
+            .find(|(edge, _)| *edge == c)
+            .map(|(_, child)| *child)
+    }
+
+    // Follow the case folded characters from `node`, returning the node that was
+    // reached, or `None` if the path doesn't exist
+    fn walk(&self, mut node: usize, chars: impl Iterator<Item = char>) -> Option<usize> {
+        for c in chars.flat_map(fold_case) {
+            node = self.child(node, c)?;
+        }
+        Some(node)
+    }
+
+    /// Find the longest key that matches a prefix of `graphemes`. Matches always
+    /// end on a grapheme boundary. Returns the number of graphemes that matched and
+    /// the index of the key. When `compose` is set, the graphemes are composed
+    /// (NFC) before matching.
+    pub(crate) fn longest_match<'g>(
+        &self,
+        graphemes: impl Iterator<Item = &'g str>,
+        compose: bool,
+    ) -> Option<(usize, usize)> {
+        let mut node = 0;
+        let mut longest = None;
+        for (i, grapheme) in graphemes.enumerate() {
+            let next = if compose {
+                self.walk(node, grapheme.nfc())
+            } else {
+                self.walk(node, grapheme.chars())
+            };
+            node = match next {
+                Some(node) => node,
+                None => break,
+            };
+            if let Some(index) = self.nodes[node].value {
+                longest = Some((i + 1, index));
+            }
+        }
+        longest
+    }
+}
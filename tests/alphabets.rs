@@ -0,0 +1,152 @@
+// Checks of the data of every embedded alphabet, so mistakes in the files in the
+// alphabets directory are caught before they're released
+use salph::{Alphabet, LintWarning, SpellingAlphabet};
+use std::{collections::HashSet, str::FromStr};
+
+// Alphabets of other scripts, which don't spell a to z
+const OTHER_SCRIPTS: &[&str] = &["ar", "el", "he", "ja", "ko", "ru", "uk"];
+
+// Letters of a to z that aren't part of the language of an alphabet
+const MISSING_LETTERS: &[(&str, &str)] = &[("tr", "qwx")];
+
+// Code words that are used for two keys in the original alphabet (e.g. "Zéro" for
+// both "z" and "0"). They decode to the key that comes first.
+const SHARED_WORDS: &[(&str, &str)] = &[("fr_BE", "zéro"), ("western_union", "zero")];
+
+// All embedded alphabets with their names
+fn embedded() -> Vec<(String, SpellingAlphabet)> {
+    SpellingAlphabet::list()
+        .into_iter()
+        .filter_map(|(name, _)| {
+            let alphabet = Alphabet::from_str(&name).ok()?;
+            Some((name, SpellingAlphabet::load(alphabet).unwrap()))
+        })
+        .collect()
+}
+
+// The alphabet parsed from its file at runtime, rather than by build.rs
+fn from_file(name: &str) -> SpellingAlphabet {
+    let path = format!("{}/alphabets/{}", env!("CARGO_MANIFEST_DIR"), name);
+    SpellingAlphabet::from_file(&path).unwrap_or_else(|e| panic!("{}: {}", name, e))
+}
+
+#[test]
+fn every_alphabet_loads() {
+    let alphabets = embedded();
+    assert!(alphabets.iter().any(|(name, _)| name == "nato"));
+    for (name, alphabet) in &alphabets {
+        assert!(!alphabet.is_empty(), "{} has no entries", name);
+        assert!(alphabet.metadata().name.is_some(), "{} has no name", name);
+
+        // The runtime parser reads the file the same way build.rs does
+        let parsed = from_file(name);
+        assert!(
+            alphabet.entries().eq(parsed.entries()),
+            "{} differs when parsed at runtime",
+            name
+        );
+    }
+}
+
+#[test]
+fn letters_and_digits_are_covered() {
+    for (name, alphabet) in embedded() {
+        let missing_letters = MISSING_LETTERS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map_or("", |(_, letters)| letters);
+        let letters = ('a'..='z').filter(|c| !missing_letters.contains(*c));
+        let other_script = OTHER_SCRIPTS.contains(&name.as_str());
+        for c in letters.filter(|_| !other_script).chain('0'..='9') {
+            assert!(
+                alphabet.contains(c),
+                "{} has no code word for '{}'",
+                name,
+                c
+            );
+        }
+
+        // Exceptions that aren't needed anymore should be removed
+        for c in missing_letters.chars() {
+            assert!(!alphabet.contains(c), "{} spells '{}'", name, c);
+        }
+        if other_script {
+            let latin = alphabet
+                .entries()
+                .any(|(key, _)| key.chars().any(|c| c.is_ascii_alphabetic()));
+            assert!(!latin, "{} has Latin letters", name);
+        }
+    }
+}
+
+#[test]
+fn keys_are_unique() {
+    for (name, _) in embedded() {
+        let duplicates = from_file(&name)
+            .lint()
+            .into_iter()
+            .filter(|warning| matches!(warning, LintWarning::DuplicateKey(_)))
+            .collect::<Vec<_>>();
+        assert!(duplicates.is_empty(), "{}: {:?}", name, duplicates);
+    }
+}
+
+#[test]
+fn code_words_are_unambiguous() {
+    for (name, alphabet) in embedded() {
+        for warning in alphabet.lint() {
+            match warning {
+                LintWarning::AmbiguousWord { word, .. }
+                    if SHARED_WORDS.contains(&(name.as_str(), word.as_str())) => {}
+                LintWarning::AmbiguousWord { .. } | LintWarning::SuspiciousWhitespace(_) => {
+                    panic!("{}: {}", name, warning)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn spellings_round_trip() {
+    for (name, alphabet) in embedded() {
+        let shared: HashSet<&str> = SHARED_WORDS
+            .iter()
+            .filter(|(n, _)| *n == name)
+            .map(|(_, word)| *word)
+            .collect();
+        let keys = alphabet
+            .entries()
+            .filter(|(_, word)| !shared.contains(word.to_lowercase().as_str()))
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        for key in &keys {
+            let spelled = alphabet
+                .str_to_spellings(key)
+                .iter()
+                .map(|spelling| spelling.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            assert_eq!(
+                alphabet.spellings_to_str(&spelled),
+                *key,
+                "{}: '{}' is spelled as '{}'",
+                name,
+                key,
+                spelled
+            );
+        }
+
+        // Spelling all keys in a row gives them back as well, even where keys are
+        // made of other keys (e.g. "ch")
+        let text = keys.concat();
+        let spelled = alphabet
+            .str_to_spellings(&text)
+            .iter()
+            .map(|spelling| spelling.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(alphabet.spellings_to_str(&spelled), text, "{}", name);
+    }
+}